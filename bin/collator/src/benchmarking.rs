@@ -407,7 +407,7 @@ where
         runtime::UncheckedExtrinsic::new_signed(
             call,
             sp_runtime::AccountId32::from(acc.public()).into(),
-            runtime::Signature::Sr25519(signature.clone()),
+            runtime::Signature::Sr25519(signature.clone()).into(),
             extra,
         )
         .into()
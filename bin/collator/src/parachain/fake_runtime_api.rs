@@ -18,7 +18,8 @@
 
 #![allow(missing_docs)]
 
-use astar_primitives::{AccountId, Balance, Block, Nonce};
+use astar_primitives::{evm::UnifiedAddress, AccountId, Balance, Block, BlockNumber, Nonce};
+use collator_selection_runtime_api::{CandidateStatus, NonCandidateStatus};
 use frame_support::weights::Weight;
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 use sp_api::impl_runtime_apis;
@@ -279,6 +280,34 @@ impl_runtime_apis! {
         }
     }
 
+    impl unified_accounts_runtime_api::UnifiedAccountsApi<Block> for Runtime {
+        fn to_account_ids(_evm_addresses: Vec<H160>) -> Vec<UnifiedAddress<AccountId>> {
+            unimplemented!()
+        }
+
+        fn to_h160s(_account_ids: Vec<AccountId>) -> Vec<UnifiedAddress<H160>> {
+            unimplemented!()
+        }
+
+        fn claim_signing_payload(_account_id: AccountId) -> [u8; 32] {
+            unimplemented!()
+        }
+    }
+
+    impl collator_selection_runtime_api::CollatorSelectionApi<Block> for Runtime {
+        fn candidates() -> Vec<CandidateStatus<AccountId, Balance, BlockNumber>> {
+            unimplemented!()
+        }
+
+        fn non_candidates() -> Vec<NonCandidateStatus<AccountId, Balance>> {
+            unimplemented!()
+        }
+
+        fn next_session_collators() -> Vec<AccountId> {
+            unimplemented!()
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(_extra: bool) -> (Vec<frame_benchmarking::BenchmarkList>, Vec<frame_support::traits::StorageInfo>) {
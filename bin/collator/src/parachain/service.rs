@@ -408,13 +408,14 @@ where
                 command_sink: None,
             };
 
-            crate::rpc::create_full(
+            let mut io = crate::rpc::create_full(
                 deps,
                 subscription,
                 pubsub_notification_sinks.clone(),
                 rpc_config.clone(),
-            )
-            .map_err(Into::into)
+            )?;
+            crate::rpc::merge_collator_selection(&mut io, client.clone())?;
+            Ok(io)
         })
     };
 
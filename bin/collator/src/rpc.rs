@@ -52,8 +52,12 @@ use moonbeam_rpc_trace::{Trace, TraceServer};
 use moonbeam_rpc_txpool::{TxPool as MoonbeamTxPool, TxPoolServer};
 
 use astar_primitives::*;
+use collator_selection::{CollatorSelection, CollatorSelectionApiServer};
+use unified_accounts::{UnifiedAccounts, UnifiedAccountsApiServer};
 
+pub mod collator_selection;
 pub mod tracing;
+pub mod unified_accounts;
 
 #[derive(Clone)]
 pub struct EvmTracingConfig {
@@ -166,6 +170,7 @@ where
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
         + AuraApi<Block, AuraId>
+        + unified_accounts_runtime_api::UnifiedAccountsApi<Block>
         + moonbeam_rpc_primitives_debug::DebugRuntimeApi<Block>
         + moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<Block>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
@@ -201,6 +206,21 @@ where
     Ok(io)
 }
 
+/// Extend the RPC module with the collator selection RPC.
+///
+/// Kept apart from [`create_full`] since not every runtime has collator selection.
+pub fn merge_collator_selection<C>(
+    io: &mut RpcModule<()>,
+    client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: collator_selection_runtime_api::CollatorSelectionApi<Block>,
+{
+    io.merge(CollatorSelection::new(client).into_rpc())?;
+    Ok(())
+}
+
 fn create_full_rpc<C, P, BE, A>(
     deps: FullDeps<C, P, A>,
     subscription_task_executor: SubscriptionTaskExecutor,
@@ -228,7 +248,8 @@ where
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
         + BlockBuilder<Block>
-        + AuraApi<Block, AuraId>,
+        + AuraApi<Block, AuraId>
+        + unified_accounts_runtime_api::UnifiedAccountsApi<Block>,
     P: TransactionPool<Block = Block> + Sync + Send + 'static,
     BE: Backend<Block> + 'static,
     BE::State: StateBackend<BlakeTwo256>,
@@ -257,6 +278,7 @@ where
 
     io.merge(System::new(client.clone(), pool.clone(), deny_unsafe).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    io.merge(UnifiedAccounts::new(client.clone()).into_rpc())?;
     io.merge(sc_rpc::dev::Dev::new(client.clone(), deny_unsafe).into_rpc())?;
 
     #[cfg(feature = "manual-seal")]
@@ -0,0 +1,177 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Collator Selection RPC, for monitoring the candidates and how close
+//! they are to being kicked.
+
+use astar_primitives::{AccountId, Balance, Block, BlockNumber};
+use collator_selection_runtime_api::{
+    CandidateStatus as RuntimeCandidateStatus, CollatorSelectionApi as CollatorSelectionRuntimeApi,
+    NonCandidateStatus as RuntimeNonCandidateStatus,
+};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// Error code for a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Status of a collation candidate.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CandidateStatus {
+    /// Candidate account.
+    pub who: AccountId,
+    /// Candidate's own deposit.
+    pub deposit: Balance,
+    /// Deposit plus the nominations behind the candidate.
+    pub total_stake: Balance,
+    /// Whether the candidate is ranked high enough to be elected.
+    pub elected: bool,
+    /// Last block authored by the candidate.
+    pub last_authored_block: BlockNumber,
+    /// Assigned Aura slots missed in the current session.
+    pub missed_slots: u32,
+    /// Consecutive sessions with missed slots.
+    pub missed_sessions: u32,
+    /// Further sessions with missed slots until the candidate is kicked.
+    pub sessions_until_kick: u32,
+}
+
+impl From<RuntimeCandidateStatus<AccountId, Balance, BlockNumber>> for CandidateStatus {
+    fn from(status: RuntimeCandidateStatus<AccountId, Balance, BlockNumber>) -> Self {
+        Self {
+            who: status.who,
+            deposit: status.deposit,
+            total_stake: status.total_stake,
+            elected: status.elected,
+            last_authored_block: status.last_authored_block,
+            missed_slots: status.missed_slots,
+            missed_sessions: status.missed_sessions,
+            sessions_until_kick: status.sessions_until_kick,
+        }
+    }
+}
+
+/// Status of a former candidate whose deposit is un-bonding.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NonCandidateStatus {
+    /// Former candidate account.
+    pub who: AccountId,
+    /// Un-bonding deposit.
+    pub deposit: Balance,
+    /// Session from which the deposit can be withdrawn.
+    pub unlock_session: u32,
+    /// Further sessions with missed slots until the un-bonding deposit is slashed.
+    pub sessions_until_kick: u32,
+}
+
+impl From<RuntimeNonCandidateStatus<AccountId, Balance>> for NonCandidateStatus {
+    fn from(status: RuntimeNonCandidateStatus<AccountId, Balance>) -> Self {
+        Self {
+            who: status.who,
+            deposit: status.deposit,
+            unlock_session: status.unlock_session,
+            sessions_until_kick: status.sessions_until_kick,
+        }
+    }
+}
+
+#[rpc(server)]
+pub trait CollatorSelectionApi<BlockHash> {
+    /// Get the status of all the candidates, in ranking order.
+    #[method(name = "collatorSelection_candidates")]
+    fn candidates(&self, at: Option<BlockHash>) -> RpcResult<Vec<CandidateStatus>>;
+
+    /// Get the status of the former candidates whose deposit is un-bonding.
+    #[method(name = "collatorSelection_nonCandidates")]
+    fn non_candidates(&self, at: Option<BlockHash>) -> RpcResult<Vec<NonCandidateStatus>>;
+
+    /// Get the collators planned for the next session.
+    #[method(name = "collatorSelection_nextSessionCollators")]
+    fn next_session_collators(&self, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+}
+
+/// Provides RPC methods to query the collator candidates.
+pub struct CollatorSelection<C> {
+    client: Arc<C>,
+}
+
+impl<C> CollatorSelection<C> {
+    /// Create new `CollatorSelection` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query collator selection.",
+        Some(format!("{:?}", e)),
+    )
+}
+
+impl<C> CollatorSelectionApiServer<<Block as BlockT>::Hash> for CollatorSelection<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: CollatorSelectionRuntimeApi<Block>,
+{
+    fn candidates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<CandidateStatus>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .candidates(at)
+            .map(|candidates| candidates.into_iter().map(Into::into).collect())
+            .map_err(runtime_error)
+    }
+
+    fn non_candidates(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<NonCandidateStatus>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .non_candidates(at)
+            .map(|non_candidates| non_candidates.into_iter().map(Into::into).collect())
+            .map_err(runtime_error)
+    }
+
+    fn next_session_collators(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<AccountId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .next_session_collators(at)
+            .map_err(runtime_error)
+    }
+}
@@ -0,0 +1,179 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Unified Accounts RPC, for resolving the account mappings and
+//! building the EIP-712 claim requests.
+
+use astar_primitives::{
+    evm::{EvmAddress, UnifiedAddress},
+    AccountId, Block,
+};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::H256;
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+use unified_accounts_runtime_api::UnifiedAccountsApi as UnifiedAccountsRuntimeApi;
+
+/// Maximum number of addresses which can be resolved in a single request.
+pub const MAX_BATCH_SIZE: usize = 1_000;
+
+/// Error code for a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code for a request exceeding [`MAX_BATCH_SIZE`].
+const BATCH_TOO_LARGE: i32 = 2;
+
+/// Address resolved from the unified accounts mappings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedAddress<Address> {
+    /// The resolved address.
+    pub address: Address,
+    /// Whether the address comes from the mappings or is the default one.
+    pub mapped: bool,
+}
+
+impl<Address> From<UnifiedAddress<Address>> for ResolvedAddress<Address> {
+    fn from(unified: UnifiedAddress<Address>) -> Self {
+        match unified {
+            UnifiedAddress::Mapped(address) => Self {
+                address,
+                mapped: true,
+            },
+            UnifiedAddress::Default(address) => Self {
+                address,
+                mapped: false,
+            },
+        }
+    }
+}
+
+#[rpc(server)]
+pub trait UnifiedAccountsApi<BlockHash> {
+    /// Get the native accounts of the given evm addresses.
+    #[method(name = "unifiedAccounts_toAccountIds")]
+    fn to_account_ids(
+        &self,
+        evm_addresses: Vec<EvmAddress>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ResolvedAddress<AccountId>>>;
+
+    /// Get the evm addresses of the given native accounts.
+    #[method(name = "unifiedAccounts_toH160s")]
+    fn to_h160s(
+        &self,
+        account_ids: Vec<AccountId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ResolvedAddress<EvmAddress>>>;
+
+    /// Get the EIP-712 payload to be signed by the evm address for claiming it
+    /// for the given native account.
+    #[method(name = "unifiedAccounts_claimSigningPayload")]
+    fn claim_signing_payload(
+        &self,
+        account_id: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<H256>;
+}
+
+/// Provides RPC methods to query the unified accounts mappings.
+pub struct UnifiedAccounts<C> {
+    client: Arc<C>,
+}
+
+impl<C> UnifiedAccounts<C> {
+    /// Create new `UnifiedAccounts` with the given reference to the client.
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+fn runtime_error(e: impl std::fmt::Debug) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query unified accounts.",
+        Some(format!("{:?}", e)),
+    )
+}
+
+fn ensure_batch_size(len: usize) -> RpcResult<()> {
+    if len > MAX_BATCH_SIZE {
+        return Err(ErrorObject::owned(
+            BATCH_TOO_LARGE,
+            "Too many addresses requested.",
+            Some(format!("Maximum is {MAX_BATCH_SIZE}, got {len}")),
+        ));
+    }
+    Ok(())
+}
+
+impl<C> UnifiedAccountsApiServer<<Block as BlockT>::Hash> for UnifiedAccounts<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: UnifiedAccountsRuntimeApi<Block>,
+{
+    fn to_account_ids(
+        &self,
+        evm_addresses: Vec<EvmAddress>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ResolvedAddress<AccountId>>> {
+        ensure_batch_size(evm_addresses.len())?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .to_account_ids(at, evm_addresses)
+            .map(|accounts| accounts.into_iter().map(Into::into).collect())
+            .map_err(runtime_error)
+    }
+
+    fn to_h160s(
+        &self,
+        account_ids: Vec<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ResolvedAddress<EvmAddress>>> {
+        ensure_batch_size(account_ids.len())?;
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .to_h160s(at, account_ids)
+            .map(|addresses| addresses.into_iter().map(Into::into).collect())
+            .map_err(runtime_error)
+    }
+
+    fn claim_signing_payload(
+        &self,
+        account_id: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<H256> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .claim_signing_payload(at, account_id)
+            .map(H256::from)
+            .map_err(runtime_error)
+    }
+}
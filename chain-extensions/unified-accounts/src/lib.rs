@@ -18,19 +18,22 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use astar_primitives::evm::{EvmAddress, UnifiedAddressMapper};
+use astar_primitives::evm::{EvmAddress, OnAccountUnification, UnifiedAddressMapper};
 use core::marker::PhantomData;
 use sp_runtime::DispatchError;
 
-use frame_support::DefaultNoBound;
+use frame_support::{storage::with_storage_layer, DefaultNoBound};
+use frame_system::RawOrigin;
 use pallet_contracts::chain_extension::{
     ChainExtension, Environment, Ext, InitState, Result as DispatchResult, RetVal,
 };
 use pallet_unified_accounts::WeightInfo;
 use parity_scale_codec::Encode;
 pub use unified_accounts_chain_extension_types::Command::{self, *};
+use unified_accounts_chain_extension_types::Outcome;
 
 type UAWeight<T> = <T as pallet_unified_accounts::Config>::WeightInfo;
+type UAPallet<T> = pallet_unified_accounts::Pallet<T>;
 
 #[derive(DefaultNoBound)]
 pub struct UnifiedAccountsExtension<T, UA>(PhantomData<(T, UA)>);
@@ -79,7 +82,38 @@ where
                 UA::to_account_id_or_default(&evm_address)
                     .using_encoded(|r| env.write(r, false, None))?;
             }
+            ClaimDefaultEvmAddress => {
+                // charge weight
+                env.charge_weight(UAWeight::<T>::claim_default_evm_address())?;
+
+                // claim for the contract itself, storage fee is paid by it,
+                // any partial changes are reverted on failure
+                let account_id = env.ext().address().clone();
+                match with_storage_layer(|| UAPallet::<T>::do_claim_default_evm_address(account_id))
+                {
+                    Ok(evm_address) => {
+                        // write to buffer
+                        evm_address.using_encoded(|r| env.write(r, false, None))?;
+                    }
+                    Err(e) => return Ok(RetVal::Converging(Outcome::from(e) as u32)),
+                }
+            }
+            ClaimEvmAddress => {
+                let (evm_address, signature): (EvmAddress, [u8; 65]) = env.read_as()?;
+                // charge weight
+                env.charge_weight(UAWeight::<T>::claim_evm_address().saturating_add(
+                    <T as pallet_unified_accounts::Config>::OnAccountUnification::weight(),
+                ))?;
+
+                // claim for the contract itself, storage fee is paid by it
+                let origin = RawOrigin::Signed(env.ext().address().clone());
+                if let Err(e) = with_storage_layer(|| {
+                    UAPallet::<T>::claim_evm_address(origin.into(), evm_address, signature)
+                }) {
+                    return Ok(RetVal::Converging(Outcome::from(e) as u32));
+                }
+            }
         };
-        Ok(RetVal::Converging(0))
+        Ok(RetVal::Converging(Outcome::Success as u32))
     }
 }
@@ -0,0 +1,40 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::{AccountId, Balance, BlockNumber};
+pub use pallet_collator_selection::{CandidateStatus, NonCandidateStatus};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Collator Selection Api.
+    ///
+    /// Used to provide the candidates' status, e.g. how close they are to being kicked.
+    pub trait CollatorSelectionApi {
+
+        /// Status of all the candidates, in ranking order.
+        fn candidates() -> Vec<CandidateStatus<AccountId, Balance, BlockNumber>>;
+
+        /// Status of the candidates whose deposit is still un-bonding.
+        fn non_candidates() -> Vec<NonCandidateStatus<AccountId, Balance>>;
+
+        /// Collators planned for the next session.
+        fn next_session_collators() -> Vec<AccountId>;
+    }
+}
@@ -26,12 +26,14 @@ use frame_benchmarking::{
 };
 use frame_support::{
     assert_ok,
-    traits::{Currency, EnsureOrigin, Get},
+    traits::{Currency, EnsureOrigin, Get, Hooks},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, EventRecord, RawOrigin};
 use pallet_authorship::EventHandler;
 use pallet_session::{self as session, SessionManager};
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
+use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
+use sp_runtime::{DigestItem, Perbill};
 use sp_std::prelude::*;
 
 pub type BalanceOf<T> =
@@ -113,6 +115,26 @@ fn register_candidates<T: Config>(count: u32) {
     }
 }
 
+fn nomination_amount<T: Config>() -> BalanceOf<T> {
+    T::MinNomination::get().max(T::Currency::minimum_balance())
+}
+
+fn nominate_candidate<T: Config>(candidate: &T::AccountId, count: u32) {
+    let amount = nomination_amount::<T>();
+    for n in 0..count {
+        let nominator: T::AccountId = account("nominator", n, SEED);
+        T::Currency::make_free_balance_be(
+            &nominator,
+            amount * 2u32.into() + T::Currency::minimum_balance(),
+        );
+        assert_ok!(CollatorSelection::<T>::nominate(
+            RawOrigin::Signed(nominator).into(),
+            candidate.clone(),
+            amount,
+        ));
+    }
+}
+
 benchmarks! {
     where_clause { where T: pallet_authorship::Config + session::Config }
 
@@ -156,7 +178,7 @@ benchmarks! {
     // worse case is when we have all the max-candidate slots filled except one, and we fill that
     // one.
     register_as_candidate {
-        let c in 1 .. T::MaxCandidates::get();
+        let c in 1 .. T::MaxCandidates::get() - 1;
 
         <CandidacyBond<T>>::put(T::Currency::minimum_balance());
         <DesiredCandidates<T>>::put(c + 1);
@@ -214,22 +236,32 @@ benchmarks! {
         assert_eq!(<NonCandidates<T>>::get(&leaving), None);
     }
 
-    // worse case is paying a non-existing candidate account.
+    // worse case is paying the author and all of its nominators.
     note_author {
+        let n in 0 .. T::MaxNominators::get();
+
         <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        register_validators::<T>(1);
+        register_candidates::<T>(1);
+        let author = <Candidates<T>>::get()[0].who.clone();
+        nominate_candidate::<T>(&author, n);
+        assert_ok!(CollatorSelection::<T>::set_commission(
+            RawOrigin::Signed(author.clone()).into(),
+            Perbill::from_percent(10),
+        ));
+
         T::Currency::make_free_balance_be(
             &<CollatorSelection<T>>::account_id(),
             T::Currency::minimum_balance() * 4u32.into(),
         );
-        let author = account("author", 0, SEED);
         let new_block: BlockNumberFor<T> = 10u32.into();
 
         frame_system::Pallet::<T>::set_block_number(new_block);
-        assert!(T::Currency::free_balance(&author) == 0u32.into());
+        let balance_before = T::Currency::free_balance(&author);
     }: {
         <CollatorSelection<T> as EventHandler<_, _>>::note_author(author.clone())
     } verify {
-        assert!(T::Currency::free_balance(&author) > 0u32.into());
+        assert!(T::Currency::free_balance(&author) > balance_before);
         assert_eq!(frame_system::Pallet::<T>::block_number(), new_block);
     }
 
@@ -237,38 +269,45 @@ benchmarks! {
     new_session {
         let r in 1 .. T::MaxCandidates::get();
         let c in 1 .. T::MaxCandidates::get();
+        let n in 0 .. T::MaxNominators::get();
 
         <CandidacyBond<T>>::put(T::Currency::minimum_balance());
-        <DesiredCandidates<T>>::put(c);
         frame_system::Pallet::<T>::set_block_number(0u32.into());
 
         register_validators::<T>(c);
         register_candidates::<T>(c);
 
-        let new_block: BlockNumberFor<T> = 1800u32.into();
-        let zero_block: BlockNumberFor<T> = 0u32.into();
         let candidates = <Candidates<T>>::get();
 
         let non_removals = c.saturating_sub(r);
 
-        for i in 0..c {
-            <LastAuthoredBlock<T>>::insert(candidates[i as usize].who.clone(), zero_block);
-        }
-
         if non_removals > 0 {
-            for i in 0..non_removals {
-                <LastAuthoredBlock<T>>::insert(candidates[i as usize].who.clone(), new_block);
-            }
-        } else {
-            for i in 0..c {
-                <LastAuthoredBlock<T>>::insert(candidates[i as usize].who.clone(), new_block);
+            for i in non_removals..c {
+                <MissedSessions<T>>::insert(candidates[i as usize].who.clone(), T::KickThreshold::get());
             }
         }
+        // the nominations behind the first kicked candidate are slashed along with it
+        nominate_candidate::<T>(&candidates[c as usize - 1].who, n);
 
         let pre_length = <Candidates<T>>::get().len();
 
-        frame_system::Pallet::<T>::set_block_number(new_block);
-
+        // worst case is all the remaining candidates being in the waiting list and rotated out,
+        // the slashes of the kicked ones being deferred and an invulnerable being phased out.
+        <DesiredCandidates<T>>::put(0);
+        <TermLimit<T>>::put(1);
+        for candidate in &candidates {
+            <ServedSessions<T>>::insert(&candidate.who, 1);
+        }
+        <SlashDeferDuration<T>>::put(1);
+        <Invulnerables<T>>::put(vec![
+            create_funded_user::<T>("invulnerable", 0, 1000),
+            create_funded_user::<T>("invulnerable", 1, 1000),
+        ]);
+        <PhaseOut<T>>::put(InvulnerablesPhaseOut {
+            target: 0,
+            interval: 1,
+            next_session: 0,
+        });
         assert!(<Candidates<T>>::get().len() == c as usize);
     }: {
         <CollatorSelection<T> as SessionManager<_>>::new_session(0)
@@ -281,6 +320,375 @@ benchmarks! {
             assert!(<Candidates<T>>::get().len() == pre_length);
         }
     }
+
+    // worst case is the lowest ranked candidate outbidding everyone else.
+    update_bond {
+        let c in 1 .. T::MaxCandidates::get();
+
+        let bond = T::Currency::minimum_balance();
+        <CandidacyBond<T>>::put(bond);
+        <DesiredCandidates<T>>::put(c);
+
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+
+        let caller = <Candidates<T>>::get().last().unwrap().who.clone();
+        T::Currency::make_free_balance_be(&caller, bond * 4u32.into());
+        let new_deposit = bond * 2u32.into();
+        whitelist!(caller);
+    }: _(RawOrigin::Signed(caller.clone()), new_deposit)
+    verify {
+        assert_last_event::<T>(Event::CandidateBondUpdated(caller.clone(), new_deposit).into());
+        assert_eq!(<Candidates<T>>::get()[0].who, caller);
+    }
+
+    // the list is always full, worst case is the new candidate outranking all of it.
+    take_candidate_slot {
+        let c in 1 .. T::MaxCandidates::get();
+        let max = T::MaxCandidates::get();
+
+        let bond = T::Currency::minimum_balance();
+        <CandidacyBond<T>>::put(bond);
+        <DesiredCandidates<T>>::put(max);
+
+        register_validators::<T>(max);
+        register_candidates::<T>(max);
+
+        // the new candidate outranks the `c` lowest ranked candidates only
+        let higher_ranked = <Candidates<T>>::get()
+            .iter()
+            .take((max - c) as usize)
+            .map(|info| info.who.clone())
+            .collect::<Vec<_>>();
+        for who in higher_ranked {
+            T::Currency::make_free_balance_be(&who, bond * 4u32.into());
+            <CollatorSelection<T>>::update_bond(RawOrigin::Signed(who).into(), bond * 3u32.into())
+                .unwrap();
+        }
+
+        let caller: T::AccountId = whitelisted_caller();
+        let deposit = bond * 2u32.into();
+        T::Currency::make_free_balance_be(&caller, bond * 4u32.into());
+
+        <session::Pallet<T>>::set_keys(
+            RawOrigin::Signed(caller.clone()).into(),
+            keys::<T>(max + 1),
+            Vec::new()
+        ).unwrap();
+
+        let replaced = <Candidates<T>>::get().last().unwrap().who.clone();
+    }: _(RawOrigin::Signed(caller.clone()), deposit)
+    verify {
+        assert_last_event::<T>(Event::CandidateReplaced(caller.clone(), replaced, deposit).into());
+        assert_eq!(<Candidates<T>>::get()[(max - c) as usize].who, caller);
+    }
+
+    // worst case is the lowest ranked candidate becoming the highest ranked one.
+    nominate {
+        let c in 1 .. T::MaxCandidates::get();
+
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        <DesiredCandidates<T>>::put(c);
+
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+
+        let candidate = <Candidates<T>>::get().last().unwrap().who.clone();
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = nomination_amount::<T>();
+        T::Currency::make_free_balance_be(
+            &caller,
+            amount * 2u32.into() + T::Currency::minimum_balance(),
+        );
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::Nominated(caller, candidate.clone(), amount).into());
+        assert_eq!(<Candidates<T>>::get()[0].who, candidate);
+    }
+
+    // worst case is the highest ranked candidate becoming the lowest ranked one.
+    unbond_nomination {
+        let c in 1 .. T::MaxCandidates::get();
+
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        <DesiredCandidates<T>>::put(c);
+
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+
+        let candidate = <Candidates<T>>::get().last().unwrap().who.clone();
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = nomination_amount::<T>();
+        T::Currency::make_free_balance_be(
+            &caller,
+            amount * 2u32.into() + T::Currency::minimum_balance(),
+        );
+        assert_ok!(CollatorSelection::<T>::nominate(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone(), amount)
+    verify {
+        assert_last_event::<T>(Event::NominationUnbonded(caller, candidate.clone(), amount).into());
+        assert_eq!(<Candidates<T>>::get().last().unwrap().who, candidate);
+    }
+
+    withdraw_nomination {
+        use frame_support::traits::{EstimateNextSessionRotation, Hooks};
+
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        register_validators::<T>(1);
+        register_candidates::<T>(1);
+
+        let candidate = <Candidates<T>>::get()[0].who.clone();
+        let caller: T::AccountId = whitelisted_caller();
+        let amount = nomination_amount::<T>();
+        T::Currency::make_free_balance_be(
+            &caller,
+            amount * 2u32.into() + T::Currency::minimum_balance(),
+        );
+        assert_ok!(CollatorSelection::<T>::nominate(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+        assert_ok!(CollatorSelection::<T>::unbond_nomination(
+            RawOrigin::Signed(caller.clone()).into(),
+            candidate.clone(),
+            amount,
+        ));
+        let session_length = <T as session::Config>::NextSessionRotation::average_session_length();
+        session::Pallet::<T>::on_initialize(session_length);
+    }: _(RawOrigin::Signed(caller.clone()), candidate.clone())
+    verify {
+        assert_last_event::<T>(Event::NominationWithdrawn(caller, candidate, amount).into());
+    }
+
+    set_commission {
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        register_validators::<T>(1);
+        register_candidates::<T>(1);
+
+        let candidate = <Candidates<T>>::get()[0].who.clone();
+        let commission = Perbill::from_percent(10);
+        whitelist!(candidate);
+    }: _(RawOrigin::Signed(candidate.clone()), commission)
+    verify {
+        assert_last_event::<T>(Event::CommissionSet(candidate, commission).into());
+    }
+
+    // worst case is paying all the collators of the session & the nominators sharing the rewards.
+    end_session {
+        let c in 1 .. T::MaxCandidates::get();
+        let n in 0 .. T::MaxNominators::get();
+
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+        let collators = <Candidates<T>>::get()
+            .into_iter()
+            .map(|info| info.who)
+            .collect::<Vec<_>>();
+        nominate_candidate::<T>(&collators[0], n);
+        assert_ok!(CollatorSelection::<T>::set_commission(
+            RawOrigin::Signed(collators[0].clone()).into(),
+            Perbill::from_percent(10),
+        ));
+
+        <CollatorRewardMode<T>>::put(RewardMode::PerSession);
+        T::Currency::make_free_balance_be(
+            &<CollatorSelection<T>>::account_id(),
+            T::Currency::minimum_balance() * (c * 1000).into(),
+        );
+
+        for who in &collators {
+            <AuthoredBlocks<T>>::insert(who, 1);
+            <MissedSlots<T>>::insert(who, 1);
+        }
+        <PlannedCollators<T>>::insert(0, collators);
+    }: {
+        <CollatorSelection<T> as SessionManager<_>>::end_session(0)
+    } verify {
+        assert_eq!(<PerformanceHistory<T>>::get(0).len(), c as usize);
+        assert!(<PerformanceHistory<T>>::get(0).iter().all(|p| p.reward > 0u32.into()));
+    }
+
+    // worst case is every collator of the session missing a slot.
+    note_missed_slots {
+        let m in 0 .. T::MaxCandidates::get();
+
+        // one collator more than the skipped slots, otherwise the gap is treated as a stall
+        let collators = (0..=m)
+            .map(|i| create_funded_user::<T>("collator", i, 1000))
+            .collect::<Vec<_>>();
+        <PlannedCollators<T>>::insert(T::ValidatorSet::session_index(), collators);
+
+        let block: BlockNumberFor<T> = (m + 10).into();
+        frame_system::Pallet::<T>::set_block_number(block);
+        frame_system::Pallet::<T>::deposit_log(DigestItem::PreRuntime(
+            AURA_ENGINE_ID,
+            Slot::from(m as u64 + 10).encode(),
+        ));
+        let slot = T::CurrentSlot::get();
+        <LastSlot<T>>::put(slot.saturating_sub(m as u64 + 1));
+    }: {
+        <CollatorSelection<T> as Hooks<_>>::on_initialize(block)
+    } verify {
+        assert_eq!(<LastSlot<T>>::get(), slot);
+        assert_eq!(<MissedSlots<T>>::iter().count(), m as usize);
+    }
+
+    // worst case is the new invulnerable being a candidate.
+    add_invulnerable {
+        let b in 1 .. T::MaxInvulnerables::get() - 1;
+        let c in 1 .. T::MaxCandidates::get();
+
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        <DesiredCandidates<T>>::put(c);
+        let invulnerables = (0..b)
+            .map(|i| create_funded_user::<T>("invulnerable", i, 1000))
+            .collect::<Vec<_>>();
+        <Invulnerables<T>>::put(invulnerables);
+
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+
+        let who = <Candidates<T>>::get().last().unwrap().who.clone();
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::add_invulnerable(origin, who.clone())
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::InvulnerableAdded(who).into());
+    }
+
+    remove_invulnerable {
+        let b in 1 .. T::MaxInvulnerables::get();
+
+        let invulnerables = (0..b)
+            .map(|i| create_funded_user::<T>("invulnerable", i, 1000))
+            .collect::<Vec<_>>();
+        let who = invulnerables.last().unwrap().clone();
+        <Invulnerables<T>>::put(invulnerables);
+
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::remove_invulnerable(origin, who.clone())
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::InvulnerableRemoved(who).into());
+    }
+
+    schedule_phase_out {
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::schedule_phase_out(origin, 0, 1)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::PhaseOutScheduled(0, 1).into());
+    }
+
+    cancel_phase_out {
+        <PhaseOut<T>>::put(InvulnerablesPhaseOut {
+            target: 0,
+            interval: 1,
+            next_session: 0,
+        });
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::cancel_phase_out(origin)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::PhaseOutCancelled.into());
+    }
+
+    set_term_limit {
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_term_limit(origin, 0)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewTermLimit(0).into());
+    }
+
+    set_reward_mode {
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_reward_mode(origin, RewardMode::PerSession)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewRewardMode(RewardMode::PerSession).into());
+    }
+
+    set_slash_defer_duration {
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_slash_defer_duration(origin, 4)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewSlashDeferDuration(4).into());
+    }
+
+    // worst case is the candidate slashed together with its active and un-bonding nominations.
+    cancel_deferred_slash {
+        let n in 0 .. T::MaxNominators::get() * 2;
+
+        let amount = T::Currency::minimum_balance();
+        let who = create_funded_user::<T>("candidate", 0, 1000);
+        let nominators = (0..n)
+            .map(|i| (create_funded_user::<T>("nominator", i, 1000), amount))
+            .collect::<Vec<_>>();
+        let slash = DeferredSlash { own: amount, nominators };
+        let total = slash.total();
+        T::Currency::make_free_balance_be(&<CollatorSelection<T>>::slash_holding_account_id(), total);
+        <DeferredSlashes<T>>::insert(1, &who, slash);
+        let balance_before = T::Currency::free_balance(&who);
+
+        let origin = T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::cancel_deferred_slash(origin, 1, who.clone())
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::DeferredSlashCancelled(who.clone(), 1, total).into());
+        assert_eq!(T::Currency::free_balance(&who), balance_before + amount);
+    }
+
+    // worst case is slashing the deferred slashes of all the candidates.
+    start_session {
+        let s in 0 .. T::MaxCandidates::get();
+
+        let amount = T::Currency::minimum_balance();
+        for i in 0..s {
+            let who = create_funded_user::<T>("candidate", i, 1000);
+            <DeferredSlashes<T>>::insert(1, who, DeferredSlash { own: amount, nominators: vec![] });
+        }
+        T::Currency::make_free_balance_be(
+            &<CollatorSelection<T>>::slash_holding_account_id(),
+            amount * (s + 1).into(),
+        );
+        <SlashDestination<T>>::put(create_funded_user::<T>("destination", 0, 1000));
+    }: {
+        <CollatorSelection<T> as SessionManager<_>>::start_session(1)
+    } verify {
+        assert_eq!(<DeferredSlashes<T>>::iter_prefix(1).count(), 0);
+    }
 }
 
 impl_benchmark_test_suite!(
@@ -40,11 +40,52 @@
 //! 2. [`Candidates`]: these are *candidates to the collation task* and may or may not be elected as
 //!    a final collator.
 //!
-//! The current implementation resolves congestion of [`Candidates`] in a first-come-first-serve
-//! manner.
+//! [`Candidates`] are ranked by their bond, highest first. Candidates can bond more than the
+//! [`CandidacyBond`] using `update_bond` and on each new session the top [`DesiredCandidates`] are
+//! elected. The remaining candidates form a ranked waiting list, they can outbid the elected ones
+//! by increasing their bond. Once the list reaches [`Config::MaxCandidates`], a newcomer can only
+//! join by taking the slot of the lowest ranked candidate with `take_candidate_slot`. Ties between
+//! equal bonds are won by the candidate who reached that bond first.
 //!
 //! Candidates will not be allowed to get kicked or leave_intent if the total number of candidates
-//! fall below MinCandidates. This is for potential disaster recovery scenarios.
+//! fall below MinCandidates. This is for potential disaster recovery scenarios. In that case, the
+//! candidates with the most missed sessions are kicked first, and the lowest ranked among the
+//! equally stale ones.
+//!
+//! ### Term limits
+//!
+//! Governance can set a [`TermLimit`], the number of consecutive sessions a candidate can be
+//! elected for while other candidates are waiting. Seats are then filled by the candidates within
+//! their term in ranking order, followed by the [`RotationQueue`] of the candidates rotated out
+//! earlier, and only then by the candidates who reached the term limit. Rotated candidates keep
+//! their bond and join the end of the queue.
+//!
+//! ### Invulnerables phase-out
+//!
+//! Governance can add or remove single invulnerables with `add_invulnerable` and
+//! `remove_invulnerable`, and schedule a phase-out of the invulnerables in favour of the
+//! permissionless candidates. While scheduled, an invulnerable is removed every given number of
+//! sessions, as long as a waiting candidate can take its seat: [`DesiredCandidates`] is raised by
+//! one, keeping the size of the collator set. The phase-out ends once the target number of
+//! invulnerables is reached.
+//!
+//! ### Staleness
+//!
+//! Collators author blocks in the Aura slots assigned to them, round robin over the session's
+//! collators. Every slot skipped between two blocks is counted as missed by the collator it was
+//! assigned to. A collator who missed more than [`Config::MissedSlotsTolerance`] of its assigned
+//! slots in a session has a missed session, and candidates with [`Config::KickThreshold`]
+//! consecutive missed sessions are kicked and slashed. Gaps in which every collator missed its
+//! slot, e.g. caused by a relay chain stall, are not charged to anyone.
+//!
+//! ### Nominations
+//!
+//! Any account can back a candidate by reserving funds with `nominate`. Candidates are ranked by
+//! their total stake, i.e. their own bond plus the nominations behind them. Nominations share the
+//! risk of the candidate: when the candidate is slashed, all of its nominations (including the
+//! ones still un-bonding) are slashed by the same `SlashRatio`. Un-bonded nominations can be
+//! withdrawn after the next session change, or after the candidate's own un-bonding lock when the
+//! candidate already left, which mirrors the [`NonCandidates`] lock.
 //!
 //! ### Rewards
 //!
@@ -55,8 +96,30 @@
 //! - Half the value of the transaction fees within the block. The other half of the transaction
 //!   fees are deposited into the Pot.
 //!
+//! Alternatively, in [`RewardMode::PerSession`], authors are not paid per block. Instead, the
+//! accumulated pot is split at the end of each session between the session's collators. Each
+//! collator gets an equal part of the pot, scaled down by [`Config::PerformancePenalty`] applied
+//! to the ratio of its produced blocks to its assigned slots, i.e. the authored blocks plus the
+//! slots it missed ([`MissedSlots`]). Whatever is
+//! not paid out stays in the pot for the next session. The authorship counts are recorded in
+//! [`PerformanceHistory`] in both modes.
+//!
+//! The author's reward is shared with its nominators. The candidate first takes its
+//! [`Commission`], the rest is split between the candidate and its nominators pro rata to their
+//! stake.
+//!
 //! To initiate rewards an ED needs to be transferred to the pot address.
 //!
+//! ### Slashing
+//!
+//! Stale candidates are kicked and slashed by [`Config::SlashRatio`] together with their
+//! nominations. The slashed funds go to the [`SlashDestination`], or are burned if there's none.
+//! When [`SlashDeferDuration`] is set, the slashed funds are instead moved to the
+//! [`Pallet::slash_holding_account_id`] and recorded in [`DeferredSlashes`] for that many sessions,
+//! so the slashed accounts can't withdraw them in the meantime. Until then, the `UpdateOrigin`
+//! can cancel a slash with `cancel_deferred_slash` to refund the candidate and its nominators, e.g.
+//! for false positives caused by a relay chain stall.
+//!
 //! Note: Eventually the Pot distribution may be modified as discussed in
 //! [this issue](https://github.com/paritytech/statemint/issues/21#issuecomment-810481073).
 
@@ -72,12 +135,13 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
 pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
     pub use crate::weights::WeightInfo;
-    use core::ops::Div;
+    use core::{cmp::Reverse, ops::Div};
     use frame_support::{
         dispatch::{DispatchClass, DispatchResultWithPostInfo},
         pallet_prelude::*,
@@ -86,19 +150,23 @@ pub mod pallet {
             RuntimeDebug,
         },
         traits::{
-            Currency, EnsureOrigin, ExistenceRequirement::KeepAlive, ReservableCurrency,
-            ValidatorRegistration, ValidatorSet,
+            Currency, EnsureOrigin,
+            ExistenceRequirement::{AllowDeath, KeepAlive},
+            Imbalance, ReservableCurrency, ValidatorRegistration, ValidatorSet,
         },
         DefaultNoBound, PalletId,
     };
     use frame_system::{pallet_prelude::*, Config as SystemConfig};
     use pallet_session::SessionManager;
+    use sp_consensus_aura::{Slot, AURA_ENGINE_ID};
     use sp_runtime::{traits::Convert, Perbill};
     use sp_staking::SessionIndex;
-    use sp_std::prelude::*;
+    use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
     type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+    type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::NegativeImbalance;
 
     /// A convertor from collators id. Since this pallet does not have stash/controller, this is
     /// just identity.
@@ -115,6 +183,30 @@ pub mod pallet {
         fn allowed_candidacy(account: &AccountId) -> bool;
     }
 
+    /// Penalty curve which pays the square of the collator's performance, e.g. producing half of
+    /// the expected blocks yields a quarter of the reward.
+    pub struct QuadraticPenalty;
+    impl Convert<Perbill, Perbill> for QuadraticPenalty {
+        fn convert(performance: Perbill) -> Perbill {
+            performance.square()
+        }
+    }
+
+    /// Reads the Aura slot of the current block from its pre-runtime digest.
+    pub struct AuraDigestSlot<T>(PhantomData<T>);
+    impl<T: frame_system::Config> Get<u64> for AuraDigestSlot<T> {
+        fn get() -> u64 {
+            frame_system::Pallet::<T>::digest()
+                .logs
+                .iter()
+                .filter_map(|log| log.as_pre_runtime())
+                .find(|(id, _)| *id == AURA_ENGINE_ID)
+                .and_then(|(_, mut data)| Slot::decode(&mut data).ok())
+                .map(u64::from)
+                .unwrap_or_default()
+        }
+    }
+
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -130,8 +222,7 @@ pub mod pallet {
         /// Account Identifier from which the internal Pot is generated.
         type PotId: Get<PalletId>;
 
-        /// Maximum number of candidates that we should have. This is used for benchmarking and is not
-        /// enforced.
+        /// Maximum number of candidates, including the waiting list, that we can have.
         ///
         /// This does not take into account the invulnerables.
         type MaxCandidates: Get<u32>;
@@ -146,8 +237,16 @@ pub mod pallet {
         /// Used only for benchmarking.
         type MaxInvulnerables: Get<u32>;
 
-        /// Will be kicked if block is not produced in threshold.
-        type KickThreshold: Get<BlockNumberFor<Self>>;
+        /// Number of consecutive sessions with missed slots after which a candidate is kicked.
+        type KickThreshold: Get<SessionIndex>;
+
+        /// Share of its assigned slots a collator can miss in a session without it counting as a
+        /// missed session.
+        type MissedSlotsTolerance: Get<Perbill>;
+
+        /// Aura slot of the current block, `0` if unknown. It must be available in
+        /// `on_initialize`, see [`AuraDigestSlot`].
+        type CurrentSlot: Get<u64>;
 
         /// A stable ID for a validator.
         type ValidatorId: Member + Parameter;
@@ -169,6 +268,19 @@ pub mod pallet {
         /// Used to check whether an account is allowed to be a candidate.
         type AccountCheck: AccountCheck<Self::AccountId>;
 
+        /// Minimum amount a nominator must keep nominated behind a candidate.
+        type MinNomination: Get<BalanceOf<Self>>;
+
+        /// Maximum number of nominators a candidate can have.
+        type MaxNominators: Get<u32>;
+
+        /// Maps the ratio of produced to expected blocks in a session into the share of the
+        /// collator's session reward that is paid out.
+        type PerformancePenalty: Convert<Perbill, Perbill>;
+
+        /// Number of sessions to keep in the [`PerformanceHistory`].
+        type PerformanceHistoryDepth: Get<SessionIndex>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -182,7 +294,111 @@ pub mod pallet {
         pub deposit: Balance,
     }
 
+    /// The current storage version.
+    pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+    /// How the collators are rewarded from the pot.
+    #[derive(
+        PartialEq,
+        Eq,
+        Clone,
+        Copy,
+        Default,
+        Encode,
+        Decode,
+        RuntimeDebug,
+        scale_info::TypeInfo,
+        MaxEncodedLen,
+    )]
+    pub enum RewardMode {
+        /// The author of each block gets half of the pot.
+        #[default]
+        PerBlock,
+        /// The pot is split at the end of each session by the collators' performance.
+        PerSession,
+    }
+
+    /// Authorship performance of a collator in a session.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct CollatorPerformance<AccountId, Balance> {
+        /// Account identifier.
+        pub who: AccountId,
+        /// Number of blocks authored in the session.
+        pub authored: u32,
+        /// Number of blocks the collator was expected to author in the session, i.e. its assigned
+        /// slots which is the sum of its authored blocks & missed slots.
+        pub expected: u32,
+        /// Session reward paid to the collator and its nominators.
+        pub reward: Balance,
+    }
+
+    /// Slash of a candidate and its nominators. The slashed funds are held by the pallet until it's
+    /// applied.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct DeferredSlash<AccountId, Balance> {
+        /// Amount slashed from the candidate's deposit.
+        pub own: Balance,
+        /// Amounts slashed from the nominators.
+        pub nominators: Vec<(AccountId, Balance)>,
+    }
+
+    impl<AccountId, Balance: Saturating + Copy> DeferredSlash<AccountId, Balance> {
+        /// Total amount slashed from the candidate and its nominators.
+        pub fn total(&self) -> Balance {
+            self.nominators
+                .iter()
+                .fold(self.own, |acc, (_, amount)| acc.saturating_add(*amount))
+        }
+    }
+
+    /// Scheduled phase-out of the invulnerables.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct InvulnerablesPhaseOut {
+        /// Number of invulnerables to keep.
+        pub target: u32,
+        /// Number of sessions between two removals.
+        pub interval: SessionIndex,
+        /// Session from which the next invulnerable can be removed.
+        pub next_session: SessionIndex,
+    }
+
+    /// Status of a collation candidate, as exposed to the off-chain world.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct CandidateStatus<AccountId, Balance, BlockNumber> {
+        /// Account identifier.
+        pub who: AccountId,
+        /// Reserved deposit.
+        pub deposit: Balance,
+        /// Deposit plus the total amount nominated behind the candidate.
+        pub total_stake: Balance,
+        /// Whether the candidate is ranked high enough to be elected.
+        pub elected: bool,
+        /// Last block authored by the candidate.
+        pub last_authored_block: BlockNumber,
+        /// Number of assigned slots missed in the current session.
+        pub missed_slots: u32,
+        /// Number of consecutive sessions with missed slots.
+        pub missed_sessions: SessionIndex,
+        /// Number of further sessions with missed slots after which the candidate is kicked.
+        pub sessions_until_kick: SessionIndex,
+    }
+
+    /// Status of a candidate who left or was kicked and whose deposit is still un-bonding.
+    #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+    pub struct NonCandidateStatus<AccountId, Balance> {
+        /// Account identifier.
+        pub who: AccountId,
+        /// Reserved deposit.
+        pub deposit: Balance,
+        /// Session from which the deposit can be withdrawn.
+        pub unlock_session: SessionIndex,
+        /// Number of further sessions with missed slots after which the un-bonding deposit is
+        /// slashed.
+        pub sessions_until_kick: SessionIndex,
+    }
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
@@ -190,7 +406,9 @@ pub mod pallet {
     #[pallet::storage]
     pub type Invulnerables<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
-    /// The (community, limited) collation candidates.
+    /// The (community, limited) collation candidates, ranked by their deposit in descending order.
+    ///
+    /// The first [`DesiredCandidates`] are elected, the rest are waiting for a slot.
     #[pallet::storage]
     pub type Candidates<T: Config> =
         StorageValue<_, Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>, ValueQuery>;
@@ -205,15 +423,28 @@ pub mod pallet {
     pub type LastAuthoredBlock<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, ValueQuery>;
 
+    /// Aura slot of the last block.
+    #[pallet::storage]
+    pub type LastSlot<T> = StorageValue<_, u64, ValueQuery>;
+
+    /// Number of assigned slots missed by each collator in the current session.
+    #[pallet::storage]
+    pub type MissedSlots<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Number of consecutive sessions in which each collator missed too many of its slots.
+    #[pallet::storage]
+    pub type MissedSessions<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
+
     /// Desired number of candidates.
     ///
     /// This should ideally always be less than [`Config::MaxCandidates`] for weights to be correct.
     #[pallet::storage]
     pub type DesiredCandidates<T> = StorageValue<_, u32, ValueQuery>;
 
-    /// Fixed amount to deposit to become a collator.
+    /// Minimum amount to deposit to become a collator.
     ///
-    /// When a collator calls `leave_intent` they immediately receive the deposit back.
+    /// When a collator calls `leave_intent` the deposit starts un-bonding.
     #[pallet::storage]
     pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
@@ -221,6 +452,99 @@ pub mod pallet {
     #[pallet::storage]
     pub type SlashDestination<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+    /// Amount nominated by a nominator behind a candidate.
+    #[pallet::storage]
+    pub type Nominations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Number of nominators and the total amount nominated behind a candidate.
+    #[pallet::storage]
+    pub type TotalNominations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, (u32, BalanceOf<T>), ValueQuery>;
+
+    /// Nominations being un-bonded from a candidate, with the session index they can be withdrawn at.
+    #[pallet::storage]
+    pub type UnbondingNominations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        T::AccountId,
+        Twox64Concat,
+        T::AccountId,
+        (SessionIndex, BalanceOf<T>),
+        OptionQuery,
+    >;
+
+    /// How the collators are rewarded from the pot.
+    #[pallet::storage]
+    pub type CollatorRewardMode<T> = StorageValue<_, RewardMode, ValueQuery>;
+
+    /// Collators planned for a session, by session index.
+    #[pallet::storage]
+    pub type PlannedCollators<T: Config> =
+        StorageMap<_, Twox64Concat, SessionIndex, Vec<T::AccountId>, ValueQuery>;
+
+    /// Number of blocks authored by each collator in the current session.
+    #[pallet::storage]
+    pub type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Authorship performance of the collators in the last `PerformanceHistoryDepth` sessions.
+    #[pallet::storage]
+    pub type PerformanceHistory<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SessionIndex,
+        Vec<CollatorPerformance<T::AccountId, BalanceOf<T>>>,
+        ValueQuery,
+    >;
+
+    /// Number of sessions the slashed funds are held before being applied.
+    ///
+    /// Slashes are applied immediately when this is zero.
+    #[pallet::storage]
+    pub type SlashDeferDuration<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+    /// Slashes waiting to be applied, by the session they're applied in and the slashed candidate.
+    #[pallet::storage]
+    pub type DeferredSlashes<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SessionIndex,
+        Twox64Concat,
+        T::AccountId,
+        DeferredSlash<T::AccountId, BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    /// Share of the block reward a candidate keeps before splitting it with its nominators.
+    #[pallet::storage]
+    pub type Commission<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill, ValueQuery>;
+
+    /// The scheduled phase-out of the invulnerables, if any.
+    #[pallet::storage]
+    pub type PhaseOut<T> = StorageValue<_, InvulnerablesPhaseOut, OptionQuery>;
+
+    /// Number of consecutive sessions a candidate can be elected for while others are waiting.
+    ///
+    /// Term limits are disabled when this is zero.
+    #[pallet::storage]
+    pub type TermLimit<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+    /// Number of consecutive sessions each candidate was elected for.
+    #[pallet::storage]
+    pub type ServedSessions<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, ValueQuery>;
+
+    /// Candidates rotated out after reaching the term limit, in the order they get their seats back.
+    #[pallet::storage]
+    pub type RotationQueue<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -272,6 +596,48 @@ pub mod pallet {
         CandidateRemoved(T::AccountId),
         /// A candidate was slashed.
         CandidateSlashed(T::AccountId),
+        /// A candidate updated their bond.
+        CandidateBondUpdated(T::AccountId, BalanceOf<T>),
+        /// A new candidate took the slot of the lowest ranked candidate. [new, replaced, deposit]
+        CandidateReplaced(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// Funds were nominated behind a candidate. [nominator, candidate, amount]
+        Nominated(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// A nomination started un-bonding. [nominator, candidate, amount]
+        NominationUnbonded(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// An un-bonded nomination was withdrawn. [nominator, candidate, amount]
+        NominationWithdrawn(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// A nomination was slashed together with its candidate. [nominator, candidate, amount]
+        NominationSlashed(T::AccountId, T::AccountId, BalanceOf<T>),
+        /// A candidate set its commission.
+        CommissionSet(T::AccountId, Perbill),
+        /// The reward mode was set.
+        NewRewardMode(RewardMode),
+        /// Session rewards were paid to the collators. [session, total paid]
+        SessionRewardsPaid(SessionIndex, BalanceOf<T>),
+        /// The slash defer duration was set.
+        NewSlashDeferDuration(SessionIndex),
+        /// A slash was deferred. [candidate, apply session, total slashed]
+        SlashDeferred(T::AccountId, SessionIndex, BalanceOf<T>),
+        /// A deferred slash was cancelled and refunded. [candidate, apply session, total refunded]
+        DeferredSlashCancelled(T::AccountId, SessionIndex, BalanceOf<T>),
+        /// A deferred slash was applied. [candidate, total slashed]
+        DeferredSlashApplied(T::AccountId, BalanceOf<T>),
+        /// An invulnerable was added.
+        InvulnerableAdded(T::AccountId),
+        /// An invulnerable was removed.
+        InvulnerableRemoved(T::AccountId),
+        /// A phase-out of the invulnerables was scheduled. [target, interval]
+        PhaseOutScheduled(u32, SessionIndex),
+        /// The phase-out of the invulnerables was cancelled.
+        PhaseOutCancelled,
+        /// An invulnerable was phased out in favour of a waiting candidate. [invulnerable, desired candidates]
+        InvulnerablePhasedOut(T::AccountId, u32),
+        /// The phase-out reached its target number of invulnerables.
+        PhaseOutCompleted,
+        /// The term limit was set.
+        NewTermLimit(SessionIndex),
+        /// A candidate reached the term limit and was rotated out in favour of a waiting one.
+        CandidateRotated(T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -301,10 +667,36 @@ pub mod pallet {
         BondStillLocked,
         /// No candidacy bond available for withdrawal.
         NoCandidacyBond,
+        /// Deposit is below the candidacy bond or not enough to outbid the lowest ranked candidate.
+        DepositTooLow,
+        /// Elected or active collators cannot decrease their bond.
+        CannotDecreaseBond,
+        /// There are free candidate slots, `register_as_candidate` should be used instead.
+        CandidateListNotFull,
+        /// Nomination is below the minimum nomination.
+        NominationTooLow,
+        /// Candidate already has the maximum number of nominators.
+        TooManyNominators,
+        /// No nomination available for the candidate.
+        NoNomination,
+        /// No deferred slash of the candidate is applied in the given session.
+        NoDeferredSlash,
+        /// There are already the maximum number of invulnerables.
+        TooManyInvulnerables,
+        /// User is not an Invulnerable
+        NotInvulnerable,
+        /// The phase-out interval must be at least one session.
+        InvalidPhaseOutInterval,
+        /// No phase-out of the invulnerables is scheduled.
+        NoPhaseOut,
     }
 
     #[pallet::hooks]
-    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            T::WeightInfo::note_missed_slots(Self::note_missed_slots())
+        }
+    }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
@@ -373,6 +765,8 @@ pub mod pallet {
         /// Register this account as a collator candidate. The account must (a) already have
         /// registered session keys and (b) be able to reserve the `CandidacyBond`.
         ///
+        /// The candidate is ranked by the reserved deposit, see `update_bond` to bond more.
+        ///
         /// This call is not available to `Invulnerable` collators.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::register_as_candidate(T::MaxCandidates::get()))]
@@ -382,59 +776,13 @@ pub mod pallet {
             // ensure we are below limit.
             let length = <Candidates<T>>::decode_len().unwrap_or_default();
             ensure!(
-                (length as u32) < DesiredCandidates::<T>::get(),
+                (length as u32) < T::MaxCandidates::get(),
                 Error::<T>::TooManyCandidates
             );
-            ensure!(
-                !Invulnerables::<T>::get().contains(&who),
-                Error::<T>::AlreadyInvulnerable
-            );
-            ensure!(
-                T::AccountCheck::allowed_candidacy(&who),
-                Error::<T>::NotAllowedCandidate
-            );
-
-            let validator_key = T::ValidatorIdOf::convert(who.clone())
-                .ok_or(Error::<T>::NoAssociatedValidatorId)?;
-            ensure!(
-                T::ValidatorRegistration::is_registered(&validator_key),
-                Error::<T>::ValidatorNotRegistered
-            );
-
-            // ensure candidacy has no previous locked un-bonding
-            <NonCandidates<T>>::try_mutate_exists(&who, |maybe| -> DispatchResult {
-                if let Some((index, deposit)) = maybe.take() {
-                    ensure!(
-                        T::ValidatorSet::session_index() >= index,
-                        Error::<T>::BondStillLocked
-                    );
-                    // unreserve previous deposit and continue with registration
-                    T::Currency::unreserve(&who, deposit);
-                }
-                Ok(())
-            })?;
+            Self::ensure_can_register(&who)?;
 
             let deposit = CandidacyBond::<T>::get();
-            // First authored block is current block plus kick threshold to handle session delay
-            let incoming = CandidateInfo {
-                who: who.clone(),
-                deposit,
-            };
-
-            let current_count =
-                <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
-                    if candidates.iter_mut().any(|candidate| candidate.who == who) {
-                        Err(Error::<T>::AlreadyCandidate)?
-                    } else {
-                        T::Currency::reserve(&who, deposit)?;
-                        candidates.push(incoming);
-                        <LastAuthoredBlock<T>>::insert(
-                            &who,
-                            frame_system::Pallet::<T>::block_number() + T::KickThreshold::get(),
-                        );
-                        Ok(candidates.len())
-                    }
-                })?;
+            let current_count = Self::do_add_candidate(&who, deposit)?;
 
             Self::deposit_event(Event::CandidateAdded(who, deposit));
             Ok(Some(T::WeightInfo::register_as_candidate(current_count as u32)).into())
@@ -473,6 +821,7 @@ pub mod pallet {
                     );
                     T::Currency::unreserve(&who, deposit);
                     <LastAuthoredBlock<T>>::remove(&who);
+                    <MissedSessions<T>>::remove(&who);
                     Ok(())
                 } else {
                     Err(Error::<T>::NoCandidacyBond.into())
@@ -497,6 +846,413 @@ pub mod pallet {
             }
             Ok(())
         }
+
+        /// Update the bond of a candidate to `new_deposit`, re-ranking the candidate accordingly.
+        ///
+        /// The bond cannot be lower than the `CandidacyBond`. Candidates that are elected for the
+        /// next session or are currently collating cannot decrease their bond.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::update_bond(T::MaxCandidates::get()))]
+        pub fn update_bond(
+            origin: OriginFor<T>,
+            new_deposit: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                new_deposit >= CandidacyBond::<T>::get(),
+                Error::<T>::DepositTooLow
+            );
+
+            let current_count =
+                <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
+                    let index = candidates
+                        .iter()
+                        .position(|candidate| candidate.who == who)
+                        .ok_or(Error::<T>::NotCandidate)?;
+                    let mut candidate = candidates.remove(index);
+
+                    if new_deposit > candidate.deposit {
+                        T::Currency::reserve(&who, new_deposit.saturating_sub(candidate.deposit))?;
+                    } else if new_deposit < candidate.deposit {
+                        ensure!(
+                            !Self::elected_candidates().contains(&who)
+                                && !T::ValidatorSet::validators().contains(&who),
+                            Error::<T>::CannotDecreaseBond
+                        );
+                        T::Currency::unreserve(&who, candidate.deposit.saturating_sub(new_deposit));
+                    }
+
+                    candidate.deposit = new_deposit;
+                    Self::insert_ranked(candidates, candidate);
+                    Ok(candidates.len())
+                })?;
+
+            Self::deposit_event(Event::CandidateBondUpdated(who, new_deposit));
+            Ok(Some(T::WeightInfo::update_bond(current_count as u32)).into())
+        }
+
+        /// Register this account as a collator candidate by taking the slot of the lowest ranked
+        /// candidate, when there are no free slots left.
+        ///
+        /// The `deposit` must be above the total stake of the lowest ranked candidate, whose bond
+        /// starts un-bonding as if they called `leave_intent`. The same requirements as for
+        /// `register_as_candidate` apply.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::take_candidate_slot(T::MaxCandidates::get()))]
+        pub fn take_candidate_slot(origin: OriginFor<T>, deposit: BalanceOf<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let candidates = <Candidates<T>>::get();
+            ensure!(
+                (candidates.len() as u32) >= T::MaxCandidates::get(),
+                Error::<T>::CandidateListNotFull
+            );
+            ensure!(
+                !candidates.iter().any(|candidate| candidate.who == who),
+                Error::<T>::AlreadyCandidate
+            );
+            ensure!(
+                deposit >= CandidacyBond::<T>::get(),
+                Error::<T>::DepositTooLow
+            );
+            let lowest = candidates.last().ok_or(Error::<T>::CandidateListNotFull)?;
+            ensure!(
+                deposit > Self::total_stake(lowest),
+                Error::<T>::DepositTooLow
+            );
+            let replaced = lowest.who.clone();
+            Self::ensure_can_register(&who)?;
+
+            Self::try_remove_candidate(&replaced)?;
+            Self::do_add_candidate(&who, deposit)?;
+
+            Self::deposit_event(Event::CandidateReplaced(who, replaced, deposit));
+            Ok(())
+        }
+
+        /// Nominate `amount` behind `candidate`, increasing its stake.
+        ///
+        /// The total nomination of the origin behind the candidate must be at least
+        /// `MinNomination`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::nominate(T::MaxCandidates::get()))]
+        pub fn nominate(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let current_count =
+                <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
+                    let index = candidates
+                        .iter()
+                        .position(|c| c.who == candidate)
+                        .ok_or(Error::<T>::NotCandidate)?;
+
+                    let nominated = <Nominations<T>>::get(&candidate, &who);
+                    let is_new = nominated.is_none();
+                    ensure!(
+                        !is_new
+                            || TotalNominations::<T>::get(&candidate).0 < T::MaxNominators::get(),
+                        Error::<T>::TooManyNominators
+                    );
+                    let nominated = nominated.unwrap_or_else(Zero::zero).saturating_add(amount);
+                    ensure!(
+                        nominated >= T::MinNomination::get(),
+                        Error::<T>::NominationTooLow
+                    );
+
+                    T::Currency::reserve(&who, amount)?;
+                    <Nominations<T>>::insert(&candidate, &who, nominated);
+                    TotalNominations::<T>::mutate(&candidate, |(count, total)| {
+                        if is_new {
+                            count.saturating_inc();
+                        }
+                        total.saturating_accrue(amount);
+                    });
+
+                    let info = candidates.remove(index);
+                    Self::insert_ranked(candidates, info);
+                    Ok(candidates.len())
+                })?;
+
+            Self::deposit_event(Event::Nominated(who, candidate, amount));
+            Ok(Some(T::WeightInfo::nominate(current_count as u32)).into())
+        }
+
+        /// Start un-bonding `amount` nominated behind `candidate`.
+        ///
+        /// The remaining nomination must either be zero or at least `MinNomination`. Un-bonded
+        /// funds can be withdrawn with `withdraw_nomination` on the next session, or after the
+        /// candidate's own un-bonding lock if the candidate already left.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::unbond_nomination(T::MaxCandidates::get()))]
+        pub fn unbond_nomination(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let (amount, is_removed) = <Nominations<T>>::try_mutate_exists(
+                &candidate,
+                &who,
+                |maybe| -> Result<(BalanceOf<T>, bool), DispatchError> {
+                    let nominated = maybe.ok_or(Error::<T>::NoNomination)?;
+                    let amount = amount.min(nominated);
+                    let remaining = nominated.saturating_sub(amount);
+                    ensure!(
+                        remaining.is_zero() || remaining >= T::MinNomination::get(),
+                        Error::<T>::NominationTooLow
+                    );
+                    *maybe = Some(remaining).filter(|r| !r.is_zero());
+                    Ok((amount, maybe.is_none()))
+                },
+            )?;
+
+            <TotalNominations<T>>::mutate_exists(&candidate, |maybe| {
+                if let Some((count, total)) = maybe {
+                    if is_removed {
+                        count.saturating_dec();
+                    }
+                    total.saturating_reduce(amount);
+                    if count.is_zero() {
+                        *maybe = None;
+                    }
+                }
+            });
+
+            // un-bonding is aligned with the candidate's own un-bonding lock
+            let mut unlock_at = T::ValidatorSet::session_index().saturating_add(1);
+            if let Some((candidate_unlock_at, _)) = NonCandidates::<T>::get(&candidate) {
+                unlock_at = unlock_at.max(candidate_unlock_at);
+            }
+            <UnbondingNominations<T>>::mutate(&candidate, &who, |maybe| {
+                let unbonding = maybe.map(|(_, u)| u).unwrap_or_else(Zero::zero);
+                *maybe = Some((unlock_at, unbonding.saturating_add(amount)));
+            });
+
+            let current_count = <Candidates<T>>::mutate(|candidates| {
+                if let Some(index) = candidates.iter().position(|c| c.who == candidate) {
+                    let info = candidates.remove(index);
+                    Self::insert_ranked(candidates, info);
+                }
+                candidates.len()
+            });
+
+            Self::deposit_event(Event::NominationUnbonded(who, candidate, amount));
+            Ok(Some(T::WeightInfo::unbond_nomination(current_count as u32)).into())
+        }
+
+        /// Withdraw the nomination un-bonded from `candidate` after the un-bonding period has
+        /// finished.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::withdraw_nomination())]
+        pub fn withdraw_nomination(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let amount = <UnbondingNominations<T>>::try_mutate_exists(
+                &candidate,
+                &who,
+                |maybe| -> Result<BalanceOf<T>, DispatchError> {
+                    let (index, amount) = maybe.take().ok_or(Error::<T>::NoNomination)?;
+                    ensure!(
+                        T::ValidatorSet::session_index() >= index,
+                        Error::<T>::BondStillLocked
+                    );
+                    T::Currency::unreserve(&who, amount);
+                    Ok(amount)
+                },
+            )?;
+
+            Self::deposit_event(Event::NominationWithdrawn(who, candidate, amount));
+            Ok(())
+        }
+
+        /// Set the share of the block rewards the candidate keeps before splitting the rest with
+        /// its nominators.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_commission())]
+        pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_account_candidate(&who), Error::<T>::NotCandidate);
+
+            <Commission<T>>::insert(&who, commission);
+            Self::deposit_event(Event::CommissionSet(who, commission));
+            Ok(())
+        }
+
+        /// Set how the collators are rewarded from the pot.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::set_reward_mode())]
+        pub fn set_reward_mode(origin: OriginFor<T>, mode: RewardMode) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <CollatorRewardMode<T>>::put(mode);
+            Self::deposit_event(Event::NewRewardMode(mode));
+            Ok(())
+        }
+
+        /// Set the number of sessions the slashed funds are held before being applied.
+        /// Use `0` to apply slashes immediately. Already deferred slashes are not affected.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::set_slash_defer_duration())]
+        pub fn set_slash_defer_duration(
+            origin: OriginFor<T>,
+            sessions: SessionIndex,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <SlashDeferDuration<T>>::put(sessions);
+            Self::deposit_event(Event::NewSlashDeferDuration(sessions));
+            Ok(())
+        }
+
+        /// Cancel a deferred slash of `who` which would be applied in `apply_session`.
+        /// The slashed funds are refunded to the candidate and its nominators.
+        // The slash covers both the active and the un-bonding nominations.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::cancel_deferred_slash(T::MaxNominators::get().saturating_mul(2)))]
+        pub fn cancel_deferred_slash(
+            origin: OriginFor<T>,
+            apply_session: SessionIndex,
+            who: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            let slash = DeferredSlashes::<T>::take(apply_session, &who)
+                .ok_or(Error::<T>::NoDeferredSlash)?;
+
+            let total = slash.total();
+            let nominators = slash.nominators.len() as u32;
+            let holding = Self::slash_holding_account_id();
+            T::Currency::transfer(&holding, &who, slash.own, AllowDeath)?;
+            for (nominator, amount) in slash.nominators {
+                T::Currency::transfer(&holding, &nominator, amount, AllowDeath)?;
+            }
+
+            Self::deposit_event(Event::DeferredSlashCancelled(who, apply_session, total));
+            Ok(Some(T::WeightInfo::cancel_deferred_slash(nominators)).into())
+        }
+
+        /// Add `who` to the invulnerables. If `who` is a candidate, its candidacy is removed and
+        /// the deposit starts un-bonding.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::add_invulnerable(
+            T::MaxInvulnerables::get().saturating_sub(1),
+            T::MaxCandidates::get(),
+        ))]
+        pub fn add_invulnerable(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            // check if the invulnerable has associated validator keys before it is added
+            let validator_key = T::ValidatorIdOf::convert(who.clone())
+                .ok_or(Error::<T>::NoAssociatedValidatorId)?;
+            ensure!(
+                T::ValidatorRegistration::is_registered(&validator_key),
+                Error::<T>::ValidatorNotRegistered
+            );
+
+            let invulnerables = <Invulnerables<T>>::try_mutate(|invulnerables| {
+                ensure!(
+                    !invulnerables.contains(&who),
+                    Error::<T>::AlreadyInvulnerable
+                );
+                ensure!(
+                    (invulnerables.len() as u32) < T::MaxInvulnerables::get(),
+                    Error::<T>::TooManyInvulnerables
+                );
+                invulnerables.push(who.clone());
+                Ok::<_, DispatchError>(invulnerables.len() as u32)
+            })?;
+
+            let candidates = Candidates::<T>::decode_len().unwrap_or_default() as u32;
+            // an invulnerable doesn't need a candidacy
+            let _ = Self::try_remove_candidate(&who);
+
+            Self::deposit_event(Event::InvulnerableAdded(who));
+            Ok(Some(T::WeightInfo::add_invulnerable(
+                invulnerables.saturating_sub(1),
+                candidates,
+            ))
+            .into())
+        }
+
+        /// Remove `who` from the invulnerables.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::remove_invulnerable(T::MaxInvulnerables::get()))]
+        pub fn remove_invulnerable(
+            origin: OriginFor<T>,
+            who: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+
+            let invulnerables = <Invulnerables<T>>::try_mutate(|invulnerables| {
+                let index = invulnerables
+                    .iter()
+                    .position(|invulnerable| *invulnerable == who)
+                    .ok_or(Error::<T>::NotInvulnerable)?;
+                invulnerables.remove(index);
+                Ok::<_, DispatchError>(invulnerables.len() as u32)
+            })?;
+
+            Self::deposit_event(Event::InvulnerableRemoved(who));
+            Ok(Some(T::WeightInfo::remove_invulnerable(
+                invulnerables.saturating_add(1),
+            ))
+            .into())
+        }
+
+        /// Schedule a phase-out of the invulnerables down to `target`, removing at most one every
+        /// `interval` sessions while waiting candidates can take their seats.
+        /// Replaces any previously scheduled phase-out.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::schedule_phase_out())]
+        pub fn schedule_phase_out(
+            origin: OriginFor<T>,
+            target: u32,
+            interval: SessionIndex,
+        ) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            ensure!(!interval.is_zero(), Error::<T>::InvalidPhaseOutInterval);
+
+            <PhaseOut<T>>::put(InvulnerablesPhaseOut {
+                target,
+                interval,
+                next_session: T::ValidatorSet::session_index().saturating_add(interval),
+            });
+            Self::deposit_event(Event::PhaseOutScheduled(target, interval));
+            Ok(())
+        }
+
+        /// Cancel the scheduled phase-out of the invulnerables.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::cancel_phase_out())]
+        pub fn cancel_phase_out(origin: OriginFor<T>) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <PhaseOut<T>>::take().ok_or(Error::<T>::NoPhaseOut)?;
+            Self::deposit_event(Event::PhaseOutCancelled);
+            Ok(())
+        }
+
+        /// Set the number of consecutive sessions a candidate can be elected for while other
+        /// candidates are waiting. Use `0` to disable term limits, which also clears the
+        /// [`RotationQueue`].
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_term_limit())]
+        pub fn set_term_limit(origin: OriginFor<T>, sessions: SessionIndex) -> DispatchResult {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <TermLimit<T>>::put(sessions);
+            if sessions.is_zero() {
+                <RotationQueue<T>>::kill();
+            }
+            Self::deposit_event(Event::NewTermLimit(sessions));
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -505,6 +1261,154 @@ pub mod pallet {
             T::PotId::get().into_account_truncating()
         }
 
+        /// Get the account holding the deferred slashes until they are applied or cancelled.
+        pub fn slash_holding_account_id() -> T::AccountId {
+            T::PotId::get().into_sub_account_truncating(b"slash")
+        }
+
+        /// Ensure `who` is allowed to register as a candidate and release any previous, already
+        /// un-bonded deposit.
+        fn ensure_can_register(who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                !Invulnerables::<T>::get().contains(who),
+                Error::<T>::AlreadyInvulnerable
+            );
+            ensure!(
+                T::AccountCheck::allowed_candidacy(who),
+                Error::<T>::NotAllowedCandidate
+            );
+
+            let validator_key = T::ValidatorIdOf::convert(who.clone())
+                .ok_or(Error::<T>::NoAssociatedValidatorId)?;
+            ensure!(
+                T::ValidatorRegistration::is_registered(&validator_key),
+                Error::<T>::ValidatorNotRegistered
+            );
+
+            // ensure candidacy has no previous locked un-bonding
+            <NonCandidates<T>>::try_mutate_exists(who, |maybe| -> DispatchResult {
+                if let Some((index, deposit)) = maybe.take() {
+                    ensure!(
+                        T::ValidatorSet::session_index() >= index,
+                        Error::<T>::BondStillLocked
+                    );
+                    // unreserve previous deposit and continue with registration
+                    T::Currency::unreserve(who, deposit);
+                }
+                Ok(())
+            })
+        }
+
+        /// Reserve `deposit` and insert `who` into the ranked candidates list.
+        /// Returns the new number of candidates.
+        fn do_add_candidate(
+            who: &T::AccountId,
+            deposit: BalanceOf<T>,
+        ) -> Result<usize, DispatchError> {
+            <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
+                if candidates.iter().any(|candidate| candidate.who == *who) {
+                    Err(Error::<T>::AlreadyCandidate)?
+                } else {
+                    T::Currency::reserve(who, deposit)?;
+                    Self::insert_ranked(
+                        candidates,
+                        CandidateInfo {
+                            who: who.clone(),
+                            deposit,
+                        },
+                    );
+                    Ok(candidates.len())
+                }
+            })
+        }
+
+        /// Insert the candidate after all the candidates with the same or higher total stake.
+        pub(crate) fn insert_ranked(
+            candidates: &mut Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
+            candidate: CandidateInfo<T::AccountId, BalanceOf<T>>,
+        ) {
+            let stake = Self::total_stake(&candidate);
+            let index = candidates.partition_point(|c| Self::total_stake(c) >= stake);
+            candidates.insert(index, candidate);
+        }
+
+        /// Candidate's own deposit plus the total amount nominated behind it.
+        pub fn total_stake(candidate: &CandidateInfo<T::AccountId, BalanceOf<T>>) -> BalanceOf<T> {
+            candidate
+                .deposit
+                .saturating_add(TotalNominations::<T>::get(&candidate.who).1)
+        }
+
+        /// The candidates elected for the next session, i.e. the top [`DesiredCandidates`] ranked
+        /// by their deposit, subject to the [`TermLimit`].
+        pub fn elected_candidates() -> Vec<T::AccountId> {
+            let desired = DesiredCandidates::<T>::get() as usize;
+            let candidates = Candidates::<T>::get()
+                .into_iter()
+                .map(|c| c.who)
+                .collect::<Vec<_>>();
+            let term_limit = TermLimit::<T>::get();
+            if term_limit.is_zero() {
+                return candidates.into_iter().take(desired).collect();
+            }
+
+            let queue = RotationQueue::<T>::get()
+                .into_iter()
+                .filter(|who| candidates.contains(who))
+                .collect::<Vec<_>>();
+            let (expired, serving): (Vec<_>, Vec<_>) = candidates
+                .into_iter()
+                .filter(|who| !queue.contains(who))
+                .partition(|who| ServedSessions::<T>::get(who) >= term_limit);
+            serving
+                .into_iter()
+                .chain(queue)
+                .chain(expired)
+                .take(desired)
+                .collect()
+        }
+
+        /// Number of further sessions with missed slots after which `who` is kicked.
+        pub fn sessions_until_kick(who: &T::AccountId) -> SessionIndex {
+            T::KickThreshold::get().saturating_sub(MissedSessions::<T>::get(who))
+        }
+
+        /// Status of all the candidates, in ranking order.
+        pub fn candidate_statuses(
+        ) -> Vec<CandidateStatus<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let elected = Self::elected_candidates();
+            Candidates::<T>::get()
+                .into_iter()
+                .map(|candidate| CandidateStatus {
+                    total_stake: Self::total_stake(&candidate),
+                    elected: elected.contains(&candidate.who),
+                    last_authored_block: LastAuthoredBlock::<T>::get(&candidate.who),
+                    missed_slots: MissedSlots::<T>::get(&candidate.who),
+                    missed_sessions: MissedSessions::<T>::get(&candidate.who),
+                    sessions_until_kick: Self::sessions_until_kick(&candidate.who),
+                    who: candidate.who,
+                    deposit: candidate.deposit,
+                })
+                .collect()
+        }
+
+        /// Status of all the un-bonding non-candidates.
+        pub fn non_candidate_statuses() -> Vec<NonCandidateStatus<T::AccountId, BalanceOf<T>>> {
+            NonCandidates::<T>::iter()
+                .map(|(who, (unlock_session, deposit))| NonCandidateStatus {
+                    sessions_until_kick: Self::sessions_until_kick(&who),
+                    who,
+                    deposit,
+                    unlock_session,
+                })
+                .collect()
+        }
+
+        /// The collators planned for the next session.
+        pub fn next_session_collators() -> Vec<T::AccountId> {
+            PlannedCollators::<T>::get(T::ValidatorSet::session_index().saturating_add(1))
+        }
+
         /// Removes a candidate if they exist. Start deposit un-bonding
         fn try_remove_candidate(who: &T::AccountId) -> Result<usize, DispatchError> {
             let current_count =
@@ -517,6 +1421,7 @@ pub mod pallet {
                     let candidate = candidates.remove(index);
                     let session_index = T::ValidatorSet::session_index().saturating_add(1);
                     <NonCandidates<T>>::insert(&who, (session_index, candidate.deposit));
+                    <ServedSessions<T>>::remove(who);
                     Ok(candidates.len())
                 })?;
             Self::deposit_event(Event::CandidateRemoved(who.clone()));
@@ -524,24 +1429,183 @@ pub mod pallet {
         }
 
         /// Slash candidate deposit and return the rest of funds.
-        fn slash_non_candidate(who: &T::AccountId) {
+        /// Nominations behind the candidate are slashed by the same ratio.
+        /// When the [`SlashDeferDuration`] is set, the slashed funds are held until it's applied.
+        /// Returns the number of slashed nominations.
+        fn slash_non_candidate(who: &T::AccountId) -> u32 {
             NonCandidates::<T>::mutate_exists(who, |maybe| {
                 if let Some((_index, deposit)) = maybe.take() {
                     let slash = T::SlashRatio::get() * deposit;
                     let remain = deposit.saturating_sub(slash);
 
-                    let (imbalance, _) = T::Currency::slash_reserved(who, slash);
+                    let (mut slashed, _) = T::Currency::slash_reserved(who, slash);
+                    let own = slashed.peek();
                     T::Currency::unreserve(who, remain);
 
-                    if let Some(dest) = SlashDestination::<T>::get() {
-                        T::Currency::resolve_creating(&dest, imbalance);
-                    }
-
                     <LastAuthoredBlock<T>>::remove(who);
+                    <MissedSessions<T>>::remove(who);
 
                     Self::deposit_event(Event::CandidateSlashed(who.clone()));
+                    let (nominators, nominations_slashed) = Self::slash_nominations(who);
+                    slashed.subsume(nominations_slashed);
+                    let slashed_count = nominators.len() as u32;
+
+                    let defer_duration = SlashDeferDuration::<T>::get();
+                    if defer_duration.is_zero() {
+                        Self::resolve_slash(slashed);
+                        return slashed_count;
+                    }
+                    if let Err(slashed) = Self::hold_slash(slashed) {
+                        // too little to be held in an empty holding account, applied right away
+                        Self::resolve_slash(slashed);
+                        return slashed_count;
+                    }
+
+                    let apply_session =
+                        T::ValidatorSet::session_index().saturating_add(defer_duration);
+                    let total = DeferredSlashes::<T>::mutate(apply_session, who, |maybe| {
+                        let slash = maybe.get_or_insert_with(|| DeferredSlash {
+                            own: Zero::zero(),
+                            nominators: Vec::new(),
+                        });
+                        slash.own.saturating_accrue(own);
+                        slash.nominators.extend(nominators);
+                        slash.total()
+                    });
+                    Self::deposit_event(Event::SlashDeferred(who.clone(), apply_session, total));
+                    slashed_count
+                } else {
+                    0
                 }
-            });
+            })
+        }
+
+        /// Slash the active and still locked un-bonding nominations behind `candidate`.
+        /// Returns the slashed nominators and amounts, together with the slashed funds.
+        fn slash_nominations(
+            candidate: &T::AccountId,
+        ) -> (Vec<(T::AccountId, BalanceOf<T>)>, NegativeImbalanceOf<T>) {
+            let session_index = T::ValidatorSet::session_index();
+            let mut slashed_nominators = Vec::new();
+            let mut slashed_funds = NegativeImbalanceOf::<T>::zero();
+
+            let mut total_slashed: BalanceOf<T> = Zero::zero();
+            for (nominator, amount) in Nominations::<T>::iter_prefix(candidate).collect::<Vec<_>>()
+            {
+                let (imbalance, _) =
+                    T::Currency::slash_reserved(&nominator, T::SlashRatio::get() * amount);
+                let slashed = imbalance.peek();
+                slashed_funds.subsume(imbalance);
+                Nominations::<T>::insert(candidate, &nominator, amount.saturating_sub(slashed));
+                total_slashed.saturating_accrue(slashed);
+                slashed_nominators.push((nominator.clone(), slashed));
+                Self::deposit_event(Event::NominationSlashed(
+                    nominator,
+                    candidate.clone(),
+                    slashed,
+                ));
+            }
+            if !total_slashed.is_zero() {
+                TotalNominations::<T>::mutate(candidate, |(_, total)| {
+                    total.saturating_reduce(total_slashed)
+                });
+            }
+
+            for (nominator, (unlock_at, amount)) in
+                UnbondingNominations::<T>::iter_prefix(candidate).collect::<Vec<_>>()
+            {
+                if session_index > unlock_at {
+                    continue;
+                }
+                let (imbalance, _) =
+                    T::Currency::slash_reserved(&nominator, T::SlashRatio::get() * amount);
+                let slashed = imbalance.peek();
+                slashed_funds.subsume(imbalance);
+                UnbondingNominations::<T>::insert(
+                    candidate,
+                    &nominator,
+                    (unlock_at, amount.saturating_sub(slashed)),
+                );
+                slashed_nominators.push((nominator.clone(), slashed));
+                Self::deposit_event(Event::NominationSlashed(
+                    nominator,
+                    candidate.clone(),
+                    slashed,
+                ));
+            }
+            (slashed_nominators, slashed_funds)
+        }
+
+        /// Send slashed funds to the slash destination, or burn them if there's none.
+        fn resolve_slash(slashed: NegativeImbalanceOf<T>) {
+            if let Some(dest) = SlashDestination::<T>::get() {
+                T::Currency::resolve_creating(&dest, slashed);
+            }
+        }
+
+        /// Move slashed funds into the [`Pallet::slash_holding_account_id`] until the slash is
+        /// applied or cancelled. Funds are returned if the holding account can't be created
+        /// with them, i.e. they are below the existential deposit.
+        fn hold_slash(slashed: NegativeImbalanceOf<T>) -> Result<(), NegativeImbalanceOf<T>> {
+            let holding = Self::slash_holding_account_id();
+            T::Currency::resolve_into_existing(&holding, slashed).or_else(|slashed| {
+                if slashed.peek() < T::Currency::minimum_balance() {
+                    return Err(slashed);
+                }
+                T::Currency::resolve_creating(&holding, slashed);
+                Ok(())
+            })
+        }
+
+        /// Apply the deferred slashes due in session `index`, paying them from the holding account.
+        /// Returns the number of applied slashes.
+        fn apply_deferred_slashes(index: SessionIndex) -> u32 {
+            let holding = Self::slash_holding_account_id();
+            let mut count = 0u32;
+            for (who, slash) in DeferredSlashes::<T>::drain_prefix(index) {
+                let (slashed, _) = T::Currency::slash(&holding, slash.total());
+                let total = slashed.peek();
+                Self::resolve_slash(slashed);
+                Self::deposit_event(Event::DeferredSlashApplied(who, total));
+                count.saturating_inc();
+            }
+            count
+        }
+
+        /// Pay `reward` from the pot to the `author` and its nominators.
+        ///
+        /// The author keeps its commission, the rest is split pro rata to the author's own bond
+        /// and the nominations. Returns the number of rewarded nominators.
+        fn pay_reward(pot: &T::AccountId, author: &T::AccountId, reward: BalanceOf<T>) -> u32 {
+            let (nominators, total_nominated) = TotalNominations::<T>::get(author);
+            if nominators.is_zero() || reward.is_zero() {
+                // `reward` is half of pot account minus ED, this should never fail.
+                let _success = T::Currency::transfer(pot, author, reward, KeepAlive);
+                debug_assert!(_success.is_ok());
+                return 0;
+            }
+
+            let own_stake = Candidates::<T>::get()
+                .into_iter()
+                .find(|c| &c.who == author)
+                .map(|c| c.deposit)
+                .or_else(|| NonCandidates::<T>::get(author).map(|(_, deposit)| deposit))
+                .unwrap_or_else(Zero::zero);
+            let total_stake = own_stake.saturating_add(total_nominated);
+
+            let shared = reward.saturating_sub(Commission::<T>::get(author) * reward);
+            let mut distributed: BalanceOf<T> = Zero::zero();
+            for (nominator, amount) in Nominations::<T>::iter_prefix(author) {
+                let share = Perbill::from_rational(amount, total_stake).mul_floor(shared);
+                if T::Currency::transfer(pot, &nominator, share, KeepAlive).is_ok() {
+                    distributed.saturating_accrue(share);
+                }
+            }
+
+            let _success =
+                T::Currency::transfer(pot, author, reward.saturating_sub(distributed), KeepAlive);
+            debug_assert!(_success.is_ok());
+            nominators
         }
 
         /// Assemble the current set of candidates and invulnerables into the next collator set.
@@ -552,39 +1616,220 @@ pub mod pallet {
             collators.extend(candidates.into_iter());
             collators
         }
-        /// Kicks out and candidates that did not produce a block in the kick threshold.
-        /// Return length of candidates before and number of kicked candidates.
-        pub fn kick_stale_candidates() -> (u32, u32) {
-            let now = frame_system::Pallet::<T>::block_number();
-            let kick_threshold = T::KickThreshold::get();
-            let count = Candidates::<T>::get().len() as u32;
-            for (who, last_authored) in LastAuthoredBlock::<T>::iter() {
-                if now.saturating_sub(last_authored) < kick_threshold {
-                    continue;
+        /// Track the consecutive sessions served by the `elected` candidates and queue the ones
+        /// rotated out after reaching the [`TermLimit`].
+        fn note_elected(elected: &[T::AccountId]) {
+            let term_limit = TermLimit::<T>::get();
+            let candidates = Candidates::<T>::get();
+            let mut queue = RotationQueue::<T>::get();
+            queue.retain(|who| !elected.contains(who) && candidates.iter().any(|c| c.who == *who));
+
+            for who in candidates.into_iter().map(|c| c.who) {
+                if elected.contains(&who) {
+                    ServedSessions::<T>::mutate(&who, |served| served.saturating_inc());
+                } else {
+                    let served = ServedSessions::<T>::take(&who);
+                    if !term_limit.is_zero() && served >= term_limit {
+                        queue.push(who.clone());
+                        Self::deposit_event(Event::CandidateRotated(who));
+                    }
                 }
+            }
+            RotationQueue::<T>::put(queue);
+        }
+
+        /// Remove the last invulnerable in favour of a waiting candidate when the scheduled
+        /// phase-out is due for the planned session `index`.
+        fn phase_out_invulnerable(index: SessionIndex) {
+            let Some(mut phase_out) = PhaseOut::<T>::get() else {
+                return;
+            };
+            let mut invulnerables = Invulnerables::<T>::get();
+            if invulnerables.len() as u32 <= phase_out.target {
+                PhaseOut::<T>::kill();
+                Self::deposit_event(Event::PhaseOutCompleted);
+                return;
+            }
+
+            let desired = DesiredCandidates::<T>::get();
+            let waiting = Candidates::<T>::decode_len().unwrap_or_default() as u32 > desired;
+            if index < phase_out.next_session || !waiting {
+                return;
+            }
+
+            if let Some(who) = invulnerables.pop() {
+                let desired = desired.saturating_add(1);
+                Invulnerables::<T>::put(&invulnerables);
+                DesiredCandidates::<T>::put(desired);
+                Self::deposit_event(Event::InvulnerablePhasedOut(who, desired));
+            }
+
+            if invulnerables.len() as u32 <= phase_out.target {
+                PhaseOut::<T>::kill();
+                Self::deposit_event(Event::PhaseOutCompleted);
+            } else {
+                phase_out.next_session = index.saturating_add(phase_out.interval);
+                PhaseOut::<T>::put(phase_out);
+            }
+        }
+
+        /// Kicks out the candidates who missed their slots in [`Config::KickThreshold`]
+        /// consecutive sessions.
+        /// The most stale candidates are kicked first, ties are broken by the ranking so the
+        /// lowest ranked candidates are kicked first.
+        /// Return length of candidates before, number of kicked candidates and number of
+        /// slashed nominations.
+        pub fn kick_stale_candidates() -> (u32, u32, u32) {
+            let kick_threshold = T::KickThreshold::get();
+            let candidates = Candidates::<T>::get();
+            let count = candidates.len() as u32;
+            let rank = |who: &T::AccountId| candidates.iter().position(|c| &c.who == who);
+            let mut stale = MissedSessions::<T>::iter()
+                .filter(|(_, missed)| *missed >= kick_threshold)
+                .collect::<Vec<_>>();
+            stale.sort_by_key(|(who, missed)| (Reverse(*missed), Reverse(rank(who))));
+            let mut nominations = 0u32;
+            for (who, _) in stale {
                 // stale candidate, kick and slash
                 if Self::is_account_candidate(&who) {
                     if Candidates::<T>::get().len() > T::MinCandidates::get() as usize {
                         // no error, who is a candidate
                         let _ = Self::try_remove_candidate(&who);
-                        Self::slash_non_candidate(&who);
-                    }
-                } else if let Some((locked_until, _)) = NonCandidates::<T>::get(&who) {
-                    if T::ValidatorSet::session_index() > locked_until {
-                        // bond is already unlocked
-                        <LastAuthoredBlock<T>>::remove(who);
-                    } else {
-                        // slash un-bonding candidate
-                        Self::slash_non_candidate(&who);
+                        nominations.saturating_accrue(Self::slash_non_candidate(&who));
                     }
+                } else if NonCandidates::<T>::contains_key(&who) {
+                    // slash un-bonding candidate, it went stale while still collating
+                    nominations.saturating_accrue(Self::slash_non_candidate(&who));
+                } else {
+                    // invulnerables are never kicked
+                    <MissedSessions<T>>::remove(who);
                 }
             }
             (
                 count,
                 count.saturating_sub(Candidates::<T>::get().len() as u32),
+                nominations,
             )
         }
 
+        /// Count the slots skipped since the last block as missed by the collators they were
+        /// assigned to, round robin over the session's collators.
+        ///
+        /// A gap in which every collator missed its slot is a chain-wide stall (e.g. of the relay
+        /// chain) rather than the collators' fault, so it isn't charged to anyone.
+        /// Returns the number of collators who missed slots.
+        fn note_missed_slots() -> u32 {
+            let slot = T::CurrentSlot::get();
+            let last_slot = LastSlot::<T>::mutate(|last| core::mem::replace(last, slot));
+            if slot.is_zero() || last_slot.is_zero() || slot <= last_slot.saturating_add(1) {
+                return 0;
+            }
+
+            let collators = PlannedCollators::<T>::get(T::ValidatorSet::session_index());
+            let count = collators.len() as u64;
+            if count.is_zero() {
+                return 0;
+            }
+
+            let missed = slot - last_slot - 1;
+            if missed >= count {
+                return 0;
+            }
+            for i in 0..missed {
+                let missed_slot = last_slot + 1 + i;
+                MissedSlots::<T>::mutate(&collators[(missed_slot % count) as usize], |missed| {
+                    missed.saturating_inc()
+                });
+            }
+            missed as u32
+        }
+
+        /// Track the consecutive sessions in which the collators missed more than
+        /// [`Config::MissedSlotsTolerance`] of their assigned slots.
+        fn note_missed_sessions(
+            collators: &[T::AccountId],
+            authored: &BTreeMap<T::AccountId, u32>,
+            missed: &BTreeMap<T::AccountId, u32>,
+        ) {
+            for who in collators {
+                let missed = missed.get(who).copied().unwrap_or_default();
+                let assigned =
+                    missed.saturating_add(authored.get(who).copied().unwrap_or_default());
+                if assigned.is_zero() {
+                    // none of the collator's slots came up, e.g. the chain stalled
+                    continue;
+                }
+                if Perbill::from_rational(missed, assigned) > T::MissedSlotsTolerance::get() {
+                    MissedSessions::<T>::mutate(who, |sessions| sessions.saturating_inc());
+                } else {
+                    MissedSessions::<T>::remove(who);
+                }
+            }
+        }
+
+        /// Record the authorship performance of the collators in the ending session and, in
+        /// [`RewardMode::PerSession`], split the pot between them accordingly.
+        /// Returns the number of collators in the session & the number of nominators paid.
+        fn reward_session(
+            index: SessionIndex,
+            collators: Vec<T::AccountId>,
+            authored: &BTreeMap<T::AccountId, u32>,
+            missed: &BTreeMap<T::AccountId, u32>,
+        ) -> (u32, u32) {
+            let count = collators.len() as u32;
+            if count.is_zero() {
+                return (0, 0);
+            }
+
+            let pot = Self::account_id();
+            let is_paid = CollatorRewardMode::<T>::get() == RewardMode::PerSession;
+            let base_reward = if is_paid {
+                T::Currency::free_balance(&pot)
+                    .checked_sub(&T::Currency::minimum_balance())
+                    .unwrap_or_else(Zero::zero)
+                    .div(count.into())
+            } else {
+                Zero::zero()
+            };
+
+            let mut total_paid: BalanceOf<T> = Zero::zero();
+            let mut nominators = 0u32;
+            let performance = collators
+                .into_iter()
+                .map(|who| {
+                    let produced = authored.get(&who).copied().unwrap_or_default();
+                    // the collator's own slots, so it isn't judged by the others' misses
+                    let expected =
+                        produced.saturating_add(missed.get(&who).copied().unwrap_or_default());
+                    let mut reward: BalanceOf<T> = Zero::zero();
+                    if is_paid && !expected.is_zero() {
+                        let ratio = Perbill::from_rational(produced, expected);
+                        reward = T::PerformancePenalty::convert(ratio) * base_reward;
+                        if !reward.is_zero() {
+                            nominators.saturating_accrue(Self::pay_reward(&pot, &who, reward));
+                            total_paid.saturating_accrue(reward);
+                        }
+                    }
+                    CollatorPerformance {
+                        who,
+                        authored: produced,
+                        expected,
+                        reward,
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            PerformanceHistory::<T>::insert(index, performance);
+            if let Some(expired) = index.checked_sub(T::PerformanceHistoryDepth::get()) {
+                PerformanceHistory::<T>::remove(expired);
+            }
+
+            if is_paid {
+                Self::deposit_event(Event::SessionRewardsPaid(index, total_paid));
+            }
+            (count, nominators)
+        }
+
         /// Check whether an account is a candidate.
         pub fn is_account_candidate(account: &T::AccountId) -> bool {
             Candidates::<T>::get().iter().any(|c| &c.who == account)
@@ -597,19 +1842,22 @@ pub mod pallet {
         pallet_authorship::EventHandler<T::AccountId, BlockNumberFor<T>> for Pallet<T>
     {
         fn note_author(author: T::AccountId) {
-            let pot = Self::account_id();
-            // assumes an ED will be sent to pot.
-            let reward = T::Currency::free_balance(&pot)
-                .checked_sub(&T::Currency::minimum_balance())
-                .unwrap_or_else(Zero::zero)
-                .div(2u32.into());
-            // `reward` is half of pot account minus ED, this should never fail.
-            let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
-            debug_assert!(_success.is_ok());
+            <AuthoredBlocks<T>>::mutate(&author, |blocks| blocks.saturating_inc());
+
+            let mut nominators = 0;
+            if CollatorRewardMode::<T>::get() == RewardMode::PerBlock {
+                let pot = Self::account_id();
+                // assumes an ED will be sent to pot.
+                let reward = T::Currency::free_balance(&pot)
+                    .checked_sub(&T::Currency::minimum_balance())
+                    .unwrap_or_else(Zero::zero)
+                    .div(2u32.into());
+                nominators = Self::pay_reward(&pot, &author, reward);
+            }
             <LastAuthoredBlock<T>>::insert(author, frame_system::Pallet::<T>::block_number());
 
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::note_author(),
+                T::WeightInfo::note_author(nominators),
                 DispatchClass::Mandatory,
             );
         }
@@ -624,24 +1872,38 @@ pub mod pallet {
                 <frame_system::Pallet<T>>::block_number(),
             );
 
-            let (candidates_len_before, removed) = Self::kick_stale_candidates();
+            let (candidates_len_before, removed, nominations) = Self::kick_stale_candidates();
+            Self::phase_out_invulnerable(index);
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::new_session(candidates_len_before, removed),
+                T::WeightInfo::new_session(candidates_len_before, removed, nominations),
                 DispatchClass::Mandatory,
             );
 
-            let active_candidates = Candidates::<T>::get()
-                .into_iter()
-                .map(|x| x.who)
-                .collect::<Vec<_>>();
-
-            Some(Self::assemble_collators(active_candidates))
+            let elected = Self::elected_candidates();
+            Self::note_elected(&elected);
+            let collators = Self::assemble_collators(elected);
+            PlannedCollators::<T>::insert(index, &collators);
+            Some(collators)
         }
-        fn start_session(_: SessionIndex) {
-            // we don't care.
+        fn start_session(index: SessionIndex) {
+            let applied = Self::apply_deferred_slashes(index);
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::WeightInfo::start_session(applied),
+                DispatchClass::Mandatory,
+            );
         }
-        fn end_session(_: SessionIndex) {
-            // we don't care.
+        fn end_session(index: SessionIndex) {
+            let collators = PlannedCollators::<T>::take(index);
+            let authored = AuthoredBlocks::<T>::drain().collect::<BTreeMap<_, _>>();
+            let missed = MissedSlots::<T>::drain().collect::<BTreeMap<_, _>>();
+            Self::note_missed_sessions(&collators, &authored, &missed);
+
+            let (collators, nominators) =
+                Self::reward_session(index, collators, &authored, &missed);
+            frame_system::Pallet::<T>::register_extra_weight_unchecked(
+                T::WeightInfo::end_session(collators, nominators),
+                DispatchClass::Mandatory,
+            );
         }
     }
 }
@@ -0,0 +1,80 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the collator selection pallet.
+
+use super::*;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Exports for versioned migration `type`s for this pallet.
+pub mod versioned {
+    use super::*;
+
+    /// Migration storage V0 to V1 wrapped in a [`frame_support::migrations::VersionedMigration`],
+    /// ensuring the migration is only performed when on-chain version is 0.
+    pub type V0ToV1<T> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        RankCandidatesByBond<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Sorts the existing [`Candidates`] by their deposit in descending order.
+///
+/// Candidates used to be kept in registration order, the sort is stable so candidates with the
+/// same deposit keep their first-come-first-serve order.
+pub struct RankCandidatesByBond<T>(PhantomData<T>);
+impl<T: Config> UncheckedOnRuntimeUpgrade for RankCandidatesByBond<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let mut candidates = Candidates::<T>::get();
+        candidates.sort_by(|a, b| b.deposit.cmp(&a.deposit));
+        Candidates::<T>::put(candidates);
+
+        T::DbWeight::get().reads_writes(1, 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((Candidates::<T>::get().len() as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let prev_count =
+            u32::decode(&mut state.as_ref()).map_err(|_| "Cannot decode data from pre_upgrade")?;
+        let candidates = Candidates::<T>::get();
+
+        ensure!(
+            candidates.len() as u32 == prev_count,
+            "candidates must not be removed"
+        );
+        ensure!(
+            candidates.windows(2).all(|w| w[0].deposit >= w[1].deposit),
+            "candidates must be ranked by deposit"
+        );
+        Ok(())
+    }
+}
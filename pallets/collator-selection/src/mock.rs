@@ -19,12 +19,12 @@ use super::*;
 use crate as collator_selection;
 use frame_support::{
     derive_impl, ord_parameter_types, parameter_types,
-    traits::{FindAuthor, ValidatorRegistration},
+    traits::{FindAuthor, Get, ValidatorRegistration},
     PalletId,
 };
 use frame_system as system;
 use frame_system::EnsureSignedBy;
-use sp_core::ConstBool;
+use sp_core::{ConstBool, ConstU32};
 use sp_runtime::{
     testing::UintAuthorityId, traits::OpaqueKeys, BuildStorage, Perbill, RuntimeAppPublic,
 };
@@ -66,18 +66,22 @@ impl pallet_balances::Config for Test {
     type AccountStore = System;
 }
 
-pub struct Author4;
-impl FindAuthor<u64> for Author4 {
+parameter_types! {
+    pub static BlockAuthor: u64 = 4;
+}
+
+pub struct MockAuthor;
+impl FindAuthor<u64> for MockAuthor {
     fn find_author<'a, I>(_digests: I) -> Option<u64>
     where
         I: 'a + IntoIterator<Item = (frame_support::ConsensusEngineId, &'a [u8])>,
     {
-        Some(4)
+        Some(BlockAuthor::get())
     }
 }
 
 impl pallet_authorship::Config for Test {
-    type FindAuthor = Author4;
+    type FindAuthor = MockAuthor;
     type EventHandler = CollatorSelection;
 }
 
@@ -163,6 +167,19 @@ parameter_types! {
     pub const MinCandidates: u32 = 1;
     pub const MaxAuthorities: u32 = 100_000;
     pub const SlashRatio: Perbill = Perbill::from_percent(10);
+    pub const MinNomination: u64 = 10;
+    pub const MaxNominators: u32 = 2;
+    pub const MissedSlotsTolerance: Perbill = Perbill::from_percent(50);
+    pub static KickThreshold: u32 = 2;
+    pub static SlotOffset: u64 = 0;
+}
+
+/// Mock Aura slot, one per block unless shifted by [`SlotOffset`] to simulate skipped slots.
+pub struct MockSlot;
+impl Get<u64> for MockSlot {
+    fn get() -> u64 {
+        System::block_number() + SlotOffset::get()
+    }
 }
 
 pub struct IsRegistered;
@@ -189,13 +206,19 @@ impl Config for Test {
     type MaxCandidates = MaxCandidates;
     type MinCandidates = MinCandidates;
     type MaxInvulnerables = MaxInvulnerables;
-    type KickThreshold = Period;
+    type KickThreshold = KickThreshold;
+    type MissedSlotsTolerance = MissedSlotsTolerance;
+    type CurrentSlot = MockSlot;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
     type ValidatorIdOf = IdentityCollator;
     type ValidatorRegistration = IsRegistered;
     type ValidatorSet = Session;
     type SlashRatio = SlashRatio;
     type AccountCheck = DummyAccountCheck;
+    type MinNomination = MinNomination;
+    type MaxNominators = MaxNominators;
+    type PerformancePenalty = QuadraticPenalty;
+    type PerformanceHistoryDepth = ConstU32<2>;
     type WeightInfo = ();
 }
 
@@ -17,15 +17,20 @@
 
 use crate as collator_selection;
 use crate::{
-    mock::*, CandidacyBond, CandidateInfo, Candidates, DesiredCandidates, Error, Invulnerables,
-    LastAuthoredBlock, NonCandidates, SlashDestination,
+    mock::*, AuthoredBlocks, CandidacyBond, CandidateInfo, CandidateStatus, Candidates,
+    CollatorPerformance, CollatorRewardMode, Commission, DeferredSlash, DeferredSlashes,
+    DesiredCandidates, Error, Invulnerables, InvulnerablesPhaseOut, LastAuthoredBlock,
+    MissedSessions, MissedSlots, Nominations, NonCandidateStatus, NonCandidates,
+    PerformanceHistory, PhaseOut, PlannedCollators, RewardMode, RotationQueue, ServedSessions,
+    SlashDeferDuration, SlashDestination, TermLimit, TotalNominations, UnbondingNominations,
 };
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{Currency, OnInitialize},
+    traits::{Currency, GetStorageVersion, OnInitialize, OnRuntimeUpgrade, StorageVersion},
 };
 use pallet_balances::Error as BalancesError;
-use sp_runtime::{traits::BadOrigin, BuildStorage};
+use pallet_session::SessionManager;
+use sp_runtime::{traits::BadOrigin, BuildStorage, Perbill};
 
 #[test]
 fn basic_setup_works() {
@@ -66,6 +71,156 @@ fn it_should_set_invulnerables() {
     });
 }
 
+#[test]
+fn add_and_remove_invulnerables() {
+    new_test_ext().execute_with(|| {
+        // cannot add with non-root.
+        assert_noop!(
+            CollatorSelection::add_invulnerable(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
+        // cannot add invulnerables without associated validator keys
+        assert_noop!(
+            CollatorSelection::add_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 7),
+            Error::<Test>::ValidatorNotRegistered
+        );
+        assert_noop!(
+            CollatorSelection::add_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 2),
+            Error::<Test>::AlreadyInvulnerable
+        );
+
+        assert_ok!(CollatorSelection::add_invulnerable(
+            RuntimeOrigin::signed(RootAccount::get()),
+            3
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::InvulnerableAdded(3),
+        ));
+        assert_eq!(Invulnerables::<Test>::get(), vec![1, 2, 3]);
+
+        // a candidate added as invulnerable gives up its candidacy
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::add_invulnerable(
+            RuntimeOrigin::signed(RootAccount::get()),
+            4
+        ));
+        assert_eq!(Invulnerables::<Test>::get(), vec![1, 2, 3, 4]);
+        assert!(Candidates::<Test>::get().is_empty());
+        assert_eq!(NonCandidates::<Test>::get(4), Some((1, 10)));
+
+        assert_noop!(
+            CollatorSelection::remove_invulnerable(RuntimeOrigin::signed(1), 3),
+            BadOrigin
+        );
+        assert_noop!(
+            CollatorSelection::remove_invulnerable(RuntimeOrigin::signed(RootAccount::get()), 5),
+            Error::<Test>::NotInvulnerable
+        );
+        assert_ok!(CollatorSelection::remove_invulnerable(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::InvulnerableRemoved(2),
+        ));
+        assert_eq!(Invulnerables::<Test>::get(), vec![1, 3, 4]);
+    });
+}
+
+#[test]
+fn schedule_and_cancel_phase_out() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CollatorSelection::schedule_phase_out(RuntimeOrigin::signed(1), 0, 1),
+            BadOrigin
+        );
+        assert_noop!(
+            CollatorSelection::schedule_phase_out(RuntimeOrigin::signed(RootAccount::get()), 0, 0),
+            Error::<Test>::InvalidPhaseOutInterval
+        );
+        assert_noop!(
+            CollatorSelection::cancel_phase_out(RuntimeOrigin::signed(RootAccount::get())),
+            Error::<Test>::NoPhaseOut
+        );
+
+        assert_ok!(CollatorSelection::schedule_phase_out(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1,
+            2
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::PhaseOutScheduled(1, 2),
+        ));
+        assert_eq!(
+            PhaseOut::<Test>::get(),
+            Some(InvulnerablesPhaseOut {
+                target: 1,
+                interval: 2,
+                next_session: 2,
+            })
+        );
+
+        assert_ok!(CollatorSelection::cancel_phase_out(RuntimeOrigin::signed(
+            RootAccount::get()
+        )));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::PhaseOutCancelled,
+        ));
+        assert_eq!(PhaseOut::<Test>::get(), None);
+    });
+}
+
+#[test]
+fn invulnerables_are_phased_out_for_waiting_candidates() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&6, 100);
+        assert_ok!(CollatorSelection::schedule_phase_out(
+            RuntimeOrigin::signed(RootAccount::get()),
+            0,
+            2
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+
+        // 5 is waiting, the last invulnerable makes room for it
+        initialize_to_block(10);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::InvulnerablePhasedOut(2, 3),
+        ));
+        assert_eq!(Invulnerables::<Test>::get(), vec![1]);
+        assert_eq!(DesiredCandidates::<Test>::get(), 3);
+        assert_eq!(NextSessionCollators::get(), vec![1, 3, 4, 5]);
+
+        // nobody is waiting when the next removal is due
+        initialize_to_block(30);
+        assert_eq!(Invulnerables::<Test>::get(), vec![1]);
+        assert_eq!(PhaseOut::<Test>::get().map(|p| p.next_session), Some(4));
+
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(6)
+        ));
+        initialize_to_block(40);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::InvulnerablePhasedOut(1, 4),
+        ));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::PhaseOutCompleted,
+        ));
+        assert!(Invulnerables::<Test>::get().is_empty());
+        assert_eq!(PhaseOut::<Test>::get(), None);
+        assert_eq!(NextSessionCollators::get(), vec![3, 4, 5, 6]);
+    });
+}
+
 #[test]
 fn set_desired_candidates_works() {
     new_test_ext().execute_with(|| {
@@ -111,26 +266,20 @@ fn set_candidacy_bond() {
 #[test]
 fn cannot_register_candidate_if_too_many() {
     new_test_ext().execute_with(|| {
-        // reset desired candidates:
-        <crate::DesiredCandidates<Test>>::put(0);
+        // desired candidates don't limit the registration, the rest are waiting for a slot.
+        for who in 10..(10 + MaxCandidates::get() as u64) {
+            Balances::make_free_balance_be(&who, 100);
+            assert_ok!(CollatorSelection::register_as_candidate(
+                RuntimeOrigin::signed(who)
+            ));
+        }
+        assert_eq!(Candidates::<Test>::get().len() as u32, MaxCandidates::get());
 
         // can't accept anyone anymore.
         assert_noop!(
             CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)),
             Error::<Test>::TooManyCandidates,
         );
-
-        // reset desired candidates:
-        <crate::DesiredCandidates<Test>>::put(1);
-        assert_ok!(CollatorSelection::register_as_candidate(
-            RuntimeOrigin::signed(4)
-        ));
-
-        // but no more
-        assert_noop!(
-            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)),
-            Error::<Test>::TooManyCandidates,
-        );
     })
 }
 
@@ -187,7 +336,6 @@ fn cannot_register_dupe_candidate() {
             deposit: 10,
         };
         assert_eq!(Candidates::<Test>::get(), vec![addition]);
-        assert_eq!(LastAuthoredBlock::<Test>::get(3), 10);
         assert_eq!(Balances::free_balance(3), 90);
 
         // but no more
@@ -280,7 +428,6 @@ fn leave_intent() {
         assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
         assert_eq!(Balances::free_balance(3), 90);
         assert_eq!(Balances::reserved_balance(3), 10);
-        assert_eq!(LastAuthoredBlock::<Test>::get(3), 10);
         // 10 unbonding from session 1
         assert_eq!(NonCandidates::<Test>::get(3), Some((1, 10)));
     });
@@ -456,6 +603,48 @@ fn session_management_works() {
     });
 }
 
+/// Advance to the next Aura slot assigned to `who` in the current session and skip it.
+fn miss_slot(who: u64) {
+    let collators = PlannedCollators::<Test>::get(Session::current_index());
+    let count = collators.len() as u64;
+    while collators[((System::block_number() + 1 + SlotOffset::get()) % count) as usize] != who {
+        initialize_to_block(System::block_number() + 1);
+    }
+    SlotOffset::set(SlotOffset::get() + 1);
+    initialize_to_block(System::block_number() + 1);
+}
+
+#[test]
+fn missed_slots_are_charged_round_robin() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        initialize_to_block(21);
+        assert_eq!(SessionCollators::get(), vec![1, 2, 3, 4]);
+        assert_eq!(MissedSlots::<Test>::iter().count(), 0);
+
+        // slots 22 to 24 are skipped
+        SlotOffset::set(3);
+        initialize_to_block(22);
+        assert_eq!(MissedSlots::<Test>::get(1), 1);
+        assert_eq!(MissedSlots::<Test>::get(2), 0);
+        assert_eq!(MissedSlots::<Test>::get(3), 1);
+        assert_eq!(MissedSlots::<Test>::get(4), 1);
+
+        // missed slots are tallied at the end of the session
+        initialize_to_block(30);
+        assert_eq!(MissedSlots::<Test>::iter().count(), 0);
+        // 4 authored blocks 20 to 29, well within the tolerance
+        assert!(!MissedSessions::<Test>::contains_key(4));
+        assert_eq!(MissedSessions::<Test>::get(3), 1);
+        assert_eq!(CollatorSelection::sessions_until_kick(&3), 1);
+    });
+}
+
 #[test]
 fn kick_and_slash_mechanism() {
     new_test_ext().execute_with(|| {
@@ -468,22 +657,28 @@ fn kick_and_slash_mechanism() {
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(4)
         ));
-        initialize_to_block(10);
-        assert_eq!(Candidates::<Test>::get().len(), 2);
         initialize_to_block(20);
-        assert_eq!(SessionChangeBlock::get(), 20);
-        // 4 authored this block, gets to stay. 3 was kicked
-        assert_eq!(Candidates::<Test>::get().len(), 1);
-        // 3 will be kicked after 1 session delay
         assert_eq!(SessionCollators::get(), vec![1, 2, 3, 4]);
-        assert_eq!(NextSessionCollators::get(), vec![1, 2, 4]);
+
+        // 3 misses its slots in two consecutive sessions
+        miss_slot(3);
+        initialize_to_block(30);
+        assert_eq!(MissedSessions::<Test>::get(3), 1);
+        assert_eq!(Candidates::<Test>::get().len(), 2);
+        miss_slot(3);
+        initialize_to_block(40);
+        assert_eq!(SessionChangeBlock::get(), 40);
+        // 4 authored its blocks, gets to stay. 3 was kicked
         let collator = CandidateInfo {
             who: 4,
             deposit: 10,
         };
         assert_eq!(Candidates::<Test>::get(), vec![collator]);
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
-        initialize_to_block(30);
+        assert!(!MissedSessions::<Test>::contains_key(3));
+        // 3 will be kicked after 1 session delay
+        assert_eq!(SessionCollators::get(), vec![1, 2, 3, 4]);
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 4]);
+        initialize_to_block(50);
         // 3 gets kicked after 1 session delay
         assert_eq!(SessionCollators::get(), vec![1, 2, 4]);
         // kicked collator gets funds back except slashed 10% (of 10 bond)
@@ -492,9 +687,70 @@ fn kick_and_slash_mechanism() {
     });
 }
 
+#[test]
+fn stalled_session_does_not_kick() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        initialize_to_block(20);
+        BlockAuthor::set(3);
+
+        // the relay chain stalls, the skipped slots are not charged to anyone
+        initialize_to_block(22);
+        SlotOffset::set(40);
+        initialize_to_block(23);
+        assert_eq!(MissedSlots::<Test>::iter().count(), 0);
+        initialize_to_block(30);
+        for who in 1..=4 {
+            assert!(!MissedSessions::<Test>::contains_key(who));
+        }
+
+        initialize_to_block(40);
+        assert!(MissedSessions::<Test>::iter().next().is_none());
+        assert!(CollatorSelection::is_account_candidate(&3));
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 3, 4]);
+        assert_eq!(Balances::reserved_balance(3), 10);
+    });
+}
+
+#[test]
+fn missed_slots_tolerance_is_respected() {
+    new_test_ext().execute_with(|| {
+        KickThreshold::set(1);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        initialize_to_block(20);
+
+        // 3 authors most of the session, missing a few slots is tolerated
+        BlockAuthor::set(3);
+        miss_slot(3);
+        miss_slot(3);
+        initialize_to_block(30);
+        assert!(!MissedSessions::<Test>::contains_key(3));
+        assert!(CollatorSelection::is_account_candidate(&3));
+
+        // 3 misses its only assigned slot
+        BlockAuthor::set(4);
+        miss_slot(3);
+        initialize_to_block(40);
+        assert!(!CollatorSelection::is_account_candidate(&3));
+        assert!(CollatorSelection::is_account_candidate(&4));
+        assert_eq!(Balances::free_balance(3), 99);
+    });
+}
+
 #[test]
 fn slash_mechanism_for_unbonding_candidates_who_missed_block() {
     new_test_ext().execute_with(|| {
+        KickThreshold::set(1);
         // Define slash destination account
         <crate::SlashDestination<Test>>::put(5);
         // add a new collator
@@ -504,13 +760,11 @@ fn slash_mechanism_for_unbonding_candidates_who_missed_block() {
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(4)
         ));
-        assert_eq!(LastAuthoredBlock::<Test>::get(3), 10);
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 10);
 
         initialize_to_block(10);
         // gets included into next session, expected to build blocks
         assert_eq!(NextSessionCollators::get(), vec![1, 2, 3, 4]);
-        // candidate left but still expected to produce blocks for current session
+        // candidate left but still expected to produce blocks for next session
         assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
         assert_eq!(Balances::free_balance(3), 90); // funds un-bonding
         initialize_to_block(19);
@@ -519,12 +773,15 @@ fn slash_mechanism_for_unbonding_candidates_who_missed_block() {
             CollatorSelection::withdraw_bond(RuntimeOrigin::signed(3)),
             Error::<Test>::BondStillLocked
         );
-        // new session, candidate gets slashed
         initialize_to_block(20);
         assert_eq!(Candidates::<Test>::get().len(), 1);
-        assert_eq!(SessionChangeBlock::get(), 20);
-        assert_eq!(LastAuthoredBlock::<Test>::contains_key(3), false);
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
+        assert_eq!(SessionCollators::get(), vec![1, 2, 3, 4]);
+
+        // new session, candidate gets slashed
+        miss_slot(3);
+        initialize_to_block(30);
+        assert_eq!(SessionChangeBlock::get(), 30);
+        assert!(!MissedSessions::<Test>::contains_key(3));
 
         // slashed, remaining bond was refunded
         assert_noop!(
@@ -548,8 +805,6 @@ fn should_not_slash_unbonding_candidates() {
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(4)
         ));
-        assert_eq!(LastAuthoredBlock::<Test>::get(3), 10);
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 10);
 
         assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
         // can withdraw on next session
@@ -558,15 +813,15 @@ fn should_not_slash_unbonding_candidates() {
         initialize_to_block(10);
         // not included next session and doesn't withdraw bond
         assert_eq!(NextSessionCollators::get(), vec![1, 2, 4]);
-        assert_eq!(LastAuthoredBlock::<Test>::get(3), 10);
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 10);
         assert_eq!(NonCandidates::<Test>::get(3), Some((1, 10)));
         assert_eq!(Balances::free_balance(3), 90);
 
+        // skipped slots are not charged to 3, it isn't collating
+        initialize_to_block(15);
+        SlotOffset::set(2);
         initialize_to_block(20);
         assert_eq!(SessionChangeBlock::get(), 20);
-        assert!(!LastAuthoredBlock::<Test>::contains_key(3));
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
+        assert!(!MissedSessions::<Test>::contains_key(3));
 
         assert_eq!(NonCandidates::<Test>::get(3), Some((1, 10)));
         assert_eq!(Balances::free_balance(3), 90);
@@ -580,6 +835,7 @@ fn should_not_slash_unbonding_candidates() {
 #[test]
 fn should_not_kick_mechanism_too_few() {
     new_test_ext().execute_with(|| {
+        KickThreshold::set(1);
         // add a new collator
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(3)
@@ -587,27 +843,925 @@ fn should_not_kick_mechanism_too_few() {
         assert_ok!(CollatorSelection::register_as_candidate(
             RuntimeOrigin::signed(5)
         ));
-        initialize_to_block(10);
-        assert_eq!(Candidates::<Test>::get().len(), 2);
         initialize_to_block(20);
-        assert_eq!(SessionChangeBlock::get(), 20);
-        // 4 authored this block, 3 gets to stay too few, 5 was kicked
-        assert_eq!(Candidates::<Test>::get().len(), 1);
-        // 5 will be kicked for next session
+        assert_eq!(SessionCollators::get(), vec![1, 2, 3, 5]);
+        // both candidates miss their slots
+        miss_slot(3);
+        miss_slot(5);
+        initialize_to_block(30);
+        assert_eq!(SessionChangeBlock::get(), 30);
+        // one of them gets to stay, there would be too few candidates.
+        // both are equally stale, so the lower ranked 5 is kicked.
+        assert!(CollatorSelection::is_account_candidate(&3));
+        assert!(!CollatorSelection::is_account_candidate(&5));
+        let kicked = 5;
         assert_eq!(NextSessionCollators::get(), vec![1, 2, 3]);
+        // kicked collator gets funds back (but slashed)
+        assert_eq!(Balances::free_balance(kicked), 99);
+        initialize_to_block(40);
+        // next session doesn't include the kicked candidate
+        assert!(!SessionCollators::get().contains(&kicked));
+    });
+}
+
+#[test]
+fn candidates_are_elected_by_bond() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+        // only 2 desired candidates, 5 is in the waiting list
+        assert_eq!(CollatorSelection::elected_candidates(), vec![4, 3]);
+
+        // 5 outbids the elected candidates
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(5), 20));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateBondUpdated(5, 20),
+        ));
+        assert_eq!(Balances::free_balance(5), 80);
         assert_eq!(
             Candidates::<Test>::get(),
-            vec![CandidateInfo {
-                who: 3,
-                deposit: 10,
-            }]
+            vec![
+                CandidateInfo {
+                    who: 5,
+                    deposit: 20,
+                },
+                CandidateInfo {
+                    who: 4,
+                    deposit: 10,
+                },
+                CandidateInfo {
+                    who: 3,
+                    deposit: 10,
+                },
+            ]
         );
-        assert_eq!(LastAuthoredBlock::<Test>::get(4), 20);
-        // kicked collator gets funds back (but slashed)
-        assert_eq!(Balances::free_balance(5), 99);
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 4]);
+
+        initialize_to_block(10);
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 5, 4]);
+
+        // waiting candidate isn't assigned any slots, it can't miss them
+        initialize_to_block(25);
+        SlotOffset::set(3);
         initialize_to_block(30);
-        // next session doesn't include 5
-        assert_eq!(SessionCollators::get(), vec![1, 2, 3]);
+        assert!(CollatorSelection::is_account_candidate(&3));
+        assert!(!MissedSessions::<Test>::contains_key(3));
+        assert_eq!(SessionCollators::get(), vec![1, 2, 5, 4]);
+    });
+}
+
+#[test]
+fn set_term_limit_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            CollatorSelection::set_term_limit(RuntimeOrigin::signed(1), 2),
+            BadOrigin
+        );
+        assert_ok!(CollatorSelection::set_term_limit(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::NewTermLimit(
+            2,
+        )));
+        assert_eq!(TermLimit::<Test>::get(), 2);
+
+        // disabling term limits clears the queue
+        RotationQueue::<Test>::put(vec![3]);
+        assert_ok!(CollatorSelection::set_term_limit(
+            RuntimeOrigin::signed(RootAccount::get()),
+            0
+        ));
+        assert!(RotationQueue::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn candidates_are_rotated_after_term_limit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::set_term_limit(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+
+        // every candidate serves two sessions in a row, then waits for one
+        let mut planned = Vec::new();
+        for block in (10..=90).step_by(10) {
+            initialize_to_block(block);
+            planned.push(NextSessionCollators::get());
+        }
+        assert_eq!(
+            planned,
+            vec![
+                vec![1, 2, 3, 4],
+                vec![1, 2, 3, 4],
+                vec![1, 2, 5, 3],
+                vec![1, 2, 5, 4],
+                vec![1, 2, 4, 3],
+                vec![1, 2, 3, 5],
+                vec![1, 2, 5, 4],
+                vec![1, 2, 4, 3],
+                vec![1, 2, 3, 5],
+            ]
+        );
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateRotated(4),
+        ));
+        assert_eq!(RotationQueue::<Test>::get(), vec![4]);
+        assert_eq!(ServedSessions::<Test>::get(3), 2);
+        assert_eq!(ServedSessions::<Test>::get(5), 1);
+
+        // rotated candidates keep their bond
+        for who in [3, 4, 5] {
+            assert!(CollatorSelection::is_account_candidate(&who));
+            assert_eq!(Balances::reserved_balance(who), 10);
+        }
+        assert!(
+            !CollatorSelection::candidate_statuses()
+                .into_iter()
+                .find(|status| status.who == 3)
+                .unwrap()
+                .elected
+        );
+    });
+}
+
+#[test]
+fn term_limit_needs_waiting_candidates() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::set_term_limit(
+            RuntimeOrigin::signed(RootAccount::get()),
+            1
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        // nobody is waiting, the candidates keep their seats
+        initialize_to_block(50);
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 3, 4]);
+        assert_eq!(ServedSessions::<Test>::get(3), 5);
+        assert!(RotationQueue::<Test>::get().is_empty());
+
+        // the lowest ranked candidate past its term makes room for the newcomer
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+        initialize_to_block(60);
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 5, 3]);
+        assert_eq!(RotationQueue::<Test>::get(), vec![4]);
+
+        // queued candidates leaving are dropped from the queue
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(4)));
+        initialize_to_block(70);
+        assert_eq!(NextSessionCollators::get(), vec![1, 2, 3, 5]);
+        assert!(RotationQueue::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn update_bond_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(1), 20),
+            Error::<Test>::NotCandidate
+        );
+        // can't go below the candidacy bond
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(5), 9),
+            Error::<Test>::DepositTooLow
+        );
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(5), 101),
+            BalancesError::<Test>::InsufficientBalance
+        );
+
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(5), 15));
+        assert_eq!(Balances::free_balance(5), 85);
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 3]);
+
+        // elected candidate can't decrease the bond
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(5), 10),
+            Error::<Test>::CannotDecreaseBond
+        );
+
+        // 4 outbids 3 which ends up in the waiting list
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(4), 12));
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 4]);
+
+        // waiting candidate can decrease the bond
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 11));
+        assert_eq!(Balances::free_balance(3), 89);
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 10));
+        assert_eq!(Balances::free_balance(3), 90);
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 4]);
+    });
+}
+
+#[test]
+fn take_candidate_slot_works() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+
+        assert_noop!(
+            CollatorSelection::take_candidate_slot(RuntimeOrigin::signed(3), 20),
+            Error::<Test>::CandidateListNotFull
+        );
+
+        for who in 10..(10 + MaxCandidates::get() as u64) {
+            Balances::make_free_balance_be(&who, 100);
+            assert_ok!(CollatorSelection::register_as_candidate(
+                RuntimeOrigin::signed(who)
+            ));
+        }
+        let lowest = Candidates::<Test>::get().last().unwrap().who;
+        assert_eq!(lowest, 29);
+
+        // must outbid the lowest ranked candidate
+        assert_noop!(
+            CollatorSelection::take_candidate_slot(RuntimeOrigin::signed(3), 10),
+            Error::<Test>::DepositTooLow
+        );
+        assert_noop!(
+            CollatorSelection::take_candidate_slot(RuntimeOrigin::signed(10), 20),
+            Error::<Test>::AlreadyCandidate
+        );
+        assert_noop!(
+            CollatorSelection::take_candidate_slot(RuntimeOrigin::signed(1), 20),
+            Error::<Test>::AlreadyInvulnerable
+        );
+
+        assert_ok!(CollatorSelection::take_candidate_slot(
+            RuntimeOrigin::signed(3),
+            11
+        ));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateRemoved(lowest),
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateReplaced(3, lowest, 11),
+        ));
+
+        assert_eq!(Candidates::<Test>::get().len() as u32, MaxCandidates::get());
+        assert_eq!(
+            Candidates::<Test>::get()[0],
+            CandidateInfo {
+                who: 3,
+                deposit: 11,
+            }
+        );
+        assert_eq!(Balances::free_balance(3), 89);
+        // replaced candidate starts un-bonding
+        assert!(!CollatorSelection::is_account_candidate(&lowest));
+        assert_eq!(NonCandidates::<Test>::get(lowest), Some((1, 10)));
+    });
+}
+
+#[test]
+fn nominations_feed_into_ranking() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+        for who in 10..=12 {
+            Balances::make_free_balance_be(&who, 100);
+        }
+        for who in 3..=5 {
+            assert_ok!(CollatorSelection::register_as_candidate(
+                RuntimeOrigin::signed(who)
+            ));
+        }
+        assert_eq!(CollatorSelection::elected_candidates(), vec![3, 4]);
+
+        assert_noop!(
+            CollatorSelection::nominate(RuntimeOrigin::signed(10), 1, 20),
+            Error::<Test>::NotCandidate
+        );
+        assert_noop!(
+            CollatorSelection::nominate(RuntimeOrigin::signed(10), 5, 9),
+            Error::<Test>::NominationTooLow
+        );
+
+        // nominations push 5 to the top
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(10),
+            5,
+            15
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::Nominated(
+            10, 5, 15,
+        )));
+        assert_eq!(Balances::reserved_balance(10), 15);
+        assert_eq!(Nominations::<Test>::get(5, 10), Some(15));
+        assert_eq!(TotalNominations::<Test>::get(5), (1, 15));
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 3]);
+
+        // only 2 nominators per candidate
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(11),
+            5,
+            10
+        ));
+        assert_noop!(
+            CollatorSelection::nominate(RuntimeOrigin::signed(12), 5, 10),
+            Error::<Test>::TooManyNominators
+        );
+        // existing nominators can still nominate more, below the minimum is fine now
+        assert_ok!(CollatorSelection::nominate(RuntimeOrigin::signed(10), 5, 1));
+        assert_eq!(TotalNominations::<Test>::get(5), (2, 26));
+
+        // 4 gets backed as well and outranks 3
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(12),
+            4,
+            10
+        ));
+        assert_eq!(CollatorSelection::elected_candidates(), vec![5, 4]);
+
+        // 3 is the lowest ranked now
+        assert_eq!(
+            Candidates::<Test>::get().last(),
+            Some(&CandidateInfo {
+                who: 3,
+                deposit: 10
+            })
+        );
+    });
+}
+
+#[test]
+fn unbond_and_withdraw_nomination() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+        Balances::make_free_balance_be(&10, 100);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(10),
+            3,
+            30
+        ));
+
+        assert_noop!(
+            CollatorSelection::unbond_nomination(RuntimeOrigin::signed(11), 3, 10),
+            Error::<Test>::NoNomination
+        );
+        // remaining nomination can't go below the minimum
+        assert_noop!(
+            CollatorSelection::unbond_nomination(RuntimeOrigin::signed(10), 3, 25),
+            Error::<Test>::NominationTooLow
+        );
+
+        assert_ok!(CollatorSelection::unbond_nomination(
+            RuntimeOrigin::signed(10),
+            3,
+            20
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::NominationUnbonded(10, 3, 20),
+        ));
+        assert_eq!(Nominations::<Test>::get(3, 10), Some(10));
+        assert_eq!(TotalNominations::<Test>::get(3), (1, 10));
+        assert_eq!(UnbondingNominations::<Test>::get(3, 10), Some((1, 20)));
+
+        // unbonding more than nominated unbonds everything
+        assert_ok!(CollatorSelection::unbond_nomination(
+            RuntimeOrigin::signed(10),
+            3,
+            100
+        ));
+        assert_eq!(Nominations::<Test>::get(3, 10), None);
+        assert!(!TotalNominations::<Test>::contains_key(3));
+        assert_eq!(UnbondingNominations::<Test>::get(3, 10), Some((1, 30)));
+        assert_eq!(Balances::reserved_balance(10), 30);
+
+        // locked until the next session
+        assert_noop!(
+            CollatorSelection::withdraw_nomination(RuntimeOrigin::signed(10), 3),
+            Error::<Test>::BondStillLocked
+        );
+        initialize_to_block(10);
+        assert_ok!(CollatorSelection::withdraw_nomination(
+            RuntimeOrigin::signed(10),
+            3
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::NominationWithdrawn(10, 3, 30),
+        ));
+        assert_eq!(Balances::free_balance(10), 100);
+        assert_noop!(
+            CollatorSelection::withdraw_nomination(RuntimeOrigin::signed(10), 3),
+            Error::<Test>::NoNomination
+        );
+    });
+}
+
+#[test]
+fn nominators_share_author_reward() {
+    new_test_ext().execute_with(|| {
+        // put 100 in the pot + 5 for ED
+        Balances::make_free_balance_be(&CollatorSelection::account_id(), 105);
+        Balances::make_free_balance_be(&10, 100);
+
+        // 4 is the default author.
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(10),
+            4,
+            30
+        ));
+
+        assert_noop!(
+            CollatorSelection::set_commission(RuntimeOrigin::signed(10), Perbill::from_percent(20)),
+            Error::<Test>::NotCandidate
+        );
+        assert_ok!(CollatorSelection::set_commission(
+            RuntimeOrigin::signed(4),
+            Perbill::from_percent(20)
+        ));
+        assert_eq!(Commission::<Test>::get(4), Perbill::from_percent(20));
+
+        // triggers `note_author`
+        Authorship::on_initialize(1);
+
+        // reward is 50: 10 commission, the remaining 40 split 10:30 by stake.
+        assert_eq!(Balances::free_balance(4), 90 + 10 + 10);
+        assert_eq!(Balances::free_balance(10), 70 + 30);
+        assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 55);
+    });
+}
+
+#[test]
+fn nominations_are_slashed_with_candidate() {
+    new_test_ext().execute_with(|| {
+        KickThreshold::set(1);
+        // Define slash destination account
+        <crate::SlashDestination<Test>>::put(5);
+        Balances::make_free_balance_be(&10, 100);
+        Balances::make_free_balance_be(&11, 100);
+
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(10),
+            3,
+            50
+        ));
+        assert_ok!(CollatorSelection::nominate(
+            RuntimeOrigin::signed(11),
+            3,
+            20
+        ));
+
+        initialize_to_block(20);
+        miss_slot(3);
+        initialize_to_block(25);
+        // still locked un-bonding nominations are slashed as well
+        assert_ok!(CollatorSelection::unbond_nomination(
+            RuntimeOrigin::signed(11),
+            3,
+            20
+        ));
+        assert_eq!(UnbondingNominations::<Test>::get(3, 11), Some((3, 20)));
+
+        // 3 missed its slot, gets kicked and slashed
+        initialize_to_block(30);
+        assert!(!CollatorSelection::is_account_candidate(&3));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::NominationSlashed(10, 3, 5),
+        ));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::NominationSlashed(11, 3, 2),
+        ));
+
+        assert_eq!(Nominations::<Test>::get(3, 10), Some(45));
+        assert_eq!(TotalNominations::<Test>::get(3), (1, 45));
+        assert_eq!(Balances::reserved_balance(10), 45);
+        assert_eq!(UnbondingNominations::<Test>::get(3, 11), Some((3, 18)));
+        assert_eq!(Balances::reserved_balance(11), 18);
+        // 1 from the candidate, 5 + 2 from the nominators
+        assert_eq!(Balances::free_balance(5), 108);
+
+        // nominations of the kicked candidate can be un-bonded
+        assert_ok!(CollatorSelection::unbond_nomination(
+            RuntimeOrigin::signed(10),
+            3,
+            45
+        ));
+        assert_ok!(CollatorSelection::withdraw_nomination(
+            RuntimeOrigin::signed(11),
+            3
+        ));
+        assert_eq!(Balances::free_balance(11), 98);
+    });
+}
+
+fn setup_deferred_slash() {
+    KickThreshold::set(1);
+    <crate::SlashDestination<Test>>::put(5);
+    Balances::make_free_balance_be(&10, 100);
+
+    assert_noop!(
+        CollatorSelection::set_slash_defer_duration(RuntimeOrigin::signed(1), 2),
+        BadOrigin
+    );
+    assert_ok!(CollatorSelection::set_slash_defer_duration(
+        RuntimeOrigin::signed(RootAccount::get()),
+        2
+    ));
+    assert_eq!(SlashDeferDuration::<Test>::get(), 2);
+
+    assert_ok!(CollatorSelection::register_as_candidate(
+        RuntimeOrigin::signed(3)
+    ));
+    assert_ok!(CollatorSelection::register_as_candidate(
+        RuntimeOrigin::signed(4)
+    ));
+    assert_ok!(CollatorSelection::nominate(
+        RuntimeOrigin::signed(10),
+        3,
+        50
+    ));
+
+    // 3 missed its slot, gets kicked in session 3 and the slash is deferred
+    initialize_to_block(20);
+    miss_slot(3);
+    initialize_to_block(30);
+    assert!(!CollatorSelection::is_account_candidate(&3));
+    System::assert_has_event(RuntimeEvent::CollatorSelection(
+        crate::Event::SlashDeferred(3, 5, 6),
+    ));
+    assert_eq!(
+        DeferredSlashes::<Test>::get(5, 3),
+        Some(DeferredSlash {
+            own: 1,
+            nominators: vec![(10, 5)],
+        })
+    );
+
+    // slashed funds are held by the pallet, out of reach of the slashed accounts
+    assert_eq!(Balances::free_balance(3), 99);
+    assert_eq!(Balances::reserved_balance(3), 0);
+    assert_eq!(Nominations::<Test>::get(3, 10), Some(45));
+    assert_eq!(Balances::reserved_balance(10), 45);
+    assert_eq!(
+        Balances::free_balance(CollatorSelection::slash_holding_account_id()),
+        6
+    );
+    assert_eq!(Balances::free_balance(5), 100);
+}
+
+#[test]
+fn deferred_slash_is_applied() {
+    new_test_ext().execute_with(|| {
+        setup_deferred_slash();
+
+        initialize_to_block(40);
+        assert!(DeferredSlashes::<Test>::contains_key(5, 3));
+        assert_eq!(Balances::free_balance(5), 100);
+
+        // applied when session 5 starts
+        initialize_to_block(50);
+        assert!(!DeferredSlashes::<Test>::contains_key(5, 3));
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::DeferredSlashApplied(3, 6),
+        ));
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::free_balance(3), 99);
+        assert_eq!(Balances::reserved_balance(10), 45);
+        assert_eq!(Balances::free_balance(5), 106);
+        assert_eq!(
+            Balances::free_balance(CollatorSelection::slash_holding_account_id()),
+            0
+        );
+    });
+}
+
+#[test]
+fn deferred_slash_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        setup_deferred_slash();
+
+        assert_noop!(
+            CollatorSelection::cancel_deferred_slash(RuntimeOrigin::signed(1), 5, 3),
+            BadOrigin
+        );
+        assert_noop!(
+            CollatorSelection::cancel_deferred_slash(
+                RuntimeOrigin::signed(RootAccount::get()),
+                3,
+                3
+            ),
+            Error::<Test>::NoDeferredSlash
+        );
+        assert_ok!(CollatorSelection::cancel_deferred_slash(
+            RuntimeOrigin::signed(RootAccount::get()),
+            5,
+            3
+        ));
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::DeferredSlashCancelled(3, 5, 6),
+        ));
+
+        // candidate and nominator are refunded
+        assert_eq!(Balances::free_balance(3), 100);
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::free_balance(10), 55);
+        assert_eq!(Balances::reserved_balance(10), 45);
+        assert_eq!(
+            Balances::free_balance(CollatorSelection::slash_holding_account_id()),
+            0
+        );
+
+        initialize_to_block(50);
+        assert_eq!(Balances::free_balance(5), 100);
+    });
+}
+
+#[test]
+fn deferred_slash_below_existential_deposit_is_applied_immediately() {
+    new_test_ext().execute_with(|| {
+        KickThreshold::set(1);
+        <crate::SlashDestination<Test>>::put(5);
+        assert_ok!(CollatorSelection::set_slash_defer_duration(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+
+        // slash of 1 without nominations can't create the holding account
+        initialize_to_block(20);
+        miss_slot(3);
+        initialize_to_block(30);
+        assert!(!CollatorSelection::is_account_candidate(&3));
+        assert_eq!(DeferredSlashes::<Test>::iter().count(), 0);
+        assert_eq!(Balances::free_balance(3), 99);
+        assert_eq!(Balances::free_balance(5), 101);
+        assert_eq!(
+            Balances::free_balance(CollatorSelection::slash_holding_account_id()),
+            0
+        );
+    });
+}
+
+#[test]
+fn set_reward_mode_works() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(CollatorRewardMode::<Test>::get(), RewardMode::PerBlock);
+
+        assert_noop!(
+            CollatorSelection::set_reward_mode(RuntimeOrigin::signed(1), RewardMode::PerSession),
+            BadOrigin
+        );
+        assert_ok!(CollatorSelection::set_reward_mode(
+            RuntimeOrigin::signed(RootAccount::get()),
+            RewardMode::PerSession
+        ));
+        assert_eq!(CollatorRewardMode::<Test>::get(), RewardMode::PerSession);
+    });
+}
+
+#[test]
+fn per_session_mode_records_authored_blocks() {
+    new_test_ext().execute_with(|| {
+        // put 100 in the pot + 5 for ED
+        Balances::make_free_balance_be(&CollatorSelection::account_id(), 105);
+        CollatorRewardMode::<Test>::put(RewardMode::PerSession);
+
+        // triggers `note_author`, no reward per block
+        Authorship::on_initialize(1);
+        assert_eq!(AuthoredBlocks::<Test>::get(4), 1);
+        assert_eq!(Balances::free_balance(4), 100);
+        assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 105);
+
+        // collators are planned for the sessions
+        initialize_to_block(10);
+        assert_eq!(PlannedCollators::<Test>::get(2), vec![1, 2]);
+        assert!(!PlannedCollators::<Test>::contains_key(0));
+        assert_eq!(PerformanceHistory::<Test>::get(0).len(), 2);
+    });
+}
+
+#[test]
+fn session_rewards_by_performance() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+        // put 300 in the pot + 5 for ED
+        Balances::make_free_balance_be(&CollatorSelection::account_id(), 305);
+        CollatorRewardMode::<Test>::put(RewardMode::PerSession);
+
+        PlannedCollators::<Test>::insert(5, vec![3, 4, 5]);
+        AuthoredBlocks::<Test>::insert(3, 4);
+        AuthoredBlocks::<Test>::insert(4, 1);
+        MissedSlots::<Test>::insert(4, 1);
+        AuthoredBlocks::<Test>::insert(5, 1);
+
+        CollatorSelection::end_session(5);
+
+        // 100 for each collator at full performance, judged by its own assigned slots.
+        // 4 produced half of its slots, which pays a quarter. 5 had a single slot and took it.
+        let performance = |who, authored, expected, reward| CollatorPerformance {
+            who,
+            authored,
+            expected,
+            reward,
+        };
+        assert_eq!(
+            PerformanceHistory::<Test>::get(5),
+            vec![
+                performance(3, 4, 4, 100),
+                performance(4, 1, 2, 25),
+                performance(5, 1, 1, 100)
+            ]
+        );
+        System::assert_last_event(RuntimeEvent::CollatorSelection(
+            crate::Event::SessionRewardsPaid(5, 225),
+        ));
+        assert_eq!(Balances::free_balance(3), 200);
+        assert_eq!(Balances::free_balance(4), 125);
+        assert_eq!(Balances::free_balance(5), 200);
+        // the rest stays in the pot for the next session
+        assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 80);
+        assert!(!PlannedCollators::<Test>::contains_key(5));
+        assert_eq!(AuthoredBlocks::<Test>::iter().count(), 0);
+
+        // only performance is recorded in per block mode
+        CollatorRewardMode::<Test>::put(RewardMode::PerBlock);
+        PlannedCollators::<Test>::insert(6, vec![3]);
+        CollatorSelection::end_session(6);
+        assert_eq!(
+            PerformanceHistory::<Test>::get(6),
+            vec![CollatorPerformance {
+                who: 3,
+                authored: 0,
+                expected: 0,
+                reward: 0,
+            }]
+        );
+        assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 80);
+
+        // history is pruned
+        PlannedCollators::<Test>::insert(7, vec![3]);
+        CollatorSelection::end_session(7);
+        assert!(!PerformanceHistory::<Test>::contains_key(5));
+        assert!(PerformanceHistory::<Test>::contains_key(6));
+    });
+}
+
+#[test]
+fn collator_status_reports_kick_risk() {
+    new_test_ext().execute_with(|| {
+        initialize_to_block(1);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(3)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(4)
+        ));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(5)
+        ));
+        assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(5), 20));
+
+        // 4 authors every block
+        initialize_to_block(5);
+        assert_eq!(
+            CollatorSelection::candidate_statuses(),
+            vec![
+                CandidateStatus {
+                    who: 5,
+                    deposit: 20,
+                    total_stake: 20,
+                    elected: true,
+                    last_authored_block: 0,
+                    missed_slots: 0,
+                    missed_sessions: 0,
+                    sessions_until_kick: 2,
+                },
+                CandidateStatus {
+                    who: 3,
+                    deposit: 10,
+                    total_stake: 10,
+                    elected: true,
+                    last_authored_block: 0,
+                    missed_slots: 0,
+                    missed_sessions: 0,
+                    sessions_until_kick: 2,
+                },
+                CandidateStatus {
+                    who: 4,
+                    deposit: 10,
+                    total_stake: 10,
+                    elected: false,
+                    last_authored_block: 5,
+                    missed_slots: 0,
+                    missed_sessions: 0,
+                    sessions_until_kick: 2,
+                },
+            ]
+        );
+        assert_eq!(CollatorSelection::next_session_collators(), vec![1, 2]);
+
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(3)));
+        assert_eq!(
+            CollatorSelection::non_candidate_statuses(),
+            vec![NonCandidateStatus {
+                who: 3,
+                deposit: 10,
+                unlock_session: 1,
+                sessions_until_kick: 2,
+            }]
+        );
+
+        initialize_to_block(10);
+        assert_eq!(
+            CollatorSelection::next_session_collators(),
+            vec![1, 2, 5, 4]
+        );
+
+        initialize_to_block(20);
+        miss_slot(5);
+        assert_eq!(CollatorSelection::candidate_statuses()[0].missed_slots, 1);
+
+        // stale candidates report the sessions left before being kicked
+        initialize_to_block(30);
+        let status = &CollatorSelection::candidate_statuses()[0];
+        assert_eq!(status.who, 5);
+        assert_eq!(status.missed_slots, 0);
+        assert_eq!(status.missed_sessions, 1);
+        assert_eq!(status.sessions_until_kick, 1);
+    });
+}
+
+#[test]
+fn migration_ranks_candidates_by_bond() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(0).put::<CollatorSelection>();
+        let candidate = |who, deposit| CandidateInfo { who, deposit };
+        Candidates::<Test>::put(vec![
+            candidate(3, 10),
+            candidate(4, 20),
+            candidate(5, 10),
+            candidate(6, 30),
+        ]);
+
+        crate::migrations::versioned::V0ToV1::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            Candidates::<Test>::get(),
+            vec![
+                candidate(6, 30),
+                candidate(4, 20),
+                candidate(3, 10),
+                candidate(5, 10),
+            ]
+        );
+        assert_eq!(CollatorSelection::on_chain_storage_version(), 1);
     });
 }
 
@@ -55,8 +55,25 @@ pub trait WeightInfo {
 	fn register_as_candidate(c: u32, ) -> Weight;
 	fn leave_intent(c: u32, ) -> Weight;
 	fn withdraw_bond() -> Weight;
-	fn note_author() -> Weight;
-	fn new_session(r: u32, c: u32, ) -> Weight;
+	fn note_author(n: u32, ) -> Weight;
+	fn new_session(r: u32, c: u32, n: u32, ) -> Weight;
+	fn update_bond(c: u32, ) -> Weight;
+	fn take_candidate_slot(c: u32, ) -> Weight;
+	fn nominate(c: u32, ) -> Weight;
+	fn unbond_nomination(c: u32, ) -> Weight;
+	fn withdraw_nomination() -> Weight;
+	fn set_commission() -> Weight;
+	fn end_session(c: u32, n: u32, ) -> Weight;
+	fn set_reward_mode() -> Weight;
+	fn set_slash_defer_duration() -> Weight;
+	fn cancel_deferred_slash(n: u32, ) -> Weight;
+	fn start_session(s: u32, ) -> Weight;
+	fn note_missed_slots(m: u32, ) -> Weight;
+	fn add_invulnerable(b: u32, c: u32, ) -> Weight;
+	fn remove_invulnerable(b: u32, ) -> Weight;
+	fn schedule_phase_out() -> Weight;
+	fn cancel_phase_out() -> Weight;
+	fn set_term_limit() -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -162,18 +179,36 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:34 w:34)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:1 w:0)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:1 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:0)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::AuthoredBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:1 w:0)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn note_author() -> Weight {
+	/// The range of component `n` is `[0, 32]`.
+	fn note_author(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `226`
-		//  Estimated: `6196`
-		// Minimum execution time: 35_307_000 picoseconds.
-		Weight::from_parts(35_803_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `226 + n * (101 ±0)`
+		//  Estimated: `6196 + n * (2603 ±0)`
+		// Minimum execution time: 38_125_000 picoseconds.
+		Weight::from_parts(40_093_736, 6196)
+			// Standard Error: 3_127
+			.saturating_add(Weight::from_parts(24_610_335, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -189,21 +224,353 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `CollatorSelection::SlashDestination` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::NonCandidates` (r:0 w:143)
 	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:32)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:0)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[1, 148]`.
 	/// The range of component `c` is `[1, 148]`.
-	fn new_session(r: u32, c: u32, ) -> Weight {
+	/// The range of component `n` is `[0, 32]`.
+	fn new_session(r: u32, c: u32, n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4554 + c * (97 ±0) + r * (112 ±0)`
-		//  Estimated: `6296 + c * (2637 ±0) + r * (2599 ±0)`
+		//  Measured:  `4554 + c * (97 ±0) + r * (112 ±0) + n * (101 ±0)`
+		//  Estimated: `6296 + c * (2637 ±0) + r * (2599 ±0) + n * (2603 ±0)`
 		// Minimum execution time: 20_506_000 picoseconds.
 		Weight::from_parts(20_755_000, 6296)
 			// Standard Error: 503_081
 			.saturating_add(Weight::from_parts(16_868_215, 0).saturating_mul(c.into()))
+			// Standard Error: 61_274
+			.saturating_add(Weight::from_parts(21_337_490, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2637).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 2599).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::DesiredCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::DesiredCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn update_bond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `749 + c * (50 ±0)`
+		//  Estimated: `2234 + c * (51 ±0)`
+		// Minimum execution time: 21_652_000 picoseconds.
+		Weight::from_parts(22_937_581, 2234)
+			// Standard Error: 516
+			.saturating_add(Weight::from_parts(52_114, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 51).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:2 w:2)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn take_candidate_slot(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `887 + c * (51 ±0)`
+		//  Estimated: `4307 + c * (51 ±0)`
+		// Minimum execution time: 49_158_000 picoseconds.
+		Weight::from_parts(50_779_128, 4307)
+			// Standard Error: 611
+			.saturating_add(Weight::from_parts(52_114, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 51).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:149 w:1)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn nominate(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806 + c * (99 ±0)`
+		//  Estimated: `4271 + c * (2575 ±0)`
+		// Minimum execution time: 27_319_000 picoseconds.
+		Weight::from_parts(28_104_772, 4271)
+			// Standard Error: 1_894
+			.saturating_add(Weight::from_parts(1_942_605, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Nominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:149 w:1)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn unbond_nomination(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912 + c * (99 ±0)`
+		//  Estimated: `4377 + c * (2575 ±0)`
+		// Minimum execution time: 29_840_000 picoseconds.
+		Weight::from_parts(30_517_094, 4377)
+			// Standard Error: 1_902
+			.saturating_add(Weight::from_parts(1_948_221, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn withdraw_nomination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3902`
+		// Minimum execution time: 21_035_000 picoseconds.
+		Weight::from_parts(21_603_000, 3902)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1536`
+		//  Estimated: `3021`
+		// Minimum execution time: 12_204_000 picoseconds.
+		Weight::from_parts(12_733_000, 3021)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::PlannedCollators` (r:1 w:1)
+	/// Proof: `CollatorSelection::PlannedCollators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::AuthoredBlocks` (r:149 w:148)
+	/// Proof: `CollatorSelection::AuthoredBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:1 w:0)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:149 w:149)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:148 w:0)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:1 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:0)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PerformanceHistory` (r:0 w:2)
+	/// Proof: `CollatorSelection::PerformanceHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	/// The range of component `n` is `[0, 32]`.
+	fn end_session(c: u32, n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317 + c * (141 ±0) + n * (101 ±0)`
+		//  Estimated: `6196 + c * (2603 ±0) + n * (2603 ±0)`
+		// Minimum execution time: 61_507_000 picoseconds.
+		Weight::from_parts(23_884_102, 6196)
+			// Standard Error: 15_307
+			.saturating_add(Weight::from_parts(29_402_117, 0).saturating_mul(c.into()))
+			// Standard Error: 70_618
+			.saturating_add(Weight::from_parts(24_703_845, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:0 w:1)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_reward_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_311_000 picoseconds.
+		Weight::from_parts(5_498_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::SlashDeferDuration` (r:0 w:1)
+	/// Proof: `CollatorSelection::SlashDeferDuration` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_slash_defer_duration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_302_000 picoseconds.
+		Weight::from_parts(5_487_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::DeferredSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::DeferredSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn cancel_deferred_slash(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364 + n * (49 ±0)`
+		//  Estimated: `6196 + n * (2603 ±0)`
+		// Minimum execution time: 41_874_000 picoseconds.
+		Weight::from_parts(43_106_388, 6196)
+			// Standard Error: 8_419
+			.saturating_add(Weight::from_parts(26_518_207, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::DeferredSlashes` (r:21 w:20)
+	/// Proof: `CollatorSelection::DeferredSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::SlashDestination` (r:1 w:0)
+	/// Proof: `CollatorSelection::SlashDestination` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 20]`.
+	fn start_session(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + s * (97 ±0)`
+		//  Estimated: `6196 + s * (2573 ±0)`
+		// Minimum execution time: 12_377_000 picoseconds.
+		Weight::from_parts(13_045_116, 6196)
+			// Standard Error: 6_288
+			.saturating_add(Weight::from_parts(27_903_561, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(s.into()))
+	}
+	/// Storage: `CollatorSelection::LastSlot` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PlannedCollators` (r:1 w:0)
+	/// Proof: `CollatorSelection::PlannedCollators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::MissedSlots` (r:20 w:20)
+	/// Proof: `CollatorSelection::MissedSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[0, 20]`.
+	fn note_missed_slots(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245 + m * (41 ±0)`
+		//  Estimated: `3710 + m * (2517 ±0)`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(8_530_442, 3710)
+			// Standard Error: 2_106
+			.saturating_add(Weight::from_parts(3_468_177, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
+	}
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 47]`.
+	/// The range of component `c` is `[1, 148]`.
+	fn add_invulnerable(b: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `627 + b * (32 ±0) + c * (53 ±0)`
+		//  Estimated: `4092 + b * (33 ±0) + c * (54 ±0)`
+		// Minimum execution time: 24_861_000 picoseconds.
+		Weight::from_parts(25_412_306, 4092)
+			// Standard Error: 1_842
+			.saturating_add(Weight::from_parts(61_507, 0).saturating_mul(b.into()))
+			// Standard Error: 592
+			.saturating_add(Weight::from_parts(87_339, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 48]`.
+	fn remove_invulnerable(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119 + b * (32 ±0)`
+		//  Estimated: `1604 + b * (32 ±0)`
+		// Minimum execution time: 9_143_000 picoseconds.
+		Weight::from_parts(9_598_372, 1604)
+			// Standard Error: 1_205
+			.saturating_add(Weight::from_parts(47_880, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(b.into()))
+	}
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PhaseOut` (r:0 w:1)
+	/// Proof: `CollatorSelection::PhaseOut` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn schedule_phase_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 7_018_000 picoseconds.
+		Weight::from_parts(7_302_000, 1527)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::PhaseOut` (r:1 w:1)
+	/// Proof: `CollatorSelection::PhaseOut` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn cancel_phase_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55`
+		//  Estimated: `1497`
+		// Minimum execution time: 7_241_000 picoseconds.
+		Weight::from_parts(7_566_000, 1497)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::TermLimit` (r:0 w:1)
+	/// Proof: `CollatorSelection::TermLimit` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RotationQueue` (r:0 w:1)
+	/// Proof: `CollatorSelection::RotationQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_term_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_118_000 picoseconds.
+		Weight::from_parts(6_392_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
@@ -309,18 +676,36 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
-	/// Storage: `System::Account` (r:2 w:2)
+	/// Storage: `System::Account` (r:34 w:34)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:1 w:0)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:1 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:0)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::AuthoredBlocks` (r:1 w:1)
+	/// Proof: `CollatorSelection::AuthoredBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:1 w:0)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
 	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
-	fn note_author() -> Weight {
+	/// The range of component `n` is `[0, 32]`.
+	fn note_author(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `226`
-		//  Estimated: `6196`
-		// Minimum execution time: 35_307_000 picoseconds.
-		Weight::from_parts(35_803_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `226 + n * (101 ±0)`
+		//  Estimated: `6196 + n * (2603 ±0)`
+		// Minimum execution time: 38_125_000 picoseconds.
+		Weight::from_parts(40_093_736, 6196)
+			// Standard Error: 3_127
+			.saturating_add(Weight::from_parts(24_610_335, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
 	}
 	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
 	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -336,20 +721,352 @@ impl WeightInfo for () {
 	/// Proof: `CollatorSelection::SlashDestination` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `CollatorSelection::NonCandidates` (r:0 w:143)
 	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:32)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:0)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `r` is `[1, 148]`.
 	/// The range of component `c` is `[1, 148]`.
-	fn new_session(r: u32, c: u32, ) -> Weight {
+	/// The range of component `n` is `[0, 32]`.
+	fn new_session(r: u32, c: u32, n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4554 + c * (97 ±0) + r * (112 ±0)`
-		//  Estimated: `6296 + c * (2637 ±0) + r * (2599 ±0)`
+		//  Measured:  `4554 + c * (97 ±0) + r * (112 ±0) + n * (101 ±0)`
+		//  Estimated: `6296 + c * (2637 ±0) + r * (2599 ±0) + n * (2603 ±0)`
 		// Minimum execution time: 20_506_000 picoseconds.
 		Weight::from_parts(20_755_000, 6296)
 			// Standard Error: 503_081
 			.saturating_add(Weight::from_parts(16_868_215, 0).saturating_mul(c.into()))
+			// Standard Error: 61_274
+			.saturating_add(Weight::from_parts(21_337_490, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2637).saturating_mul(c.into()))
 			.saturating_add(Weight::from_parts(0, 2599).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::DesiredCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::DesiredCandidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::Validators` (r:1 w:0)
+	/// Proof: `Session::Validators` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn update_bond(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `749 + c * (50 ±0)`
+		//  Estimated: `2234 + c * (51 ±0)`
+		// Minimum execution time: 21_652_000 picoseconds.
+		Weight::from_parts(22_937_581, 2234)
+			// Standard Error: 516
+			.saturating_add(Weight::from_parts(52_114, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 51).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CandidacyBond` (r:1 w:0)
+	/// Proof: `CollatorSelection::CandidacyBond` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:0)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `DappStaking::Ledger` (r:1 w:0)
+	/// Proof: `DappStaking::Ledger` (`max_values`: None, `max_size`: Some(310), added: 2785, mode: `MaxEncodedLen`)
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:2 w:2)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::LastAuthoredBlock` (r:0 w:1)
+	/// Proof: `CollatorSelection::LastAuthoredBlock` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn take_candidate_slot(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `887 + c * (51 ±0)`
+		//  Estimated: `4307 + c * (51 ±0)`
+		// Minimum execution time: 49_158_000 picoseconds.
+		Weight::from_parts(50_779_128, 4307)
+			// Standard Error: 611
+			.saturating_add(Weight::from_parts(52_114, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 51).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:149 w:1)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn nominate(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `806 + c * (99 ±0)`
+		//  Estimated: `4271 + c * (2575 ±0)`
+		// Minimum execution time: 27_319_000 picoseconds.
+		Weight::from_parts(28_104_772, 4271)
+			// Standard Error: 1_894
+			.saturating_add(Weight::from_parts(1_942_605, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Nominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:149 w:1)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	fn unbond_nomination(c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912 + c * (99 ±0)`
+		//  Estimated: `4377 + c * (2575 ±0)`
+		// Minimum execution time: 29_840_000 picoseconds.
+		Weight::from_parts(30_517_094, 4377)
+			// Standard Error: 1_902
+			.saturating_add(Weight::from_parts(1_948_221, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 2575).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::UnbondingNominations` (r:1 w:1)
+	/// Proof: `CollatorSelection::UnbondingNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn withdraw_nomination() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `437`
+		//  Estimated: `3902`
+		// Minimum execution time: 21_035_000 picoseconds.
+		Weight::from_parts(21_603_000, 3902)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:0 w:1)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1536`
+		//  Estimated: `3021`
+		// Minimum execution time: 12_204_000 picoseconds.
+		Weight::from_parts(12_733_000, 3021)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::PlannedCollators` (r:1 w:1)
+	/// Proof: `CollatorSelection::PlannedCollators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::AuthoredBlocks` (r:149 w:148)
+	/// Proof: `CollatorSelection::AuthoredBlocks` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:1 w:0)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:149 w:149)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::TotalNominations` (r:148 w:0)
+	/// Proof: `CollatorSelection::TotalNominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:0)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Commission` (r:1 w:0)
+	/// Proof: `CollatorSelection::Commission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Nominations` (r:33 w:0)
+	/// Proof: `CollatorSelection::Nominations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PerformanceHistory` (r:0 w:2)
+	/// Proof: `CollatorSelection::PerformanceHistory` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `c` is `[1, 148]`.
+	/// The range of component `n` is `[0, 32]`.
+	fn end_session(c: u32, n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317 + c * (141 ±0) + n * (101 ±0)`
+		//  Estimated: `6196 + c * (2603 ±0) + n * (2603 ±0)`
+		// Minimum execution time: 61_507_000 picoseconds.
+		Weight::from_parts(23_884_102, 6196)
+			// Standard Error: 15_307
+			.saturating_add(Weight::from_parts(29_402_117, 0).saturating_mul(c.into()))
+			// Standard Error: 70_618
+			.saturating_add(Weight::from_parts(24_703_845, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::CollatorRewardMode` (r:0 w:1)
+	/// Proof: `CollatorSelection::CollatorRewardMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_reward_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_311_000 picoseconds.
+		Weight::from_parts(5_498_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::SlashDeferDuration` (r:0 w:1)
+	/// Proof: `CollatorSelection::SlashDeferDuration` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_slash_defer_duration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_302_000 picoseconds.
+		Weight::from_parts(5_487_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::DeferredSlashes` (r:1 w:1)
+	/// Proof: `CollatorSelection::DeferredSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:66 w:66)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 64]`.
+	fn cancel_deferred_slash(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `364 + n * (49 ±0)`
+		//  Estimated: `6196 + n * (2603 ±0)`
+		// Minimum execution time: 41_874_000 picoseconds.
+		Weight::from_parts(43_106_388, 6196)
+			// Standard Error: 8_419
+			.saturating_add(Weight::from_parts(26_518_207, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(n.into()))
+	}
+	/// Storage: `CollatorSelection::DeferredSlashes` (r:21 w:20)
+	/// Proof: `CollatorSelection::DeferredSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::SlashDestination` (r:1 w:0)
+	/// Proof: `CollatorSelection::SlashDestination` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 20]`.
+	fn start_session(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `281 + s * (97 ±0)`
+		//  Estimated: `6196 + s * (2573 ±0)`
+		// Minimum execution time: 12_377_000 picoseconds.
+		Weight::from_parts(13_045_116, 6196)
+			// Standard Error: 6_288
+			.saturating_add(Weight::from_parts(27_903_561, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2573).saturating_mul(s.into()))
+	}
+	/// Storage: `CollatorSelection::LastSlot` (r:1 w:1)
+	/// Proof: `CollatorSelection::LastSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PlannedCollators` (r:1 w:0)
+	/// Proof: `CollatorSelection::PlannedCollators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::MissedSlots` (r:20 w:20)
+	/// Proof: `CollatorSelection::MissedSlots` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[0, 20]`.
+	fn note_missed_slots(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245 + m * (41 ±0)`
+		//  Estimated: `3710 + m * (2517 ±0)`
+		// Minimum execution time: 7_912_000 picoseconds.
+		Weight::from_parts(8_530_442, 3710)
+			// Standard Error: 2_106
+			.saturating_add(Weight::from_parts(3_468_177, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2517).saturating_mul(m.into()))
+	}
+	/// Storage: `Session::NextKeys` (r:1 w:0)
+	/// Proof: `Session::NextKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::Candidates` (r:1 w:1)
+	/// Proof: `CollatorSelection::Candidates` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::NonCandidates` (r:0 w:1)
+	/// Proof: `CollatorSelection::NonCandidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 47]`.
+	/// The range of component `c` is `[1, 148]`.
+	fn add_invulnerable(b: u32, c: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `627 + b * (32 ±0) + c * (53 ±0)`
+		//  Estimated: `4092 + b * (33 ±0) + c * (54 ±0)`
+		// Minimum execution time: 24_861_000 picoseconds.
+		Weight::from_parts(25_412_306, 4092)
+			// Standard Error: 1_842
+			.saturating_add(Weight::from_parts(61_507, 0).saturating_mul(b.into()))
+			// Standard Error: 592
+			.saturating_add(Weight::from_parts(87_339, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(b.into()))
+			.saturating_add(Weight::from_parts(0, 54).saturating_mul(c.into()))
+	}
+	/// Storage: `CollatorSelection::Invulnerables` (r:1 w:1)
+	/// Proof: `CollatorSelection::Invulnerables` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[1, 48]`.
+	fn remove_invulnerable(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `119 + b * (32 ±0)`
+		//  Estimated: `1604 + b * (32 ±0)`
+		// Minimum execution time: 9_143_000 picoseconds.
+		Weight::from_parts(9_598_372, 1604)
+			// Standard Error: 1_205
+			.saturating_add(Weight::from_parts(47_880, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(b.into()))
+	}
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `CollatorSelection::PhaseOut` (r:0 w:1)
+	/// Proof: `CollatorSelection::PhaseOut` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn schedule_phase_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 7_018_000 picoseconds.
+		Weight::from_parts(7_302_000, 1527)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::PhaseOut` (r:1 w:1)
+	/// Proof: `CollatorSelection::PhaseOut` (`max_values`: Some(1), `max_size`: Some(12), added: 507, mode: `MaxEncodedLen`)
+	fn cancel_phase_out() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `55`
+		//  Estimated: `1497`
+		// Minimum execution time: 7_241_000 picoseconds.
+		Weight::from_parts(7_566_000, 1497)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollatorSelection::TermLimit` (r:0 w:1)
+	/// Proof: `CollatorSelection::TermLimit` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `CollatorSelection::RotationQueue` (r:0 w:1)
+	/// Proof: `CollatorSelection::RotationQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_term_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_118_000 picoseconds.
+		Weight::from_parts(6_392_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
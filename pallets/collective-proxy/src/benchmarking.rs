@@ -25,23 +25,163 @@ pub(super) fn assert_last_event<T: Config>(generic_event: <T as Config>::Runtime
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Name of the proxy used in the benchmarks.
+fn proxy_name<T: Config>() -> ProxyName<T> {
+    BoundedVec::truncate_from(b"bench".to_vec())
+}
+
+/// Register the proxy used in the benchmarks.
+fn setup_proxy<T: Config>() {
+    let proxy = proxy_name::<T>();
+    Proxies::<T>::insert(
+        &proxy,
+        ProxyInfo {
+            account: Pallet::<T>::proxy_account(&proxy),
+            origin: T::BenchmarkHelper::proxy_origin(),
+            filter: T::BenchmarkHelper::call_filter(),
+        },
+    );
+}
+
 #[benchmarks()]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
     fn execute_call() {
-        let origin = T::CollectiveProxy::try_successful_origin()
+        setup_proxy::<T>();
+        let proxy = proxy_name::<T>();
+        let origin = T::CollectiveProxy::try_successful_origin(&T::BenchmarkHelper::proxy_origin())
+            .expect("Must succeed in order to run benchmarks.");
+
+        // A bit dirty, but the filter profile must allow the `remark` call.
+        let call: <T as Config>::RuntimeCall =
+            frame_system::Call::<T>::remark { remark: vec![] }.into();
+
+        // Worst case, call type is rate limited and was already executed in this period.
+        let call_name = Pallet::<T>::call_name(&call);
+        CallRateLimits::<T>::insert(&proxy, &call_name, 2);
+        CallCounts::<T>::insert(&proxy, &call_name, (Pallet::<T>::current_period(), 1));
+
+        #[extrinsic_call]
+        execute_proxy_call(origin as T::RuntimeOrigin, proxy.clone(), Box::new(call));
+
+        assert_last_event::<T>(
+            Event::<T>::CollectiveProxyExecuted {
+                proxy: proxy.clone(),
+                result: Ok(()),
+            }
+            .into(),
+        );
+        assert_eq!(
+            CallCounts::<T>::get(&proxy, &call_name).map(|(_, count)| count),
+            Some(2)
+        );
+    }
+
+    #[benchmark]
+    fn set_spend_limit() {
+        let origin = T::ManagerOrigin::try_successful_origin()
             .expect("Must succeed in order to run benchmarks.");
+        let proxy = proxy_name::<T>();
 
-        // A bit dirty, but runtime should ensure to allow the `remark` call.
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            proxy.clone(),
+            SpendAsset::Native,
+            Some(1_000),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::SpendLimitSet {
+                proxy,
+                asset: SpendAsset::Native,
+                limit: Some(1_000),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn set_call_rate_limit() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let proxy = proxy_name::<T>();
         let call: <T as Config>::RuntimeCall =
             frame_system::Call::<T>::remark { remark: vec![] }.into();
+        let call_name = Pallet::<T>::call_name(&call);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            proxy.clone(),
+            call_name.clone(),
+            Some(10),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::CallRateLimitSet {
+                proxy,
+                call_name,
+                limit: Some(10),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn register_proxy() {
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let proxy = proxy_name::<T>();
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            proxy.clone(),
+            T::BenchmarkHelper::proxy_origin(),
+            T::BenchmarkHelper::call_filter(),
+        );
+
+        assert_last_event::<T>(
+            Event::<T>::ProxyRegistered {
+                account: Pallet::<T>::proxy_account(&proxy),
+                proxy,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn update_proxy() {
+        setup_proxy::<T>();
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let proxy = proxy_name::<T>();
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            proxy.clone(),
+            T::BenchmarkHelper::proxy_origin(),
+            T::BenchmarkHelper::call_filter(),
+        );
+
+        assert_last_event::<T>(Event::<T>::ProxyUpdated { proxy }.into());
+    }
+
+    #[benchmark]
+    fn remove_proxy() {
+        setup_proxy::<T>();
+        let origin = T::ManagerOrigin::try_successful_origin()
+            .expect("Must succeed in order to run benchmarks.");
+        let proxy = proxy_name::<T>();
 
         #[extrinsic_call]
-        _(origin as T::RuntimeOrigin, Box::new(call));
+        _(origin as T::RuntimeOrigin, proxy.clone());
 
-        assert_last_event::<T>(Event::<T>::CollectiveProxyExecuted { result: Ok(()) }.into());
+        assert_last_event::<T>(Event::<T>::ProxyRemoved { proxy }.into());
     }
 
     impl_benchmark_test_suite!(
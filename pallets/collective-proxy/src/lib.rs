@@ -18,13 +18,18 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use astar_primitives::Balance;
 use frame_support::{
     dispatch::GetDispatchInfo,
     pallet_prelude::*,
-    traits::{InstanceFilter, IsType, OriginTrait},
+    traits::{EnsureOriginWithArg, GetCallMetadata, InstanceFilter, IsType, OriginTrait},
+    PalletId,
 };
 use frame_system::pallet_prelude::*;
-use sp_runtime::traits::Dispatchable;
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{
+    AccountIdConversion, CheckedDiv, Dispatchable, MaybeSerializeDeserialize, Saturating, Zero,
+};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -37,20 +42,100 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
+/// Asset spent by a call dispatched on behalf of the proxy account.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum SpendAsset<AssetId> {
+    /// The native currency.
+    Native,
+    /// An asset of the assets pallet.
+    Asset(AssetId),
+}
+
+/// Inspects the funds a call would spend from the proxy account.
+pub trait SpendInspector<Call, AssetId> {
+    /// Amounts spent by `call`, per asset. The same asset can appear more than once.
+    fn spends(call: &Call) -> Vec<(SpendAsset<AssetId>, Balance)>;
+}
+
+impl<Call, AssetId> SpendInspector<Call, AssetId> for () {
+    fn spends(_call: &Call) -> Vec<(SpendAsset<AssetId>, Balance)> {
+        Vec::new()
+    }
+}
+
+/// Unwraps the calls nested in a call, e.g. by a batch.
+pub trait NestedCalls<Call> {
+    /// Calls directly nested in `call`, not including `call` itself.
+    fn nested_calls(call: &Call) -> Vec<&Call>;
+}
+
+impl<Call> NestedCalls<Call> for () {
+    fn nested_calls(_call: &Call) -> Vec<&Call> {
+        Vec::new()
+    }
+}
+
+/// Call type, identified by the pallet and function names.
+pub type RuntimeCallNameOf<T> = (
+    BoundedVec<u8, <T as Config>::MaxNameLen>,
+    BoundedVec<u8, <T as Config>::MaxNameLen>,
+);
+
+/// Name of a registered proxy.
+pub type ProxyName<T> = BoundedVec<u8, <T as Config>::MaxProxyNameLen>;
+
+/// Registered collective proxy.
+#[derive(
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    Clone,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub struct ProxyInfo<AccountId, ProxyOrigin, CallFilter> {
+    /// Account on behalf of which the calls are dispatched.
+    pub account: AccountId,
+    /// Origin allowed to act through the proxy.
+    pub origin: ProxyOrigin,
+    /// Filter profile applied to the dispatched calls.
+    pub filter: CallFilter,
+}
+
+/// Registered collective proxy, as stored by the pallet.
+pub type ProxyInfoOf<T> = ProxyInfo<
+    <T as frame_system::Config>::AccountId,
+    <T as Config>::ProxyOrigin,
+    <T as Config>::CallFilter,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
+    /// The current storage version.
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    // TODO: The pallet is intentionally very basic. It could be improved to handle more origins, more aliases, etc.
-    // There could also be different instances, if such approach was needed.
-    // However, it's supposed to be the simplest solution possible to cover a specific scenario.
-    // Pallet is stateless and can easily be upgraded in the future.
+    #[cfg(feature = "runtime-benchmarks")]
+    pub trait BenchmarkHelper<ProxyOrigin, CallFilter> {
+        /// Origin of a proxy, for which a successful origin can be created.
+        fn proxy_origin() -> ProxyOrigin;
+
+        /// Filter profile of a proxy, which must allow `remark` calls.
+        fn call_filter() -> CallFilter;
+    }
 
     /// Configuration trait.
     #[pallet::config]
@@ -62,64 +147,526 @@ pub mod pallet {
         type RuntimeCall: Parameter
             + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin>
             + GetDispatchInfo
+            + GetCallMetadata
             + From<frame_system::Call<Self>>
             + IsType<<Self as frame_system::Config>::RuntimeCall>;
 
-        /// Origin that can act on behalf of the collective.
-        type CollectiveProxy: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// Identifies the origin allowed to act through a proxy, e.g. a specific collective.
+        type ProxyOrigin: Parameter + Member + MaxEncodedLen + MaybeSerializeDeserialize;
+
+        /// Ensures the origin is the one identified by the proxy origin.
+        type CollectiveProxy: EnsureOriginWithArg<
+            <Self as frame_system::Config>::RuntimeOrigin,
+            Self::ProxyOrigin,
+        >;
+
+        /// Origin that can manage the proxies, and set their spend and call rate limits.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Filter profile, determining whether a call can be executed through a proxy or not.
+        type CallFilter: Parameter
+            + Member
+            + MaxEncodedLen
+            + MaybeSerializeDeserialize
+            + InstanceFilter<<Self as Config>::RuntimeCall>;
+
+        /// Used to derive the accounts of the registered proxies.
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Identifier of the assets the proxy accounts can spend.
+        type AssetId: Parameter + MaxEncodedLen + Copy;
+
+        /// Inspects the funds spent by the calls, to enforce the spend limits.
+        type SpendInspector: SpendInspector<<Self as Config>::RuntimeCall, Self::AssetId>;
+
+        /// Unwraps the nested calls, e.g. of a batch, so each of them counts towards the call
+        /// rate limits.
+        type NestedCalls: NestedCalls<<Self as Config>::RuntimeCall>;
+
+        /// Number of blocks in a budget period, after which the spent amounts and call counts are
+        /// reset.
+        #[pallet::constant]
+        type BudgetPeriod: Get<BlockNumberFor<Self>>;
 
-        /// Account representing the collective treasury.
-        type ProxyAccountId: Get<Self::AccountId>;
+        /// Maximum length of the pallet and function names identifying a call type.
+        #[pallet::constant]
+        type MaxNameLen: Get<u32>;
 
-        /// Filter to determine whether a call can be executed or not.
-        type CallFilter: InstanceFilter<<Self as Config>::RuntimeCall> + Default;
+        /// Maximum length of a proxy name.
+        /// Should be short enough for the derived accounts of different names to be unique.
+        #[pallet::constant]
+        type MaxProxyNameLen: Get<u32>;
+
+        /// Proxy used by `execute_call`, i.e. the one the single proxy was migrated to when
+        /// named proxies were introduced.
+        type LegacyProxy: Get<ProxyName<Self>>;
 
         /// Weight info
         type WeightInfo: WeightInfo;
+
+        /// Helper trait for benchmarks.
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::ProxyOrigin, Self::CallFilter>;
     }
 
+    /// Registered proxies.
+    #[pallet::storage]
+    pub type Proxies<T: Config> =
+        StorageMap<_, Blake2_128Concat, ProxyName<T>, ProxyInfoOf<T>, OptionQuery>;
+
+    /// Maximum amount of each asset that can be spent by a proxy in a budget period.
+    /// Assets without a limit can be spent freely.
+    #[pallet::storage]
+    pub type SpendLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProxyName<T>,
+        Blake2_128Concat,
+        SpendAsset<T::AssetId>,
+        Balance,
+        OptionQuery,
+    >;
+
+    /// Amount spent by a proxy of each limited asset, with the budget period it was spent in.
+    #[pallet::storage]
+    pub type SpentAmounts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProxyName<T>,
+        Blake2_128Concat,
+        SpendAsset<T::AssetId>,
+        (BlockNumberFor<T>, Balance),
+        OptionQuery,
+    >;
+
+    /// Maximum number of calls of each call type that can be executed by a proxy in a budget
+    /// period. Call types without a limit can be executed freely.
+    #[pallet::storage]
+    pub type CallRateLimits<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProxyName<T>,
+        Blake2_128Concat,
+        RuntimeCallNameOf<T>,
+        u32,
+        OptionQuery,
+    >;
+
+    /// Number of calls of each limited call type executed by a proxy, with the budget period
+    /// they were executed in.
+    #[pallet::storage]
+    pub type CallCounts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        ProxyName<T>,
+        Blake2_128Concat,
+        RuntimeCallNameOf<T>,
+        (BlockNumberFor<T>, u32),
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Community proxy call executed successfully.
-        CollectiveProxyExecuted { result: DispatchResult },
+        CollectiveProxyExecuted {
+            proxy: ProxyName<T>,
+            result: DispatchResult,
+        },
+        /// The spend limit of an asset was set.
+        SpendLimitSet {
+            proxy: ProxyName<T>,
+            asset: SpendAsset<T::AssetId>,
+            limit: Option<Balance>,
+        },
+        /// The rate limit of a call type was set.
+        CallRateLimitSet {
+            proxy: ProxyName<T>,
+            call_name: RuntimeCallNameOf<T>,
+            limit: Option<u32>,
+        },
+        /// Funds were spent from the budget of the current period.
+        BudgetSpent {
+            proxy: ProxyName<T>,
+            asset: SpendAsset<T::AssetId>,
+            amount: Balance,
+            remaining: Balance,
+        },
+        /// A rate limited call was executed.
+        CallRateUsed {
+            proxy: ProxyName<T>,
+            call_name: RuntimeCallNameOf<T>,
+            remaining: u32,
+        },
+        /// A new proxy was registered.
+        ProxyRegistered {
+            proxy: ProxyName<T>,
+            account: T::AccountId,
+        },
+        /// The origin or the filter profile of a proxy was updated.
+        ProxyUpdated { proxy: ProxyName<T> },
+        /// A proxy was removed.
+        ProxyRemoved { proxy: ProxyName<T> },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The call would spend more than the remaining budget of the current period.
+        SpendLimitExceeded,
+        /// The call type was already executed the maximum number of times in the current period.
+        CallRateLimitExceeded,
+        /// No proxy is registered with the given name.
+        ProxyNotFound,
+        /// A proxy is already registered with the given name.
+        ProxyAlreadyRegistered,
+    }
+
+    #[pallet::genesis_config]
+    #[derive(DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Proxies registered at genesis, along with the accounts they act on behalf of.
+        pub proxies: Vec<(ProxyName<T>, ProxyInfoOf<T>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (proxy, info) in &self.proxies {
+                assert!(
+                    !Proxies::<T>::contains_key(proxy),
+                    "duplicate proxies in genesis."
+                );
+                Proxies::<T>::insert(proxy, info);
+            }
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Executes the call on a behalf of an aliased account.
+        /// Executes the call on a behalf of the account of the [`Config::LegacyProxy`].
         ///
-        /// The `origin` of the call is supposed to be a _collective_ (but can be anything) which can dispatch `call` on behalf of the aliased account.
-        /// It's essentially a proxy call that can be made by arbitrary origin type.
+        /// Kept at its call index for the encoded calls predating the named proxies, e.g. pending
+        /// motions. See `execute_proxy_call` for the details.
         #[pallet::call_index(0)]
-        #[pallet::weight({
-			let di = call.get_dispatch_info();
-			(T::WeightInfo::execute_call().saturating_add(di.weight), di.class)
-		})]
+        #[pallet::weight(Pallet::<T>::execute_call_weight(call))]
         pub fn execute_call(
             origin: OriginFor<T>,
             call: Box<<T as Config>::RuntimeCall>,
         ) -> DispatchResult {
+            Self::do_execute_call(origin, T::LegacyProxy::get(), call)
+        }
+
+        /// Executes the call on a behalf of the account of the `proxy`.
+        ///
+        /// The `origin` of the call is supposed to be a _collective_ (but can be anything) which can dispatch `call` on behalf of the aliased account.
+        /// It's essentially a proxy call that can be made by arbitrary origin type.
+        ///
+        /// The call must fit in the spend limits and the call rate limits of the proxy in the
+        /// current budget period, which are only used up if the call succeeds. The calls nested
+        /// in `call`, e.g. by a batch, count towards the call rate limits as well.
+        #[pallet::call_index(6)]
+        #[pallet::weight(Pallet::<T>::execute_call_weight(call))]
+        pub fn execute_proxy_call(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            Self::do_execute_call(origin, proxy, call)
+        }
+
+        /// Set the maximum amount of `asset` that can be spent by `proxy` in a budget period.
+        /// Use `None` to remove the limit.
+        ///
+        /// Limits can be set before the proxy is registered, and are kept when it's removed.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::set_spend_limit())]
+        pub fn set_spend_limit(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            asset: SpendAsset<T::AssetId>,
+            limit: Option<Balance>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            SpendLimits::<T>::set(&proxy, &asset, limit);
+            if limit.is_none() {
+                SpentAmounts::<T>::remove(&proxy, &asset);
+            }
+            Self::deposit_event(Event::SpendLimitSet {
+                proxy,
+                asset,
+                limit,
+            });
+            Ok(())
+        }
+
+        /// Set the maximum number of calls of `call_name` type that can be executed by `proxy`
+        /// in a budget period. Use `None` to remove the limit.
+        ///
+        /// Limits can be set before the proxy is registered, and are kept when it's removed.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::set_call_rate_limit())]
+        pub fn set_call_rate_limit(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            call_name: RuntimeCallNameOf<T>,
+            limit: Option<u32>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            CallRateLimits::<T>::set(&proxy, &call_name, limit);
+            if limit.is_none() {
+                CallCounts::<T>::remove(&proxy, &call_name);
+            }
+            Self::deposit_event(Event::CallRateLimitSet {
+                proxy,
+                call_name,
+                limit,
+            });
+            Ok(())
+        }
+
+        /// Register a new proxy, dispatching calls on behalf of an account derived from its name.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::register_proxy())]
+        pub fn register_proxy(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            proxy_origin: T::ProxyOrigin,
+            filter: T::CallFilter,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                !Proxies::<T>::contains_key(&proxy),
+                Error::<T>::ProxyAlreadyRegistered
+            );
+
+            let account = Self::proxy_account(&proxy);
+            Proxies::<T>::insert(
+                &proxy,
+                ProxyInfo {
+                    account: account.clone(),
+                    origin: proxy_origin,
+                    filter,
+                },
+            );
+            Self::deposit_event(Event::ProxyRegistered { proxy, account });
+            Ok(())
+        }
+
+        /// Update the origin and the filter profile of a registered proxy.
+        /// Its account remains unchanged.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::update_proxy())]
+        pub fn update_proxy(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            proxy_origin: T::ProxyOrigin,
+            filter: T::CallFilter,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+
+            Proxies::<T>::try_mutate(&proxy, |maybe_info| {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::ProxyNotFound)?;
+                info.origin = proxy_origin;
+                info.filter = filter;
+                Ok::<_, DispatchError>(())
+            })?;
+            Self::deposit_event(Event::ProxyUpdated { proxy });
+            Ok(())
+        }
+
+        /// Remove a registered proxy. Its account and limits are left untouched.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::remove_proxy())]
+        pub fn remove_proxy(origin: OriginFor<T>, proxy: ProxyName<T>) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                Proxies::<T>::take(&proxy).is_some(),
+                Error::<T>::ProxyNotFound
+            );
+
+            Self::deposit_event(Event::ProxyRemoved { proxy });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Weight and class of executing `call` through a proxy.
+        fn execute_call_weight(call: &<T as Config>::RuntimeCall) -> (Weight, DispatchClass) {
+            let di = call.get_dispatch_info();
+            let spends = T::SpendInspector::spends(call).len() as u64;
+            let call_names = Pallet::<T>::call_names(call).len() as u64;
+            (
+                T::WeightInfo::execute_call()
+                    .saturating_add(T::DbWeight::get().reads_writes(2, 1).saturating_mul(spends))
+                    .saturating_add(
+                        T::DbWeight::get()
+                            .reads_writes(2, 1)
+                            .saturating_mul(call_names.saturating_sub(1)),
+                    )
+                    .saturating_add(di.weight),
+                di.class,
+            )
+        }
+
+        /// Executes `call` on behalf of the account of `proxy`, within its limits.
+        fn do_execute_call(
+            origin: OriginFor<T>,
+            proxy: ProxyName<T>,
+            call: Box<<T as Config>::RuntimeCall>,
+        ) -> DispatchResult {
+            let info = Proxies::<T>::get(&proxy).ok_or(Error::<T>::ProxyNotFound)?;
+
             // Ensure origin is valid.
-            T::CollectiveProxy::ensure_origin(origin)?;
+            T::CollectiveProxy::ensure_origin(origin, &info.origin)?;
+
+            // Ensure the limits of the current period are respected before dispatching.
+            let period = Self::current_period();
+            let call_usage = Self::check_call_rate(&proxy, &call, period)?;
+            let spend_usage = Self::check_spends(&proxy, &call, period)?;
 
             // Account authentication is ensured by the `CollectiveProxy` origin check.
-            let mut origin: T::RuntimeOrigin =
-                frame_system::RawOrigin::Signed(T::ProxyAccountId::get()).into();
+            let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(info.account).into();
 
-            // Ensure custom filter is applied.
+            // Ensure the filter profile of the proxy is applied.
+            let filter = info.filter;
             origin.add_filter(move |c: &<T as frame_system::Config>::RuntimeCall| {
                 let c = <T as Config>::RuntimeCall::from_ref(c);
-                T::CallFilter::default().filter(c)
+                filter.filter(c)
             });
 
             // Dispatch the call.
             let e = call.dispatch(origin);
+            if e.is_ok() {
+                Self::note_usage(&proxy, period, call_usage, spend_usage);
+            }
             Self::deposit_event(Event::CollectiveProxyExecuted {
+                proxy,
                 result: e.map(|_| ()).map_err(|e| e.error),
             });
 
             Ok(())
         }
+
+        /// Account derived from the name of a proxy.
+        pub fn proxy_account(proxy: &ProxyName<T>) -> T::AccountId {
+            T::PalletId::get().into_sub_account_truncating(proxy)
+        }
+
+        /// Index of the current budget period.
+        pub fn current_period() -> BlockNumberFor<T> {
+            frame_system::Pallet::<T>::block_number()
+                .checked_div(&T::BudgetPeriod::get())
+                .unwrap_or_else(Zero::zero)
+        }
+
+        /// Call type of `call`.
+        pub fn call_name(call: &<T as Config>::RuntimeCall) -> RuntimeCallNameOf<T> {
+            let metadata = call.get_call_metadata();
+            (
+                BoundedVec::truncate_from(metadata.pallet_name.as_bytes().to_vec()),
+                BoundedVec::truncate_from(metadata.function_name.as_bytes().to_vec()),
+            )
+        }
+
+        /// Call types of `call` and of all the calls nested in it, with their number of calls.
+        pub fn call_names(call: &<T as Config>::RuntimeCall) -> Vec<(RuntimeCallNameOf<T>, u32)> {
+            let mut call_names: Vec<(RuntimeCallNameOf<T>, u32)> = Vec::new();
+            let mut pending = vec![call];
+            while let Some(call) = pending.pop() {
+                let call_name = Self::call_name(call);
+                match call_names.iter_mut().find(|(name, _)| *name == call_name) {
+                    Some((_, count)) => count.saturating_inc(),
+                    None => call_names.push((call_name, 1)),
+                }
+                pending.extend(T::NestedCalls::nested_calls(call));
+            }
+            call_names
+        }
+
+        /// Ensure the call types of `call` and its nested calls stay within the rate limits of
+        /// `proxy` in `period`. Returns the limited call types with their updated counts and limits.
+        fn check_call_rate(
+            proxy: &ProxyName<T>,
+            call: &<T as Config>::RuntimeCall,
+            period: BlockNumberFor<T>,
+        ) -> Result<Vec<(RuntimeCallNameOf<T>, u32, u32)>, DispatchError> {
+            let mut usage = Vec::new();
+            for (call_name, calls) in Self::call_names(call) {
+                let Some(limit) = CallRateLimits::<T>::get(proxy, &call_name) else {
+                    continue;
+                };
+
+                let count = match CallCounts::<T>::get(proxy, &call_name) {
+                    Some((counted_in, count)) if counted_in == period => count,
+                    _ => 0,
+                };
+                let count = count.saturating_add(calls);
+                ensure!(count <= limit, Error::<T>::CallRateLimitExceeded);
+                usage.push((call_name, count, limit));
+            }
+            Ok(usage)
+        }
+
+        /// Ensure the funds spent by `call` fit in the remaining budgets of `proxy` in `period`.
+        /// Returns the spent amounts and the updated usage of the limited assets.
+        fn check_spends(
+            proxy: &ProxyName<T>,
+            call: &<T as Config>::RuntimeCall,
+            period: BlockNumberFor<T>,
+        ) -> Result<Vec<(SpendAsset<T::AssetId>, Balance, Balance, Balance)>, DispatchError>
+        {
+            let mut spends: Vec<(SpendAsset<T::AssetId>, Balance)> = Vec::new();
+            for (asset, amount) in T::SpendInspector::spends(call) {
+                match spends.iter_mut().find(|(a, _)| *a == asset) {
+                    Some((_, total)) => total.saturating_accrue(amount),
+                    None => spends.push((asset, amount)),
+                }
+            }
+
+            let mut usage = Vec::new();
+            for (asset, amount) in spends {
+                let Some(limit) = SpendLimits::<T>::get(proxy, &asset) else {
+                    continue;
+                };
+                let spent = match SpentAmounts::<T>::get(proxy, &asset) {
+                    Some((spent_in, spent)) if spent_in == period => spent,
+                    _ => Zero::zero(),
+                };
+                let spent = spent.saturating_add(amount);
+                ensure!(spent <= limit, Error::<T>::SpendLimitExceeded);
+                usage.push((asset, amount, spent, limit));
+            }
+            Ok(usage)
+        }
+
+        /// Record the usage of the call rate and spend limits of `proxy` in `period`.
+        fn note_usage(
+            proxy: &ProxyName<T>,
+            period: BlockNumberFor<T>,
+            call_usage: Vec<(RuntimeCallNameOf<T>, u32, u32)>,
+            spend_usage: Vec<(SpendAsset<T::AssetId>, Balance, Balance, Balance)>,
+        ) {
+            for (call_name, count, limit) in call_usage {
+                CallCounts::<T>::insert(proxy, &call_name, (period, count));
+                Self::deposit_event(Event::CallRateUsed {
+                    proxy: proxy.clone(),
+                    call_name,
+                    remaining: limit.saturating_sub(count),
+                });
+            }
+
+            for (asset, amount, spent, limit) in spend_usage {
+                SpentAmounts::<T>::insert(proxy, &asset, (period, spent));
+                Self::deposit_event(Event::BudgetSpent {
+                    proxy: proxy.clone(),
+                    asset,
+                    amount,
+                    remaining: limit.saturating_sub(spent),
+                });
+            }
+        }
     }
 }
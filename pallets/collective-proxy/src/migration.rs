@@ -0,0 +1,68 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::UncheckedOnRuntimeUpgrade;
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Exports for versioned migration `type`s for this pallet.
+pub mod versioned {
+    use super::*;
+
+    /// Migration V0 to V1 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 0.
+    pub type V0ToV1<T, LegacyProxy> = frame_support::migrations::VersionedMigration<
+        0,
+        1,
+        v1::VersionMigrateV0ToV1<T, LegacyProxy>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+mod v1 {
+    use super::*;
+
+    /// Registers the single proxy of the runtime, prior to the introduction of named proxies,
+    /// keeping its account so the funds it holds remain accessible.
+    pub struct VersionMigrateV0ToV1<T, LegacyProxy>(PhantomData<(T, LegacyProxy)>);
+    impl<T: Config, LegacyProxy: Get<(ProxyName<T>, ProxyInfoOf<T>)>> UncheckedOnRuntimeUpgrade
+        for VersionMigrateV0ToV1<T, LegacyProxy>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let (name, info) = LegacyProxy::get();
+            Proxies::<T>::insert(name, info);
+
+            T::DbWeight::get().writes(1)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (name, info) = LegacyProxy::get();
+            ensure!(
+                Proxies::<T>::get(name) == Some(info),
+                "Legacy proxy must be registered."
+            );
+
+            Ok(())
+        }
+    }
+}
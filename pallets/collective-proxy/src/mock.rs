@@ -16,29 +16,43 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_collective_proxy};
+use crate::{
+    self as pallet_collective_proxy, NestedCalls, ProxyInfo, ProxyName, SpendAsset, SpendInspector,
+};
 
-use astar_primitives::{Balance, BlockNumber};
+use astar_primitives::{AssetId, Balance, BlockNumber};
 use frame_support::{
     construct_runtime, derive_impl, ord_parameter_types, parameter_types,
-    traits::{ConstU128, InstanceFilter},
+    traits::{
+        AsEnsureOriginWithArg, ConstU128, ConstU32, EnsureOrigin, EnsureOriginWithArg,
+        InstanceFilter,
+    },
     weights::Weight,
+    BoundedVec, PalletId,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_io::TestExternalities;
-use sp_runtime::BuildStorage;
+use sp_runtime::{BuildStorage, RuntimeDebug};
 
-use frame_system::EnsureSignedBy;
+use frame_system::{EnsureRoot, EnsureSigned, EnsureSignedBy};
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 type AccountId = u64;
 
 pub(crate) const COMMUNITY_ACCOUNT: AccountId = 1337;
 pub(crate) const PRIVILEGED_ACCOUNT: AccountId = 365;
+pub(crate) const OTHER_PRIVILEGED_ACCOUNT: AccountId = 366;
+pub(crate) const ASSET_ID: AssetId = 7;
+pub(crate) const BUDGET_PERIOD: BlockNumber = 10;
 
 construct_runtime!(
     pub struct Test {
         System: frame_system,
         Balances: pallet_balances,
+        Assets: pallet_assets,
+        Utility: pallet_utility,
         CollectiveProxy: pallet_collective_proxy,
     }
 );
@@ -62,32 +76,191 @@ impl pallet_balances::Config for Test {
     type AccountStore = System;
 }
 
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+    type Balance = Balance;
+    type AssetId = AssetId;
+    type AssetIdParameter = AssetId;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+    type ForceOrigin = EnsureRoot<AccountId>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type Freezer = ();
+}
+
+impl pallet_utility::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeCall = RuntimeCall;
+    type PalletsOrigin = OriginCaller;
+    type WeightInfo = ();
+}
+
 parameter_types! {
-    pub const ProxyAccountId: AccountId = COMMUNITY_ACCOUNT;
+    pub const CollectiveProxyPalletId: PalletId = PalletId(*b"py/clprx");
+    pub CommunityProxyName: ProxyName<Test> = community();
 }
 ord_parameter_types! {
     pub const CollectiveProxyManager: AccountId = PRIVILEGED_ACCOUNT;
+    pub const OtherCollectiveProxyManager: AccountId = OTHER_PRIVILEGED_ACCOUNT;
 }
 
-#[derive(Default)]
-pub struct MockCallFilter;
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum MockProxyOrigin {
+    Privileged,
+    OtherPrivileged,
+}
+
+pub struct MockCollectiveProxy;
+impl EnsureOriginWithArg<RuntimeOrigin, MockProxyOrigin> for MockCollectiveProxy {
+    type Success = AccountId;
+
+    fn try_origin(o: RuntimeOrigin, a: &MockProxyOrigin) -> Result<AccountId, RuntimeOrigin> {
+        match a {
+            MockProxyOrigin::Privileged => {
+                EnsureSignedBy::<CollectiveProxyManager, AccountId>::try_origin(o)
+            }
+            MockProxyOrigin::OtherPrivileged => {
+                EnsureSignedBy::<OtherCollectiveProxyManager, AccountId>::try_origin(o)
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(a: &MockProxyOrigin) -> Result<RuntimeOrigin, ()> {
+        match a {
+            MockProxyOrigin::Privileged => Ok(RuntimeOrigin::signed(PRIVILEGED_ACCOUNT)),
+            MockProxyOrigin::OtherPrivileged => Ok(RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT)),
+        }
+    }
+}
+
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    MaxEncodedLen,
+    RuntimeDebug,
+    TypeInfo,
+    Serialize,
+    Deserialize,
+)]
+pub enum MockCallFilter {
+    Transfers,
+    RemarkOnly,
+}
 impl InstanceFilter<RuntimeCall> for MockCallFilter {
     fn filter(&self, c: &RuntimeCall) -> bool {
-        matches!(
-            c,
-            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
-                | RuntimeCall::System(frame_system::Call::remark { .. })
-        )
+        match self {
+            MockCallFilter::Transfers => matches!(
+                c,
+                RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { .. })
+                    | RuntimeCall::Assets(pallet_assets::Call::transfer { .. })
+                    | RuntimeCall::System(frame_system::Call::remark { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
+            ),
+            MockCallFilter::RemarkOnly => {
+                matches!(c, RuntimeCall::System(frame_system::Call::remark { .. }))
+            }
+        }
+    }
+}
+
+pub struct MockSpendInspector;
+impl SpendInspector<RuntimeCall, AssetId> for MockSpendInspector {
+    fn spends(call: &RuntimeCall) -> Vec<(SpendAsset<AssetId>, Balance)> {
+        match call {
+            RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value, ..
+            })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) => {
+                vec![(SpendAsset::Native, *value)]
+            }
+            RuntimeCall::Assets(pallet_assets::Call::transfer { id, amount, .. }) => {
+                vec![(SpendAsset::Asset(*id), *amount)]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+pub struct MockNestedCalls;
+impl NestedCalls<RuntimeCall> for MockNestedCalls {
+    fn nested_calls(call: &RuntimeCall) -> Vec<&RuntimeCall> {
+        match call {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().collect()
+            }
+            _ => Vec::new(),
+        }
     }
 }
 
 impl pallet_collective_proxy::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureSignedBy<CollectiveProxyManager, AccountId>;
-    type ProxyAccountId = ProxyAccountId;
+    type ProxyOrigin = MockProxyOrigin;
+    type CollectiveProxy = MockCollectiveProxy;
+    type ManagerOrigin = EnsureRoot<AccountId>;
     type CallFilter = MockCallFilter;
+    type PalletId = CollectiveProxyPalletId;
+    type AssetId = AssetId;
+    type SpendInspector = MockSpendInspector;
+    type NestedCalls = MockNestedCalls;
+    type BudgetPeriod = ConstU32<BUDGET_PERIOD>;
+    type MaxNameLen = ConstU32<32>;
+    type MaxProxyNameLen = ConstU32<16>;
+    type LegacyProxy = CommunityProxyName;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = MockBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MockBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collective_proxy::BenchmarkHelper<MockProxyOrigin, MockCallFilter>
+    for MockBenchmarkHelper
+{
+    fn proxy_origin() -> MockProxyOrigin {
+        MockProxyOrigin::Privileged
+    }
+
+    fn call_filter() -> MockCallFilter {
+        MockCallFilter::RemarkOnly
+    }
+}
+
+/// Name of the community proxy, registered at genesis with the `COMMUNITY_ACCOUNT`.
+pub(crate) fn community() -> ProxyName<Test> {
+    BoundedVec::truncate_from(b"community".to_vec())
+}
+
+/// Community proxy, as registered at genesis.
+pub(crate) fn community_info() -> pallet_collective_proxy::ProxyInfoOf<Test> {
+    ProxyInfo {
+        account: COMMUNITY_ACCOUNT,
+        origin: MockProxyOrigin::Privileged,
+        filter: MockCallFilter::Transfers,
+    }
 }
 
 pub struct ExtBuilder;
@@ -108,10 +281,22 @@ impl ExtBuilder {
             .assimilate_storage(&mut storage)
             .ok();
 
+        pallet_assets::GenesisConfig::<Test> {
+            assets: vec![(ASSET_ID, PRIVILEGED_ACCOUNT, true, 1)],
+            metadata: vec![],
+            accounts: vec![(ASSET_ID, COMMUNITY_ACCOUNT, 1000)],
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
+        pallet_collective_proxy::GenesisConfig::<Test> {
+            proxies: vec![(community(), community_info())],
+        }
+        .assimilate_storage(&mut storage)
+        .ok();
+
         let mut ext = TestExternalities::from(storage);
-        ext.execute_with(|| {
-            System::set_block_number(1);
-        });
+        ext.execute_with(|| System::set_block_number(1));
 
         ext
     }
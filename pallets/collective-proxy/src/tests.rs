@@ -16,17 +16,48 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Event};
+use crate::{
+    mock::*, CallCounts, Error, Event, Proxies, ProxyInfo, ProxyName, SpendAsset, SpendLimits,
+    SpentAmounts,
+};
 
-use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use frame_support::{
+    assert_noop, assert_ok,
+    error::BadOrigin,
+    traits::{ConstU32, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+    BoundedVec,
+};
+use pallet_assets::Call as AssetsCall;
 use pallet_balances::Call as BalancesCall;
+use pallet_utility::Call as UtilityCall;
+use parity_scale_codec::Encode;
+use sp_runtime::BuildStorage;
+
+fn native_transfer(value: u128) -> Box<RuntimeCall> {
+    Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+        dest: 2,
+        value,
+    }))
+}
+
+fn grants() -> ProxyName<Test> {
+    BoundedVec::truncate_from(b"grants".to_vec())
+}
+
+fn remark_name() -> (BoundedVec<u8, ConstU32<32>>, BoundedVec<u8, ConstU32<32>>) {
+    (
+        BoundedVec::truncate_from(b"System".to_vec()),
+        BoundedVec::truncate_from(b"remark".to_vec()),
+    )
+}
 
 #[test]
 fn execute_call_fails_for_invalid_origin() {
     ExtBuilder::build().execute_with(|| {
         assert_noop!(
-            CollectiveProxy::execute_call(
+            CollectiveProxy::execute_proxy_call(
                 RuntimeOrigin::signed(1),
+                community(),
                 Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
                     dest: 2,
                     value: 10
@@ -43,8 +74,9 @@ fn execute_call_filters_not_allowed_call() {
         let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
 
         // Call is filtered, but `execute_call` succeeds.
-        assert_ok!(CollectiveProxy::execute_call(
+        assert_ok!(CollectiveProxy::execute_proxy_call(
             RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
             Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
                 dest: 2,
                 value: 10
@@ -54,6 +86,7 @@ fn execute_call_filters_not_allowed_call() {
         // Ensure event with error is emitted.
         System::assert_last_event(
             Event::<Test>::CollectiveProxyExecuted {
+                proxy: community(),
                 result: Err(frame_system::Error::<Test>::CallFiltered.into()),
             }
             .into(),
@@ -74,8 +107,9 @@ fn execute_call_succeeds() {
         let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
         let transfer_value = init_balance / 3;
 
-        assert_ok!(CollectiveProxy::execute_call(
+        assert_ok!(CollectiveProxy::execute_proxy_call(
             RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
             Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
                 dest: 2,
                 value: transfer_value
@@ -84,6 +118,7 @@ fn execute_call_succeeds() {
 
         System::assert_last_event(
             Event::<Test>::CollectiveProxyExecuted {
+                proxy: community(),
                 result: Ok(().into()),
             }
             .into(),
@@ -94,3 +129,690 @@ fn execute_call_succeeds() {
         assert_eq!(init_balance, after_balance + transfer_value,);
     });
 }
+
+#[test]
+fn execute_call_dispatches_through_legacy_proxy() {
+    ExtBuilder::build().execute_with(|| {
+        let init_balance = Balances::free_balance(COMMUNITY_ACCOUNT);
+
+        assert_noop!(
+            CollectiveProxy::execute_call(RuntimeOrigin::signed(1), native_transfer(10)),
+            BadOrigin
+        );
+
+        assert_ok!(CollectiveProxy::execute_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            native_transfer(10)
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                proxy: community(),
+                result: Ok(().into()),
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(COMMUNITY_ACCOUNT), init_balance - 10);
+
+        // Calls encoded before the named proxies still decode to `execute_call`.
+        let call = RuntimeCall::CollectiveProxy(crate::Call::execute_call {
+            call: native_transfer(10),
+        });
+        assert_eq!(call.encode()[1], 0);
+
+        // Fails once the legacy proxy is removed.
+        assert_ok!(CollectiveProxy::remove_proxy(
+            RuntimeOrigin::root(),
+            community()
+        ));
+        assert_noop!(
+            CollectiveProxy::execute_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                native_transfer(10)
+            ),
+            Error::<Test>::ProxyNotFound
+        );
+    });
+}
+
+#[test]
+fn set_limits_requires_manager_origin() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollectiveProxy::set_spend_limit(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                SpendAsset::Native,
+                Some(100)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            CollectiveProxy::set_call_rate_limit(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                remark_name(),
+                Some(1)
+            ),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn native_spend_limit_is_enforced() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            community(),
+            SpendAsset::Native,
+            Some(100)
+        ));
+        System::assert_last_event(
+            Event::<Test>::SpendLimitSet {
+                proxy: community(),
+                asset: SpendAsset::Native,
+                limit: Some(100),
+            }
+            .into(),
+        );
+
+        // Spend part of the budget, remaining budget is reported.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(60)
+        ));
+        System::assert_has_event(
+            Event::<Test>::BudgetSpent {
+                proxy: community(),
+                asset: SpendAsset::Native,
+                amount: 60,
+                remaining: 40,
+            }
+            .into(),
+        );
+
+        // Exceeding the remaining budget fails before dispatch.
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                native_transfer(41)
+            ),
+            Error::<Test>::SpendLimitExceeded
+        );
+
+        // Spending the exact remaining budget is fine.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(40)
+        ));
+        System::assert_has_event(
+            Event::<Test>::BudgetSpent {
+                proxy: community(),
+                asset: SpendAsset::Native,
+                amount: 40,
+                remaining: 0,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(COMMUNITY_ACCOUNT), 900);
+    });
+}
+
+#[test]
+fn spend_budget_is_reset_each_period() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            community(),
+            SpendAsset::Native,
+            Some(100)
+        ));
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(100)
+        ));
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                native_transfer(1)
+            ),
+            Error::<Test>::SpendLimitExceeded
+        );
+
+        // Last block of the period, budget is still used up.
+        System::set_block_number(BUDGET_PERIOD - 1);
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                native_transfer(1)
+            ),
+            Error::<Test>::SpendLimitExceeded
+        );
+
+        // New period, the whole budget is available again.
+        System::set_block_number(BUDGET_PERIOD);
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(100)
+        ));
+        assert_eq!(
+            SpentAmounts::<Test>::get(community(), SpendAsset::Native),
+            Some((1, 100))
+        );
+    });
+}
+
+#[test]
+fn asset_spend_limit_is_tracked_separately() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            community(),
+            SpendAsset::Asset(ASSET_ID),
+            Some(50)
+        ));
+
+        let asset_transfer = |amount| {
+            Box::new(RuntimeCall::Assets(AssetsCall::transfer {
+                id: ASSET_ID,
+                target: 2,
+                amount,
+            }))
+        };
+
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            asset_transfer(50)
+        ));
+        System::assert_has_event(
+            Event::<Test>::BudgetSpent {
+                proxy: community(),
+                asset: SpendAsset::Asset(ASSET_ID),
+                amount: 50,
+                remaining: 0,
+            }
+            .into(),
+        );
+        assert_eq!(Assets::balance(ASSET_ID, COMMUNITY_ACCOUNT), 950);
+
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                asset_transfer(1)
+            ),
+            Error::<Test>::SpendLimitExceeded
+        );
+
+        // Native currency isn't limited.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(500)
+        ));
+        assert!(SpentAmounts::<Test>::get(community(), SpendAsset::Native).is_none());
+
+        // Removing the limit clears the usage.
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            community(),
+            SpendAsset::Asset(ASSET_ID),
+            None
+        ));
+        assert!(SpentAmounts::<Test>::get(community(), SpendAsset::Asset(ASSET_ID)).is_none());
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            asset_transfer(100)
+        ));
+    });
+}
+
+#[test]
+fn failed_call_does_not_use_budget() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            community(),
+            SpendAsset::Native,
+            Some(100)
+        ));
+
+        // Filtered call within the budget is dispatched, but fails.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            Box::new(RuntimeCall::Balances(BalancesCall::transfer_keep_alive {
+                dest: 2,
+                value: 100
+            }))
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                proxy: community(),
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+        assert!(SpentAmounts::<Test>::get(community(), SpendAsset::Native).is_none());
+
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(100)
+        ));
+    });
+}
+
+#[test]
+fn call_rate_limit_is_enforced() {
+    ExtBuilder::build().execute_with(|| {
+        let remark = || {
+            Box::new(RuntimeCall::System(frame_system::Call::remark {
+                remark: vec![],
+            }))
+        };
+
+        assert_ok!(CollectiveProxy::set_call_rate_limit(
+            RuntimeOrigin::root(),
+            community(),
+            remark_name(),
+            Some(2)
+        ));
+        System::assert_last_event(
+            Event::<Test>::CallRateLimitSet {
+                proxy: community(),
+                call_name: remark_name(),
+                limit: Some(2),
+            }
+            .into(),
+        );
+
+        for remaining in [1, 0] {
+            assert_ok!(CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                remark()
+            ));
+            System::assert_has_event(
+                Event::<Test>::CallRateUsed {
+                    proxy: community(),
+                    call_name: remark_name(),
+                    remaining,
+                }
+                .into(),
+            );
+        }
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                remark()
+            ),
+            Error::<Test>::CallRateLimitExceeded
+        );
+
+        // Other call types aren't limited.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(10)
+        ));
+
+        // Count is reset in the next period.
+        System::set_block_number(BUDGET_PERIOD);
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            remark()
+        ));
+        assert_eq!(
+            CallCounts::<Test>::get(community(), remark_name()),
+            Some((1, 1))
+        );
+
+        // Removing the limit clears the count.
+        assert_ok!(CollectiveProxy::set_call_rate_limit(
+            RuntimeOrigin::root(),
+            community(),
+            remark_name(),
+            None
+        ));
+        assert!(CallCounts::<Test>::get(community(), remark_name()).is_none());
+    });
+}
+
+#[test]
+fn call_rate_limit_counts_batched_calls() {
+    ExtBuilder::build().execute_with(|| {
+        let remark = || RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+        assert_ok!(CollectiveProxy::set_call_rate_limit(
+            RuntimeOrigin::root(),
+            community(),
+            remark_name(),
+            Some(3)
+        ));
+
+        // Each batched call counts, also in nested batches.
+        let batch = |calls| Box::new(RuntimeCall::Utility(UtilityCall::batch_all { calls }));
+        let nested = RuntimeCall::Utility(UtilityCall::batch {
+            calls: vec![remark(), remark()],
+        });
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                batch(vec![remark(), remark(), nested.clone()])
+            ),
+            Error::<Test>::CallRateLimitExceeded
+        );
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            batch(vec![nested])
+        ));
+        assert_eq!(
+            CallCounts::<Test>::get(community(), remark_name()),
+            Some((0, 2))
+        );
+
+        // The total is enforced against the remaining calls.
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                community(),
+                batch(vec![remark(), remark()])
+            ),
+            Error::<Test>::CallRateLimitExceeded
+        );
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            batch(vec![remark()])
+        ));
+        assert_eq!(
+            CallCounts::<Test>::get(community(), remark_name()),
+            Some((0, 3))
+        );
+    });
+}
+
+#[test]
+fn execute_call_fails_for_unknown_proxy() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                grants(),
+                native_transfer(10)
+            ),
+            Error::<Test>::ProxyNotFound
+        );
+    });
+}
+
+#[test]
+fn register_update_and_remove_proxy_works() {
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(
+            CollectiveProxy::register_proxy(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                grants(),
+                MockProxyOrigin::OtherPrivileged,
+                MockCallFilter::RemarkOnly
+            ),
+            BadOrigin
+        );
+
+        // Register a new proxy, with an account derived from its name.
+        assert_ok!(CollectiveProxy::register_proxy(
+            RuntimeOrigin::root(),
+            grants(),
+            MockProxyOrigin::OtherPrivileged,
+            MockCallFilter::RemarkOnly
+        ));
+        let account = CollectiveProxy::proxy_account(&grants());
+        System::assert_last_event(
+            Event::<Test>::ProxyRegistered {
+                proxy: grants(),
+                account,
+            }
+            .into(),
+        );
+        assert_ne!(account, COMMUNITY_ACCOUNT);
+        assert_eq!(
+            Proxies::<Test>::get(grants()),
+            Some(ProxyInfo {
+                account,
+                origin: MockProxyOrigin::OtherPrivileged,
+                filter: MockCallFilter::RemarkOnly,
+            })
+        );
+        assert_noop!(
+            CollectiveProxy::register_proxy(
+                RuntimeOrigin::root(),
+                community(),
+                MockProxyOrigin::OtherPrivileged,
+                MockCallFilter::RemarkOnly
+            ),
+            Error::<Test>::ProxyAlreadyRegistered
+        );
+
+        // Update keeps the account of the existing proxy.
+        assert_ok!(CollectiveProxy::update_proxy(
+            RuntimeOrigin::root(),
+            community(),
+            MockProxyOrigin::OtherPrivileged,
+            MockCallFilter::RemarkOnly
+        ));
+        System::assert_last_event(Event::<Test>::ProxyUpdated { proxy: community() }.into());
+        assert_eq!(
+            Proxies::<Test>::get(community()),
+            Some(ProxyInfo {
+                account: COMMUNITY_ACCOUNT,
+                origin: MockProxyOrigin::OtherPrivileged,
+                filter: MockCallFilter::RemarkOnly,
+            })
+        );
+
+        // Remove the proxy.
+        assert_ok!(CollectiveProxy::remove_proxy(
+            RuntimeOrigin::root(),
+            grants()
+        ));
+        System::assert_last_event(Event::<Test>::ProxyRemoved { proxy: grants() }.into());
+        assert!(!Proxies::<Test>::contains_key(grants()));
+
+        assert_noop!(
+            CollectiveProxy::update_proxy(
+                RuntimeOrigin::root(),
+                grants(),
+                MockProxyOrigin::Privileged,
+                MockCallFilter::Transfers
+            ),
+            Error::<Test>::ProxyNotFound
+        );
+        assert_noop!(
+            CollectiveProxy::remove_proxy(RuntimeOrigin::root(), grants()),
+            Error::<Test>::ProxyNotFound
+        );
+    });
+}
+
+#[test]
+fn proxies_use_their_own_origin_filter_and_account() {
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(CollectiveProxy::register_proxy(
+            RuntimeOrigin::root(),
+            grants(),
+            MockProxyOrigin::OtherPrivileged,
+            MockCallFilter::RemarkOnly
+        ));
+        let account = CollectiveProxy::proxy_account(&grants());
+        Balances::make_free_balance_be(&account, 1000);
+
+        // Each proxy only accepts its own origin.
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+                grants(),
+                native_transfer(10)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT),
+                community(),
+                native_transfer(10)
+            ),
+            BadOrigin
+        );
+
+        // Transfers are filtered for the new proxy, but remarks are allowed.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT),
+            grants(),
+            native_transfer(10)
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                proxy: grants(),
+                result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+            }
+            .into(),
+        );
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT),
+            grants(),
+            Box::new(RuntimeCall::System(frame_system::Call::remark {
+                remark: vec![]
+            }))
+        ));
+        System::assert_last_event(
+            Event::<Test>::CollectiveProxyExecuted {
+                proxy: grants(),
+                result: Ok(()),
+            }
+            .into(),
+        );
+        assert_eq!(Balances::free_balance(account), 1000);
+
+        // Calls of the community proxy are dispatched from the community account.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(10)
+        ));
+        assert_eq!(Balances::free_balance(COMMUNITY_ACCOUNT), 990);
+    });
+}
+
+#[test]
+fn limits_are_tracked_per_proxy() {
+    ExtBuilder::build().execute_with(|| {
+        // Limits can be set before the proxy is registered.
+        assert_ok!(CollectiveProxy::set_spend_limit(
+            RuntimeOrigin::root(),
+            grants(),
+            SpendAsset::Native,
+            Some(100)
+        ));
+        assert_ok!(CollectiveProxy::register_proxy(
+            RuntimeOrigin::root(),
+            grants(),
+            MockProxyOrigin::OtherPrivileged,
+            MockCallFilter::Transfers
+        ));
+        Balances::make_free_balance_be(&CollectiveProxy::proxy_account(&grants()), 1000);
+
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT),
+            grants(),
+            native_transfer(100)
+        ));
+        assert_noop!(
+            CollectiveProxy::execute_proxy_call(
+                RuntimeOrigin::signed(OTHER_PRIVILEGED_ACCOUNT),
+                grants(),
+                native_transfer(1)
+            ),
+            Error::<Test>::SpendLimitExceeded
+        );
+
+        // The community proxy has no limit.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(500)
+        ));
+        assert!(SpentAmounts::<Test>::get(community(), SpendAsset::Native).is_none());
+
+        // Limits are kept when the proxy is removed.
+        assert_ok!(CollectiveProxy::remove_proxy(
+            RuntimeOrigin::root(),
+            grants()
+        ));
+        assert_eq!(
+            SpendLimits::<Test>::get(grants(), SpendAsset::Native),
+            Some(100)
+        );
+    });
+}
+
+#[test]
+fn genesis_registers_proxies() {
+    ExtBuilder::build().execute_with(|| {
+        assert_eq!(Proxies::<Test>::get(community()), Some(community_info()));
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(10)
+        ));
+    });
+}
+
+#[test]
+#[should_panic(expected = "duplicate proxies in genesis.")]
+fn genesis_rejects_duplicate_proxies() {
+    let _ = crate::GenesisConfig::<Test> {
+        proxies: vec![
+            (community(), community_info()),
+            (community(), community_info()),
+        ],
+    }
+    .build_storage();
+}
+
+#[test]
+fn migration_registers_legacy_proxy() {
+    ExtBuilder::build().execute_with(|| {
+        StorageVersion::new(0).put::<CollectiveProxy>();
+        Proxies::<Test>::remove(community());
+
+        frame_support::parameter_types! {
+            pub LegacyProxy: (ProxyName<Test>, crate::ProxyInfoOf<Test>) =
+                (community(), community_info());
+        }
+        crate::migration::versioned::V0ToV1::<Test, LegacyProxy>::on_runtime_upgrade();
+
+        assert_eq!(Proxies::<Test>::get(community()), Some(community_info()));
+        assert_eq!(CollectiveProxy::on_chain_storage_version(), 1);
+
+        // Calls are still dispatched from the legacy account.
+        assert_ok!(CollectiveProxy::execute_proxy_call(
+            RuntimeOrigin::signed(PRIVILEGED_ACCOUNT),
+            community(),
+            native_transfer(10)
+        ));
+        assert_eq!(Balances::free_balance(COMMUNITY_ACCOUNT), 990);
+    });
+}
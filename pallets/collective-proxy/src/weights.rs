@@ -50,27 +50,162 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_collective_proxy.
 pub trait WeightInfo {
 	fn execute_call() -> Weight;
+	fn set_spend_limit() -> Weight;
+	fn set_call_rate_limit() -> Weight;
+	fn register_proxy() -> Weight;
+	fn update_proxy() -> Weight;
+	fn remove_proxy() -> Weight;
 }
 
 /// Weights for pallet_collective_proxy using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallRateLimits` (r:1 w:0)
+	/// Proof: `CollectiveProxy::CallRateLimits` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallCounts` (r:1 w:1)
+	/// Proof: `CollectiveProxy::CallCounts` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
 	fn execute_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `4028`
+		// Minimum execution time: 19_877_000 picoseconds.
+		Weight::from_parts(20_412_000, 4028)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::SpendLimits` (r:0 w:1)
+	/// Proof: `CollectiveProxy::SpendLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::SpentAmounts` (r:0 w:1)
+	/// Proof: `CollectiveProxy::SpentAmounts` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	fn set_spend_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_126_000 picoseconds.
+		Weight::from_parts(8_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollectiveProxy::CallRateLimits` (r:0 w:1)
+	/// Proof: `CollectiveProxy::CallRateLimits` (`max_values`: None, `max_size`: Some(542), added: 3017, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallCounts` (r:0 w:1)
+	/// Proof: `CollectiveProxy::CallCounts` (`max_values`: None, `max_size`: Some(546), added: 3021, mode: `MaxEncodedLen`)
+	fn set_call_rate_limit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 7_732_000 picoseconds.
-		Weight::from_parts(7_950_000, 0)
+		// Minimum execution time: 8_877_000 picoseconds.
+		Weight::from_parts(9_135_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn register_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3548`
+		// Minimum execution time: 11_903_000 picoseconds.
+		Weight::from_parts(12_268_000, 3548)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn update_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3548`
+		// Minimum execution time: 12_155_000 picoseconds.
+		Weight::from_parts(12_540_000, 3548)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn remove_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3548`
+		// Minimum execution time: 11_618_000 picoseconds.
+		Weight::from_parts(11_984_000, 3548)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:0)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallRateLimits` (r:1 w:0)
+	/// Proof: `CollectiveProxy::CallRateLimits` (`max_values`: None, `max_size`: Some(559), added: 3034, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallCounts` (r:1 w:1)
+	/// Proof: `CollectiveProxy::CallCounts` (`max_values`: None, `max_size`: Some(563), added: 3038, mode: `MaxEncodedLen`)
 	fn execute_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `214`
+		//  Estimated: `4028`
+		// Minimum execution time: 19_877_000 picoseconds.
+		Weight::from_parts(20_412_000, 4028)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::SpendLimits` (r:0 w:1)
+	/// Proof: `CollectiveProxy::SpendLimits` (`max_values`: None, `max_size`: Some(50), added: 2525, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::SpentAmounts` (r:0 w:1)
+	/// Proof: `CollectiveProxy::SpentAmounts` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	fn set_spend_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 8_126_000 picoseconds.
+		Weight::from_parts(8_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollectiveProxy::CallRateLimits` (r:0 w:1)
+	/// Proof: `CollectiveProxy::CallRateLimits` (`max_values`: None, `max_size`: Some(542), added: 3017, mode: `MaxEncodedLen`)
+	/// Storage: `CollectiveProxy::CallCounts` (r:0 w:1)
+	/// Proof: `CollectiveProxy::CallCounts` (`max_values`: None, `max_size`: Some(546), added: 3021, mode: `MaxEncodedLen`)
+	fn set_call_rate_limit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
-		// Minimum execution time: 7_732_000 picoseconds.
-		Weight::from_parts(7_950_000, 0)
+		// Minimum execution time: 8_877_000 picoseconds.
+		Weight::from_parts(9_135_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn register_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3548`
+		// Minimum execution time: 11_903_000 picoseconds.
+		Weight::from_parts(12_268_000, 3548)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn update_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3548`
+		// Minimum execution time: 12_155_000 picoseconds.
+		Weight::from_parts(12_540_000, 3548)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `CollectiveProxy::Proxies` (r:1 w:1)
+	/// Proof: `CollectiveProxy::Proxies` (`max_values`: None, `max_size`: Some(83), added: 2558, mode: `MaxEncodedLen`)
+	fn remove_proxy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `163`
+		//  Estimated: `3548`
+		// Minimum execution time: 11_618_000 picoseconds.
+		Weight::from_parts(11_984_000, 3548)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 }
@@ -58,6 +58,7 @@ use astar_primitives::{
         PeriodNumber, Rank, RankedTier, SmartContractHandle, StakingRewardHandler, TierId,
         TierSlots as TierSlotFunc, STANDARD_TIER_SLOTS_ARGS,
     },
+    evm::OnAccountUnification,
     oracle::PriceProvider,
     Balance, BlockNumber,
 };
@@ -414,6 +415,8 @@ pub mod pallet {
         InvalidTierParams,
         /// Same contract specified as source and destination.
         SameContracts,
+        /// Account ledger cannot be moved during account unification, since the target account already has a non-empty ledger.
+        UnificationTargetHasLedger,
     }
 
     /// General information about dApp staking protocol state.
@@ -2870,4 +2873,42 @@ pub mod pallet {
             Self::set_maintenance_mode(false);
         }
     }
+
+    /// Implementation of the `OnAccountUnification` trait for the `DappStaking` pallet.
+    /// Moves the ledger, together with all of the staker info entries, from the default account
+    /// to the unified account. The dApp staking freeze is moved along with the ledger.
+    ///
+    /// Ledgers aren't merged, so the unification is refused in case the unified account already has a non-empty ledger.
+    impl<T: Config> OnAccountUnification<T::AccountId> for Pallet<T> {
+        fn on_unification(default: &T::AccountId, unified: &T::AccountId) -> DispatchResult {
+            let ledger = Ledger::<T>::get(default);
+            if ledger.is_empty() {
+                return Ok(());
+            }
+            ensure!(
+                Ledger::<T>::get(unified).is_empty(),
+                Error::<T>::UnificationTargetHasLedger
+            );
+
+            let staker_entries: Vec<_> = StakerInfo::<T>::drain_prefix(default).collect();
+            for (smart_contract, staking_info) in staker_entries {
+                StakerInfo::<T>::insert(unified, smart_contract, staking_info);
+            }
+
+            Self::update_ledger(default, AccountLedgerFor::<T>::default())?;
+            Self::update_ledger(unified, ledger)?;
+
+            Ok(())
+        }
+
+        fn weight() -> Weight {
+            let max_staked_contracts = u64::from(T::MaxNumberOfStakedContracts::get());
+
+            // Ledger of both accounts, staker info entries & freeze of both accounts
+            T::DbWeight::get().reads_writes(
+                max_staked_contracts.saturating_add(6),
+                max_staked_contracts.saturating_mul(2).saturating_add(6),
+            )
+        }
+    }
 }
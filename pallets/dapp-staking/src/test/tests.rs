@@ -20,17 +20,17 @@ use crate::test::{mock::*, testing_utils::*};
 use crate::{
     pallet::Config, ActiveProtocolState, BonusStatusWrapperFor, ContractStake, CurrentEraInfo,
     DAppId, DAppTierRewardsFor, DAppTiers, EraReward, EraRewards, Error, Event, ForcingType,
-    GenesisConfig, IntegratedDApps, Ledger, NextDAppId, Perbill, PeriodNumber, Permill, Safeguard,
-    SingularStakingInfo, StakeAmount, StakerInfo, StaticTierParams, Subperiod, TierConfig,
-    TierParameters, TierThreshold,
+    FreezeReason, GenesisConfig, IntegratedDApps, Ledger, NextDAppId, Perbill, PeriodNumber,
+    Permill, Safeguard, SingularStakingInfo, StakeAmount, StakerInfo, StaticTierParams, Subperiod,
+    TierConfig, TierParameters, TierThreshold,
 };
 
 use frame_support::{
     assert_noop, assert_ok, assert_storage_noop,
     error::BadOrigin,
     traits::{
-        fungible::Unbalanced as FunUnbalanced, Currency, Get, OnFinalize, OnInitialize,
-        ReservableCurrency, SafeModeNotify,
+        fungible::{InspectFreeze, Unbalanced as FunUnbalanced},
+        Currency, Get, OnFinalize, OnInitialize, ReservableCurrency, SafeModeNotify,
     },
     BoundedVec,
 };
@@ -44,6 +44,7 @@ use astar_primitives::{
         CycleConfiguration, EraNumber, RankedTier, SmartContractHandle, StakingRewardHandler,
         TierSlots,
     },
+    evm::OnAccountUnification,
     Balance, BlockNumber,
 };
 
@@ -4278,3 +4279,62 @@ fn active_update_bonus_status() {
             );
         })
 }
+
+#[test]
+fn on_account_unification_moves_ledger_and_stake() {
+    ExtBuilder::default().build_and_execute(|| {
+        // Register smart contract, lock & stake some amount
+        let smart_contract = MockSmartContract::wasm(1 as AccountId);
+        assert_register(1, &smart_contract);
+
+        let (default_account, unified_account) = (2, 3);
+        let (lock_amount, stake_amount) = (300, 100);
+        assert_lock(default_account, lock_amount);
+        assert_stake(default_account, &smart_contract, stake_amount);
+
+        let ledger = Ledger::<Test>::get(&default_account);
+        let staker_info = StakerInfo::<Test>::get(&default_account, &smart_contract)
+            .expect("Entry must exist since stake was made.");
+
+        // Unify the accounts & verify everything has been moved
+        assert_ok!(DappStaking::on_unification(
+            &default_account,
+            &unified_account
+        ));
+
+        assert!(!Ledger::<Test>::contains_key(&default_account));
+        assert!(StakerInfo::<Test>::get(&default_account, &smart_contract).is_none());
+        assert!(
+            Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &default_account).is_zero()
+        );
+
+        assert_eq!(Ledger::<Test>::get(&unified_account), ledger);
+        assert_eq!(
+            StakerInfo::<Test>::get(&unified_account, &smart_contract),
+            Some(staker_info)
+        );
+        assert_eq!(
+            Balances::balance_frozen(&FreezeReason::DAppStaking.into(), &unified_account),
+            lock_amount
+        );
+
+        // Account without a ledger can be unified, nothing happens
+        assert_storage_noop!({
+            let _ = DappStaking::on_unification(&default_account, &unified_account);
+        });
+    })
+}
+
+#[test]
+fn on_account_unification_fails_if_unified_account_has_ledger() {
+    ExtBuilder::default().build_and_execute(|| {
+        let (default_account, unified_account) = (2, 3);
+        assert_lock(default_account, 300);
+        assert_lock(unified_account, 100);
+
+        assert_noop!(
+            DappStaking::on_unification(&default_account, &unified_account),
+            Error::<Test>::UnificationTargetHasLedger
+        );
+    })
+}
@@ -0,0 +1,39 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::oracle::{CurrencyId, Price};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Price Aggregator Api.
+    ///
+    /// Used to provide information otherwise not available via RPC.
+    pub trait PriceAggregatorApi {
+
+        /// All currencies tracked by the price aggregator, native currency first.
+        fn tracked_currencies() -> Vec<CurrencyId>;
+
+        /// Moving average price of the given currency.
+        ///
+        /// Returns `None` if the currency isn't tracked.
+        fn average_price(currency_id: CurrencyId) -> Option<Price>;
+    }
+}
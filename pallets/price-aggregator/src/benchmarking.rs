@@ -21,6 +21,35 @@
 use super::*;
 use frame_benchmarking::v2::*;
 
+/// Returns the values which fill up the entire circular buffer.
+fn full_buffer_values<T: Config>() -> BoundedVec<CurrencyAmount, T::CircularBufferLength> {
+    let buffer_length = <T as Config>::CircularBufferLength::get();
+    let mut values = BoundedVec::<CurrencyAmount, T::CircularBufferLength>::default();
+    for x in 1..=buffer_length {
+        values
+            .try_push(CurrencyAmount::from_rational(x as u128 + 7, 10))
+            .expect("Must succeed since we are iterating to the limit");
+    }
+
+    values
+}
+
+/// Fills up the tracked currencies, leaving space for just one more.
+///
+/// Returns the currency Id which isn't tracked yet.
+fn prepare_tracked_currencies<T: Config>() -> CurrencyId {
+    let max_currencies = <T as Config>::MaxTrackedCurrencies::get();
+    let currencies: Vec<_> = (1..max_currencies)
+        .map(|x| CurrencyId::Asset(x.into()))
+        .collect();
+    TrackedCurrencies::<T>::put(
+        BoundedVec::try_from(currencies)
+            .expect("Must succeed since we are iterating below the limit"),
+    );
+
+    CurrencyId::Asset(max_currencies.into())
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -36,15 +65,16 @@ mod benchmarks {
                 .try_push(value)
                 .expect("Must succeed since we are iterating to the limit");
         }
-        CurrentBlockValues::<T>::put(result);
+        let currency_id = T::NativeCurrencyId::get();
+        CurrentBlockValues::<T>::insert(currency_id, result);
 
         #[block]
         {
-            Pallet::<T>::process_block_aggregated_values();
+            Pallet::<T>::process_block_aggregated_values(currency_id);
         }
 
         assert!(
-            CurrentBlockValues::<T>::get().is_empty(),
+            CurrentBlockValues::<T>::get(currency_id).is_empty(),
             "Should have been cleaned up."
         );
     }
@@ -52,7 +82,8 @@ mod benchmarks {
     #[benchmark]
     fn process_intermediate_aggregated_values() {
         // 1. Fill up the current aggregator and make it trigger on the current block end
-        IntermediateValueAggregator::<T>::mutate(|a| {
+        let currency_id = T::NativeCurrencyId::get();
+        IntermediateValueAggregator::<T>::mutate(currency_id, |a| {
             a.limit_block = frame_system::Pallet::<T>::block_number().saturated_into();
 
             a.total = CurrencyAmount::from_rational(1234, 10);
@@ -61,27 +92,87 @@ mod benchmarks {
 
         // 2. Fill up the circular buffer with some values
         let buffer_length = <T as Config>::CircularBufferLength::get();
-        ValuesCircularBuffer::<T>::mutate(|b| {
+        ValuesCircularBuffer::<T>::mutate(currency_id, |b| {
             for x in 1..=buffer_length {
                 b.add(CurrencyAmount::from_rational(x as u128 + 3, 10));
             }
         });
         assert_eq!(
-            ValuesCircularBuffer::<T>::get().buffer.len(),
+            ValuesCircularBuffer::<T>::get(currency_id).buffer.len(),
             buffer_length as usize,
             "Sanity check."
         );
 
         // 3. Prepare local variables
-        let buffer_snapshot = ValuesCircularBuffer::<T>::get();
+        let buffer_snapshot = ValuesCircularBuffer::<T>::get(currency_id);
         let current_block = frame_system::Pallet::<T>::block_number();
 
         #[block]
         {
-            Pallet::<T>::process_intermediate_aggregated_values(current_block);
+            Pallet::<T>::process_intermediate_aggregated_values(currency_id, current_block);
         }
 
-        assert!(ValuesCircularBuffer::<T>::get() != buffer_snapshot);
+        assert!(ValuesCircularBuffer::<T>::get(currency_id) != buffer_snapshot);
+    }
+
+    #[benchmark]
+    fn reset_circular_buffer() -> Result<(), BenchmarkError> {
+        let currency_id = T::NativeCurrencyId::get();
+        let values = full_buffer_values::<T>();
+
+        let origin =
+            T::ResetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, currency_id, values.clone());
+
+        assert_eq!(ValuesCircularBuffer::<T>::get(currency_id).buffer, values);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn register_currency() -> Result<(), BenchmarkError> {
+        // Fill up tracked currencies, leaving space for just one more
+        let currency_id = prepare_tracked_currencies::<T>();
+        let values = full_buffer_values::<T>();
+
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, currency_id, values.clone());
+
+        assert!(Pallet::<T>::is_tracked(&currency_id));
+        assert_eq!(ValuesCircularBuffer::<T>::get(currency_id).buffer, values);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unregister_currency(
+        f: Linear<0, { T::MaxDataFeeders::get() }>,
+    ) -> Result<(), BenchmarkError> {
+        // Fill up tracked currencies, and unregister the last one
+        let currency_id = prepare_tracked_currencies::<T>();
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Pallet::<T>::register_currency(origin.clone(), currency_id, full_buffer_values::<T>())
+            .map_err(|_| BenchmarkError::Weightless)?;
+
+        // Each data feeder has submitted a value for the currency
+        for x in 0..f {
+            let feeder: T::AccountId = account("feeder", x, 0);
+            LastSubmission::<T>::insert(currency_id, feeder, 1);
+        }
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, currency_id);
+
+        assert!(!Pallet::<T>::is_tracked(&currency_id));
+        assert_eq!(LastSubmission::<T>::iter_prefix(currency_id).count(), 0);
+
+        Ok(())
     }
 
     impl_benchmark_test_suite!(
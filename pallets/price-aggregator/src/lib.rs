@@ -22,14 +22,17 @@
 //!
 //! Purpose of this pallet is to aggregate price data over some time, and then calculate the moving average.
 //!
+//! Native currency is always tracked. Additional currencies can be registered & unregistered by the privileged origin,
+//! and each of them is aggregated independently, with its own block values, intermediate aggregator & circular buffer.
+//!
 //! ## Solution
 //!
 //! The overall solution is broken down into several steps that occur over the course of various time periods.
 //!
 //! ### Block Aggregation
 //!
-//! During each block, the native currency price data is accumulated. This is done 'outside' the pallet, and it's only expected
-//! that 'something' will push this data to the price aggregator pallet. The pallet itself doesn't care about the source of the data, nor who submitted it.
+//! During each block, the price data of each tracked currency is accumulated. This is done 'outside' the pallet, and it's only expected
+//! that 'something' will push this data to the price aggregator pallet. The pallet itself doesn't care about the source of the data, and only tracks who submitted it for the liveness monitoring.
 //!
 //! At the end of each block, accumulated data is processed according to the specified algorithm (e.g. can be average, median, or something else).
 //! In case processing was successful, the result is stored in the intermediate value aggregator.
@@ -45,25 +48,44 @@
 //!
 //! The moving average is calculated from the circular buffer, and is used to provide the 'average' price of the native currency, over some time period.
 //! It's important to note that the moving average is not a 'real-time' value, but rather a 'lagging' indicator.
+//!
+//! The calculation strategy is configurable, e.g. simple, exponential or time-weighted moving average, or a trimmed mean
+//! which ignores the outliers.
+//!
+//! ### Circuit Breaker
+//!
+//! To limit the impact of a compromised data feeder, both the submitted values and the aggregated average values
+//! are checked against the reference price, which is the latest value in the circular buffer.
+//! Values outside of the allowed deviation band are either rejected or clamped, depending on the configuration.
+//!
+//! In case of a genuine market move, privileged origin can reset the circular buffer to the new values.
+//!
+//! ### Liveness
+//!
+//! The last submission block of each data feeder is tracked, as well as the number of consecutive blocks without
+//! any valid value for each currency. Once the threshold is reached, the price feed is considered stale and an event is emitted.
+//! Optionally, if the native currency price feed is stale for too long, a fallback price provider is used instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use frame_support::{pallet_prelude::*, DefaultNoBound};
+use frame_support::{pallet_prelude::*, traits::ChangeMembers, DefaultNoBound};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_arithmetic::{
-    fixed_point::FixedU128,
-    traits::{CheckedAdd, SaturatedConversion, Saturating, Zero},
+    fixed_point::{FixedPointNumber, FixedU128},
+    traits::{CheckedAdd, CheckedDiv, One, SaturatedConversion, Saturating, Zero},
+    PerThing, Perbill,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use orml_traits::OnNewData;
 
 use astar_primitives::{
-    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    oracle::{CurrencyAmount, CurrencyId, MultiCurrencyPriceProvider, PriceProvider},
     BlockNumber,
 };
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -124,6 +146,108 @@ impl ProcessBlockValues for MedianBlockValue {
     }
 }
 
+/// Trait for calculating the moving average from the values stored in the circular buffer.
+///
+/// Values are provided in chronological order, oldest value first.
+pub trait MovingAverageStrategy {
+    /// Calculate the moving average of the provided values.
+    ///
+    /// In case no values are provided, zero is returned.
+    fn calculate(values: &[CurrencyAmount]) -> CurrencyAmount;
+}
+
+/// Used to calculate the simple moving average, where all values have the same weight.
+pub struct SimpleMovingAverage;
+impl MovingAverageStrategy for SimpleMovingAverage {
+    fn calculate(values: &[CurrencyAmount]) -> CurrencyAmount {
+        if values.is_empty() {
+            return CurrencyAmount::zero();
+        }
+
+        let sum = values.iter().fold(CurrencyAmount::zero(), |acc, &value| {
+            acc.saturating_add(value)
+        });
+
+        sum.saturating_mul(FixedU128::from_rational(1, values.len() as u128))
+    }
+}
+
+/// Used to calculate the exponential moving average.
+///
+/// The oldest value is used as the seed, and each subsequent value is applied with the `Alpha` smoothing factor:
+/// `ema = alpha * value + (1 - alpha) * ema`.
+///
+/// Higher `Alpha` makes the average react faster to the new values.
+pub struct ExponentialMovingAverage<Alpha>(PhantomData<Alpha>);
+impl<Alpha: Get<Perbill>> MovingAverageStrategy for ExponentialMovingAverage<Alpha> {
+    fn calculate(values: &[CurrencyAmount]) -> CurrencyAmount {
+        let (seed, rest) = match values.split_first() {
+            Some(split) => split,
+            None => return CurrencyAmount::zero(),
+        };
+
+        let alpha = FixedU128::from_perbill(Alpha::get());
+        let complement = FixedU128::from_perbill(Alpha::get().left_from_one());
+
+        rest.iter().fold(*seed, |ema, &value| {
+            alpha
+                .saturating_mul(value)
+                .saturating_add(complement.saturating_mul(ema))
+        })
+    }
+}
+
+/// Used to calculate the time-weighted moving average.
+///
+/// Since values in the buffer are sampled at equal intervals, the weight of each value is its position in time,
+/// i.e. the oldest value has weight `1`, and the newest value has weight `n`.
+pub struct TimeWeightedAverage;
+impl MovingAverageStrategy for TimeWeightedAverage {
+    fn calculate(values: &[CurrencyAmount]) -> CurrencyAmount {
+        if values.is_empty() {
+            return CurrencyAmount::zero();
+        }
+
+        let weighted_sum =
+            values
+                .iter()
+                .zip(1_u128..)
+                .fold(CurrencyAmount::zero(), |acc, (&value, weight)| {
+                    acc.saturating_add(
+                        value.saturating_mul(FixedU128::saturating_from_integer(weight)),
+                    )
+                });
+
+        // Sum of weights, `1 + 2 + ... + n`, is guaranteed to be greater than zero at this point.
+        let len = values.len() as u128;
+        let total_weight = len.saturating_mul(len.saturating_add(1)) / 2;
+
+        weighted_sum.saturating_mul(FixedU128::from_rational(1, total_weight))
+    }
+}
+
+/// Used to calculate the trimmed mean, where the outliers are ignored.
+///
+/// Values are sorted, and `TrimRatio` share of values is dropped from both the lower and the upper end.
+/// Simple average of the remaining values is returned. At least one value is always kept.
+pub struct TrimmedMeanAverage<TrimRatio>(PhantomData<TrimRatio>);
+impl<TrimRatio: Get<Perbill>> MovingAverageStrategy for TrimmedMeanAverage<TrimRatio> {
+    fn calculate(values: &[CurrencyAmount]) -> CurrencyAmount {
+        if values.is_empty() {
+            return CurrencyAmount::zero();
+        }
+
+        let mut sorted_values = values.to_vec();
+        sorted_values.sort_unstable();
+
+        let len = sorted_values.len();
+        let max_trim = len.saturating_sub(1) / 2;
+        let trim = (TrimRatio::get().mul_floor(len as u32) as usize).min(max_trim);
+
+        SimpleMovingAverage::calculate(&sorted_values[trim..len.saturating_sub(trim)])
+    }
+}
+
 /// Used to aggregate the accumulated values over some time period.
 ///
 /// To avoid having a large memory footprint, values are summed up into a single accumulator.
@@ -177,6 +301,16 @@ impl ValueAggregator {
                 .saturating_mul(FixedU128::from_rational(1, self.count.into()))
         }
     }
+
+    /// Number of values accumulated so far.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Block number at which aggregation should reset.
+    pub fn limit_block(&self) -> BlockNumber {
+        self.limit_block
+    }
 }
 
 /// Used to store the aggregated intermediate values into a circular buffer.
@@ -204,6 +338,15 @@ pub struct CircularBuffer<L: Get<u32>> {
 }
 
 impl<L: Get<u32>> CircularBuffer<L> {
+    /// New circular buffer, initialized with the provided values.
+    ///
+    /// Values are expected to be in chronological order, and are truncated to the buffer capacity if needed.
+    pub fn from_values(values: &[CurrencyAmount]) -> Self {
+        let mut circular_buffer = Self::default();
+        values.iter().for_each(|value| circular_buffer.add(*value));
+        circular_buffer
+    }
+
     /// Adds a new value to the circular buffer, possibly overriding the oldest value if capacity is filled.
     pub fn add(&mut self, value: CurrencyAmount) {
         // This can never happen, parameters must ensure that.
@@ -230,21 +373,109 @@ impl<L: Get<u32>> CircularBuffer<L> {
         self.head = self.head.saturating_add(1) % L::get();
     }
 
-    /// Returns the average of the accumulated values.
+    /// Returns the simple average of the accumulated values.
     pub fn average(&self) -> CurrencyAmount {
+        SimpleMovingAverage::calculate(&self.buffer)
+    }
+
+    /// Returns the moving average of the accumulated values, calculated using the provided strategy.
+    pub fn moving_average<S: MovingAverageStrategy>(&self) -> CurrencyAmount {
+        S::calculate(&self.ordered_values())
+    }
+
+    /// Returns the accumulated values in chronological order, oldest value first.
+    pub fn ordered_values(&self) -> Vec<CurrencyAmount> {
+        // Until the buffer is filled out, values are already in the chronological order.
+        // Afterwards, `head` points to the oldest value.
+        let split_index = if self.buffer.len() < L::get() as usize {
+            0
+        } else {
+            (self.head as usize).min(self.buffer.len())
+        };
+
+        let (newer, older) = self.buffer.split_at(split_index);
+        older.iter().chain(newer.iter()).copied().collect()
+    }
+
+    /// Returns the most recently added value, if any.
+    pub fn latest(&self) -> Option<CurrencyAmount> {
         if self.buffer.is_empty() {
-            return CurrencyAmount::zero();
+            return None;
         }
 
-        let sum = self
-            .buffer
-            .iter()
-            .fold(CurrencyAmount::zero(), |acc, &value| {
-                acc.saturating_add(value)
-            });
+        let index = if self.head.is_zero() {
+            self.buffer.len().saturating_sub(1)
+        } else {
+            (self.head as usize).saturating_sub(1)
+        };
 
-        // At this point, length of the buffer is guaranteed to be greater than zero.
-        sum.saturating_mul(FixedU128::from_rational(1, self.buffer.len() as u128))
+        self.buffer.get(index).copied()
+    }
+}
+
+/// Action to take when a value deviates from the reference price more than allowed.
+#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+pub enum DeviationAction {
+    /// Value is ignored.
+    Reject,
+    /// Value is clamped to the nearest bound of the allowed band.
+    Clamp,
+}
+
+/// Allowed band around the reference price.
+///
+/// For the maximum deviation `d`, the band is `[reference / (1 + d), reference * (1 + d)]`,
+/// which makes it symmetric in relative terms, e.g. for `d = 100%` the value can at most halve or double.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DeviationBand {
+    /// Lowest allowed value.
+    pub lower: CurrencyAmount,
+    /// Highest allowed value.
+    pub upper: CurrencyAmount,
+}
+
+impl DeviationBand {
+    /// New band around the `reference` value, with the given maximum deviation.
+    pub fn new(reference: CurrencyAmount, max_deviation: Perbill) -> Self {
+        let factor = FixedU128::one().saturating_add(FixedU128::from_perbill(max_deviation));
+
+        Self {
+            lower: reference
+                .checked_div(&factor)
+                .unwrap_or_else(CurrencyAmount::zero),
+            upper: reference.saturating_mul(factor),
+        }
+    }
+
+    /// `true` if value is within the band (inclusive), `false` otherwise.
+    pub fn contains(&self, value: CurrencyAmount) -> bool {
+        value >= self.lower && value <= self.upper
+    }
+
+    /// Clamps the value to the band.
+    pub fn clamp(&self, value: CurrencyAmount) -> CurrencyAmount {
+        value.max(self.lower).min(self.upper)
+    }
+}
+
+/// Used to provide the fallback native currency price, in case the price feed has been stale for too long.
+pub trait FallbackPrice {
+    /// Returns the fallback price, or `None` if there's no fallback.
+    fn fallback_price() -> Option<CurrencyAmount>;
+}
+
+/// No fallback, moving average is always used.
+impl FallbackPrice for () {
+    fn fallback_price() -> Option<CurrencyAmount> {
+        None
+    }
+}
+
+/// Uses the provided price provider as the fallback, e.g. `pallet-static-price-provider`.
+pub struct FallbackToProvider<P>(PhantomData<P>);
+impl<P: PriceProvider> FallbackPrice for FallbackToProvider<P> {
+    fn fallback_price() -> Option<CurrencyAmount> {
+        Some(P::average_price())
     }
 }
 
@@ -255,7 +486,7 @@ pub mod pallet {
     use super::*;
 
     /// The current storage version.
-    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -273,9 +504,18 @@ pub mod pallet {
         /// Used to process accumulated values in the current block.
         type ProcessBlockValues: ProcessBlockValues;
 
+        /// Used to calculate the moving average from the values stored in the circular buffer.
+        type MovingAverageStrategy: MovingAverageStrategy;
+
         /// Native currency ID that this pallet is supposed to track.
+        ///
+        /// Native currency is always tracked, and cannot be unregistered.
         type NativeCurrencyId: Get<CurrencyId>;
 
+        /// Maximum number of additional currencies that can be tracked, excluding the native currency.
+        #[pallet::constant]
+        type MaxTrackedCurrencies: Get<u32>;
+
         /// Maximum length of the circular buffer used to calculate the moving average.
         #[pallet::constant]
         type CircularBufferLength: Get<u32>;
@@ -285,6 +525,43 @@ pub mod pallet {
         #[pallet::constant]
         type AggregationDuration: Get<BlockNumberFor<Self>>;
 
+        /// Maximum allowed deviation of a single submitted value from the reference price,
+        /// which is the latest value in the circular buffer.
+        #[pallet::constant]
+        type MaxBlockDeviation: Get<Perbill>;
+
+        /// Maximum allowed deviation of the aggregation window average from the reference price,
+        /// which is the latest value in the circular buffer.
+        #[pallet::constant]
+        type MaxAggregationDeviation: Get<Perbill>;
+
+        /// Action to take when a value is outside the allowed deviation band.
+        #[pallet::constant]
+        type DeviationAction: Get<DeviationAction>;
+
+        /// Origin allowed to reset the circular buffer, e.g. after a genuine market move.
+        type ResetOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Origin allowed to register & unregister tracked currencies.
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Number of consecutive blocks without any valid value, after which the price feed is considered stale.
+        #[pallet::constant]
+        type StalenessThreshold: Get<u32>;
+
+        /// Used to provide the fallback native currency price, in case the price feed has been stale for too long.
+        type FallbackPrice: FallbackPrice;
+
+        /// Number of consecutive blocks without any valid native currency value,
+        /// after which the fallback price is used instead of the moving average.
+        #[pallet::constant]
+        type FallbackThreshold: Get<u32>;
+
+        /// Maximum number of data feeders, e.g. the maximum number of oracle members.
+        /// Bounds the number of last submissions tracked per currency.
+        #[pallet::constant]
+        type MaxDataFeeders: Get<u32>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -297,12 +574,17 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
         fn build(&self) {
-            ValuesCircularBuffer::<T>::put(CircularBuffer::<T::CircularBufferLength> {
-                buffer: self.circular_buffer.clone(),
-                head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
-            });
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            ValuesCircularBuffer::<T>::insert(
+                native_currency_id,
+                CircularBuffer::<T::CircularBufferLength> {
+                    buffer: self.circular_buffer.clone(),
+                    head: self.circular_buffer.len() as u32 % T::CircularBufferLength::get(),
+                },
+            );
 
-            IntermediateValueAggregator::<T>::mutate(|aggregator| {
+            IntermediateValueAggregator::<T>::mutate(native_currency_id, |aggregator| {
                 aggregator.limit_block = T::AggregationDuration::get().saturated_into();
             });
         }
@@ -311,53 +593,162 @@ pub mod pallet {
     #[pallet::event]
     #[pallet::generate_deposit(pub(crate) fn deposit_event)]
     pub enum Event<T: Config> {
-        /// New average native currency value has been calculated and pushed into the moving average buffer.
-        AverageAggregatedValue { value: CurrencyAmount },
+        /// New average currency value has been calculated and pushed into the moving average buffer.
+        AverageAggregatedValue {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+        },
+        /// Submitted value deviated too much from the reference price, and was rejected.
+        BlockValueRejected {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+            reference: CurrencyAmount,
+        },
+        /// Submitted value deviated too much from the reference price, and was clamped.
+        BlockValueClamped {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+            clamped: CurrencyAmount,
+        },
+        /// Aggregated average value deviated too much from the reference price, and was rejected.
+        AggregatedValueRejected {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+            reference: CurrencyAmount,
+        },
+        /// Aggregated average value deviated too much from the reference price, and was clamped.
+        AggregatedValueClamped {
+            currency_id: CurrencyId,
+            value: CurrencyAmount,
+            clamped: CurrencyAmount,
+        },
+        /// Circular buffer has been reset to new values.
+        CircularBufferReset {
+            currency_id: CurrencyId,
+            values: BoundedVec<CurrencyAmount, T::CircularBufferLength>,
+        },
+        /// New currency is now being tracked.
+        CurrencyRegistered { currency_id: CurrencyId },
+        /// Currency is no longer being tracked, and all of its data has been removed.
+        CurrencyUnregistered { currency_id: CurrencyId },
+        /// No valid value has been submitted for the currency during the specified number of consecutive blocks.
+        PriceFeedStale {
+            currency_id: CurrencyId,
+            blocks: u32,
+        },
+        /// Valid values are being submitted for the currency again, after the price feed was stale.
+        PriceFeedResumed {
+            currency_id: CurrencyId,
+            stale_blocks: u32,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Circular buffer must contain at least one value.
+        EmptyCircularBuffer,
+        /// Zero is invalid value for the price.
+        ZeroValue,
+        /// Currency is already being tracked.
+        CurrencyAlreadyTracked,
+        /// Currency isn't being tracked.
+        CurrencyNotTracked,
+        /// Maximum number of tracked currencies has been reached.
+        TooManyTrackedCurrencies,
+        /// Native currency is always tracked, and cannot be unregistered.
+        NativeCurrencyCannotBeUnregistered,
     }
 
-    /// Storage for the accumulated native currency price in the current block.
+    /// Additional currencies tracked by the pallet, excluding the native currency.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type CurrentBlockValues<T: Config> =
-        StorageValue<_, BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>, ValueQuery>;
+    pub type TrackedCurrencies<T: Config> =
+        StorageValue<_, BoundedVec<CurrencyId, T::MaxTrackedCurrencies>, ValueQuery>;
 
-    /// Used to store the aggregated processed block values during some time period.
+    /// Storage for the accumulated currency prices in the current block.
     #[pallet::storage]
-    #[pallet::whitelist_storage]
-    pub type IntermediateValueAggregator<T: Config> = StorageValue<_, ValueAggregator, ValueQuery>;
+    pub type CurrentBlockValues<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        BoundedVec<CurrencyAmount, T::MaxValuesPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Used to store the aggregated processed block values during some time period, per currency.
+    #[pallet::storage]
+    pub type IntermediateValueAggregator<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, ValueAggregator, ValueQuery>;
 
-    /// Used to store aggregated intermediate values for some time period.
+    /// Used to store aggregated intermediate values for some time period, per currency.
+    #[pallet::storage]
+    pub type ValuesCircularBuffer<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        CircularBuffer<T::CircularBufferLength>,
+        ValueQuery,
+    >;
+
+    /// Block number at which the data feeder last submitted an accepted value, per currency.
+    ///
+    /// Entries are removed when the currency is unregistered, or when the data feeder is removed.
     #[pallet::storage]
-    pub type ValuesCircularBuffer<T: Config> =
-        StorageValue<_, CircularBuffer<T::CircularBufferLength>, ValueQuery>;
+    pub type LastSubmission<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        CurrencyId,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumber,
+        OptionQuery,
+    >;
+
+    /// Number of consecutive blocks without any valid value, per currency.
+    #[pallet::storage]
+    pub type BlocksWithoutValidValue<T: Config> =
+        StorageMap<_, Twox64Concat, CurrencyId, u32, ValueQuery>;
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            // Need to account for the reads and writes of:
-            // - CurrentBlockValues
-            // - IntermediateValueAggregator
-            //
-            // Also need to account for the weight of processing block accumulated values.
-            let mut total_weight = T::DbWeight::get()
-                .reads_writes(2, 2)
-                .saturating_add(T::WeightInfo::process_block_aggregated_values());
-
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                total_weight
-                    .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+            // Need to account for the read of tracked currencies.
+            let mut total_weight = T::DbWeight::get().reads(1);
+
+            for currency_id in Self::tracked_currencies() {
+                // Need to account for the reads and writes of:
+                // - CurrentBlockValues
+                // - IntermediateValueAggregator
+                // - BlocksWithoutValidValue
+                //
+                // Also need to account for the weight of processing block accumulated values.
+                total_weight.saturating_accrue(
+                    T::DbWeight::get()
+                        .reads_writes(3, 3)
+                        .saturating_add(T::WeightInfo::process_block_aggregated_values()),
+                );
+
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    total_weight
+                        .saturating_accrue(T::WeightInfo::process_intermediate_aggregated_values());
+                }
             }
 
             total_weight
         }
 
         fn on_finalize(now: BlockNumberFor<T>) {
-            // 1. Process the accumulated native currency values in the current block.
-            Self::process_block_aggregated_values();
-
-            // 2. Check if we need to push the average aggregated value to the storage.
-            if IntermediateValueAggregator::<T>::get().limit_block <= now.saturated_into() {
-                Self::process_intermediate_aggregated_values(now);
+            for currency_id in Self::tracked_currencies() {
+                // 1. Process the accumulated currency values in the current block.
+                Self::process_block_aggregated_values(currency_id);
+
+                // 2. Check if we need to push the average aggregated value to the storage.
+                if IntermediateValueAggregator::<T>::get(currency_id).limit_block
+                    <= now.saturated_into()
+                {
+                    Self::process_intermediate_aggregated_values(currency_id, now);
+                }
             }
         }
 
@@ -365,28 +756,202 @@ pub mod pallet {
             assert!(T::MaxValuesPerBlock::get() > 0);
             assert!(T::CircularBufferLength::get() > 0);
             assert!(!T::AggregationDuration::get().is_zero());
+            assert!(T::StalenessThreshold::get() > 0);
+            assert!(T::FallbackThreshold::get() > 0);
+        }
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Privileged action used to reset the circular buffer of the specified currency to the provided values.
+        ///
+        /// Intended to be used after a genuine market move, when the circuit breaker prevents
+        /// the moving average from catching up with the real price.
+        /// Intermediate aggregated values and current block values are cleared as well, but the limit block is kept.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::reset_circular_buffer())]
+        pub fn reset_circular_buffer(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+            values: BoundedVec<CurrencyAmount, T::CircularBufferLength>,
+        ) -> DispatchResult {
+            T::ResetOrigin::ensure_origin(origin)?;
+            ensure!(
+                Self::is_tracked(&currency_id),
+                Error::<T>::CurrencyNotTracked
+            );
+            Self::ensure_valid_buffer_values(&values)?;
+
+            ValuesCircularBuffer::<T>::insert(currency_id, CircularBuffer::from_values(&values));
+            IntermediateValueAggregator::<T>::mutate(currency_id, |aggregator| {
+                *aggregator = ValueAggregator::new(aggregator.limit_block);
+            });
+            CurrentBlockValues::<T>::remove(currency_id);
+
+            Self::deposit_event(Event::<T>::CircularBufferReset {
+                currency_id,
+                values,
+            });
+
+            Ok(())
+        }
+
+        /// Privileged action used to start tracking a new currency.
+        ///
+        /// Circular buffer is initialized with the provided values, which are also used as the reference price
+        /// for the circuit breaker.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::register_currency())]
+        pub fn register_currency(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+            initial_values: BoundedVec<CurrencyAmount, T::CircularBufferLength>,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                !Self::is_tracked(&currency_id),
+                Error::<T>::CurrencyAlreadyTracked
+            );
+            Self::ensure_valid_buffer_values(&initial_values)?;
+
+            TrackedCurrencies::<T>::try_mutate(|currencies| {
+                currencies
+                    .try_push(currency_id)
+                    .map_err(|_| Error::<T>::TooManyTrackedCurrencies)
+            })?;
+
+            ValuesCircularBuffer::<T>::insert(
+                currency_id,
+                CircularBuffer::from_values(&initial_values),
+            );
+            let now = frame_system::Pallet::<T>::block_number();
+            IntermediateValueAggregator::<T>::insert(
+                currency_id,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
+
+            Self::deposit_event(Event::<T>::CurrencyRegistered { currency_id });
+
+            Ok(())
+        }
+
+        /// Privileged action used to stop tracking a currency.
+        ///
+        /// All of the currency data is removed. Native currency cannot be unregistered.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::unregister_currency(T::MaxDataFeeders::get()))]
+        pub fn unregister_currency(
+            origin: OriginFor<T>,
+            currency_id: CurrencyId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                currency_id != T::NativeCurrencyId::get(),
+                Error::<T>::NativeCurrencyCannotBeUnregistered
+            );
+
+            TrackedCurrencies::<T>::try_mutate(|currencies| {
+                let index = currencies
+                    .iter()
+                    .position(|id| *id == currency_id)
+                    .ok_or(Error::<T>::CurrencyNotTracked)?;
+                currencies.remove(index);
+
+                Ok::<(), Error<T>>(())
+            })?;
+
+            ValuesCircularBuffer::<T>::remove(currency_id);
+            IntermediateValueAggregator::<T>::remove(currency_id);
+            CurrentBlockValues::<T>::remove(currency_id);
+            BlocksWithoutValidValue::<T>::remove(currency_id);
+            let _ = LastSubmission::<T>::clear_prefix(currency_id, T::MaxDataFeeders::get(), None);
+
+            Self::deposit_event(Event::<T>::CurrencyUnregistered { currency_id });
+
+            Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Used to process the native currency values accumulated in the current block.
+        /// All tracked currencies, native currency first.
+        pub fn tracked_currencies() -> Vec<CurrencyId> {
+            sp_std::iter::once(T::NativeCurrencyId::get())
+                .chain(TrackedCurrencies::<T>::get())
+                .collect()
+        }
+
+        /// `true` if the currency is being tracked, `false` otherwise.
+        pub fn is_tracked(currency_id: &CurrencyId) -> bool {
+            *currency_id == T::NativeCurrencyId::get()
+                || TrackedCurrencies::<T>::get().contains(currency_id)
+        }
+
+        /// `true` if no valid value has been submitted for the currency for at least `StalenessThreshold` blocks.
+        pub fn is_stale(currency_id: CurrencyId) -> bool {
+            BlocksWithoutValidValue::<T>::get(currency_id) >= T::StalenessThreshold::get()
+        }
+
+        /// Updates the number of consecutive blocks without any valid value for the currency,
+        /// and notifies about price feed staleness changes.
+        fn note_block_value(currency_id: CurrencyId, has_valid_value: bool) {
+            let threshold = T::StalenessThreshold::get();
+
+            BlocksWithoutValidValue::<T>::mutate(currency_id, |blocks| {
+                if has_valid_value {
+                    if *blocks >= threshold {
+                        Self::deposit_event(Event::PriceFeedResumed {
+                            currency_id,
+                            stale_blocks: *blocks,
+                        });
+                    }
+                    *blocks = 0;
+                } else {
+                    blocks.saturating_inc();
+                    if *blocks == threshold {
+                        Self::deposit_event(Event::PriceFeedStale {
+                            currency_id,
+                            blocks: *blocks,
+                        });
+                    }
+                }
+            });
+        }
+
+        /// Ensures the provided circular buffer values are non-empty, and that none of them is zero.
+        fn ensure_valid_buffer_values(values: &[CurrencyAmount]) -> DispatchResult {
+            ensure!(!values.is_empty(), Error::<T>::EmptyCircularBuffer);
+            ensure!(
+                values.iter().all(|value| !value.is_zero()),
+                Error::<T>::ZeroValue
+            );
+
+            Ok(())
+        }
+
+        /// Used to process the currency values accumulated in the current block.
         ///
         /// Guarantees that the accumulated values are cleared after processing.
-        /// In case of an error during processing, intermediate aggregated value is not updated.
-        pub(crate) fn process_block_aggregated_values() {
+        /// In case of an error during processing, intermediate aggregated value is not updated,
+        /// and the block is counted as a block without any valid value.
+        pub(crate) fn process_block_aggregated_values(currency_id: CurrencyId) {
             // 1. Take the accumulated block values, clearing the existing storage.
-            let accumulated_values = CurrentBlockValues::<T>::take();
+            let accumulated_values = CurrentBlockValues::<T>::take(currency_id);
 
             // 2. Attempt to process accumulated block values.
-            let processed_value = match T::ProcessBlockValues::process(
-                accumulated_values.as_slice(),
-            ) {
+            let processed_value = T::ProcessBlockValues::process(accumulated_values.as_slice());
+            Self::note_block_value(currency_id, processed_value.is_ok());
+
+            let processed_value = match processed_value {
                 Ok(value) => value,
                 Err(message) => {
                     log::trace!(
                         target: LOG_TARGET,
-                        "Failed to process the accumulated native currency values in the current block. \
+                        "Failed to process the accumulated {:?} currency values in the current block. \
                         Reason: {:?}",
+                        currency_id,
                         message
                     );
 
@@ -397,16 +962,17 @@ pub mod pallet {
 
             // 3. Attempt to store the processed value.
             // This operation is practically infallible, but we check the results for the additional safety.
-            let intermediate_value = IntermediateValueAggregator::<T>::get();
+            let intermediate_value = IntermediateValueAggregator::<T>::get(currency_id);
             match intermediate_value.try_add(processed_value) {
                 Ok(new_aggregator) => {
-                    IntermediateValueAggregator::<T>::put(new_aggregator);
+                    IntermediateValueAggregator::<T>::insert(currency_id, new_aggregator);
                 }
                 Err(message) => {
                     log::error!(
                         target: LOG_TARGET,
-                        "Failed to add the processed native currency value to the intermediate storage. \
+                        "Failed to add the processed {:?} currency value to the intermediate storage. \
                         Reason: {:?}",
+                        currency_id,
                         message
                     );
                 }
@@ -414,30 +980,71 @@ pub mod pallet {
         }
 
         /// Used to process the intermediate aggregated values, and push them to the moving average storage.
-        pub(crate) fn process_intermediate_aggregated_values(now: BlockNumberFor<T>) {
+        pub(crate) fn process_intermediate_aggregated_values(
+            currency_id: CurrencyId,
+            now: BlockNumberFor<T>,
+        ) {
             // 1. Get the average value from the intermediate aggregator.
-            let average_value = IntermediateValueAggregator::<T>::get().average();
+            let average_value = IntermediateValueAggregator::<T>::get(currency_id).average();
 
             // 2. Reset the aggregator back to zero, and set the new limit block.
-            IntermediateValueAggregator::<T>::put(ValueAggregator::new(
-                now.saturating_add(T::AggregationDuration::get())
-                    .saturated_into(),
-            ));
+            IntermediateValueAggregator::<T>::insert(
+                currency_id,
+                ValueAggregator::new(
+                    now.saturating_add(T::AggregationDuration::get())
+                        .saturated_into(),
+                ),
+            );
 
             // 3. In case aggregated value equals 0, it means something has gone wrong since it's extremely unlikely
             // that price goes to absolute zero. The much more likely case is that there's a problem with the oracle data feed.
             if average_value.is_zero() {
                 log::error!(
                     target: LOG_TARGET,
-                    "The average aggregated price equals zero, which most likely means that oracle data feed is faulty. \
-                    Not pushing the 'zero' value to the moving average storage."
+                    "The average aggregated {:?} price equals zero, which most likely means that oracle data feed is faulty. \
+                    Not pushing the 'zero' value to the moving average storage.",
+                    currency_id
                 );
                 return;
             }
 
-            // 4. Push the 'valid' average aggregated value to the circular buffer.
-            ValuesCircularBuffer::<T>::mutate(|buffer| buffer.add(average_value));
+            // 4. Ensure the average value doesn't deviate too much from the reference price.
+            let mut circular_buffer = ValuesCircularBuffer::<T>::get(currency_id);
+            let average_value = match circular_buffer.latest() {
+                Some(reference) => {
+                    let band = DeviationBand::new(reference, T::MaxAggregationDeviation::get());
+                    if band.contains(average_value) {
+                        average_value
+                    } else {
+                        match T::DeviationAction::get() {
+                            DeviationAction::Reject => {
+                                Self::deposit_event(Event::AggregatedValueRejected {
+                                    currency_id,
+                                    value: average_value,
+                                    reference,
+                                });
+                                return;
+                            }
+                            DeviationAction::Clamp => {
+                                let clamped = band.clamp(average_value);
+                                Self::deposit_event(Event::AggregatedValueClamped {
+                                    currency_id,
+                                    value: average_value,
+                                    clamped,
+                                });
+                                clamped
+                            }
+                        }
+                    }
+                }
+                None => average_value,
+            };
+
+            // 5. Push the 'valid' average aggregated value to the circular buffer.
+            circular_buffer.add(average_value);
+            ValuesCircularBuffer::<T>::insert(currency_id, circular_buffer);
             Self::deposit_event(Event::AverageAggregatedValue {
+                currency_id,
                 value: average_value,
             });
         }
@@ -446,18 +1053,57 @@ pub mod pallet {
     // Make this pallet an 'observer' ('listener') of the new oracle data feed.
     impl<T: Config> OnNewData<T::AccountId, CurrencyId, CurrencyAmount> for Pallet<T> {
         fn on_new_data(who: &T::AccountId, key: &CurrencyId, value: &CurrencyAmount) {
-            // Ignore any currency that is not native currency.
-            if T::NativeCurrencyId::get() != *key {
+            // Ignore any currency that is not tracked.
+            if !Self::is_tracked(key) {
                 return;
             }
+            let currency_id = *key;
+
+            // Ensure the value doesn't deviate too much from the reference price.
+            let value = match ValuesCircularBuffer::<T>::get(currency_id).latest() {
+                Some(reference) => {
+                    let band = DeviationBand::new(reference, T::MaxBlockDeviation::get());
+                    if band.contains(*value) {
+                        *value
+                    } else {
+                        match T::DeviationAction::get() {
+                            DeviationAction::Reject => {
+                                Self::deposit_event(Event::BlockValueRejected {
+                                    currency_id,
+                                    value: *value,
+                                    reference,
+                                });
+                                return;
+                            }
+                            DeviationAction::Clamp => {
+                                let clamped = band.clamp(*value);
+                                Self::deposit_event(Event::BlockValueClamped {
+                                    currency_id,
+                                    value: *value,
+                                    clamped,
+                                });
+                                clamped
+                            }
+                        }
+                    }
+                }
+                None => *value,
+            };
 
-            CurrentBlockValues::<T>::mutate(|v| match v.try_push(*value) {
-                Ok(()) => {}
+            CurrentBlockValues::<T>::mutate(currency_id, |v| match v.try_push(value) {
+                Ok(()) => {
+                    LastSubmission::<T>::insert(
+                        currency_id,
+                        who,
+                        frame_system::Pallet::<T>::block_number().saturated_into::<BlockNumber>(),
+                    );
+                }
                 Err(_) => {
                     log::error!(
                     target: LOG_TARGET,
-                        "Failed to push native currency value into the ongoing block due to exceeded capacity. \
+                        "Failed to push {:?} currency value into the ongoing block due to exceeded capacity. \
                         Value was submitted by: {:?}",
+                        currency_id,
                         who
                     );
                 }
@@ -465,12 +1111,53 @@ pub mod pallet {
         }
     }
 
+    // Make this pallet aware of the data feeder changes, e.g. of the oracle membership changes,
+    // so the last submissions of the removed data feeders are cleaned up.
+    impl<T: Config> ChangeMembers<T::AccountId> for Pallet<T> {
+        fn change_members_sorted(
+            _incoming: &[T::AccountId],
+            outgoing: &[T::AccountId],
+            _sorted_new: &[T::AccountId],
+        ) {
+            for currency_id in Self::tracked_currencies() {
+                for who in outgoing {
+                    LastSubmission::<T>::remove(currency_id, who);
+                }
+            }
+        }
+    }
+
     // Make this pallet a `price provider` for the native currency.
     //
-    // For this particular implementation, a simple moving average is used to calculate the average price.
+    // The moving average is calculated using the configured strategy.
+    // In case the price feed has been stale for too long, fallback price is used, if available.
     impl<T: Config> PriceProvider for Pallet<T> {
         fn average_price() -> FixedU128 {
-            ValuesCircularBuffer::<T>::get().average()
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            if BlocksWithoutValidValue::<T>::get(native_currency_id) >= T::FallbackThreshold::get()
+            {
+                if let Some(price) = T::FallbackPrice::fallback_price() {
+                    return price;
+                }
+            }
+
+            ValuesCircularBuffer::<T>::get(native_currency_id)
+                .moving_average::<T::MovingAverageStrategy>()
+        }
+    }
+
+    // Make this pallet a `price provider` for all of the tracked currencies.
+    impl<T: Config> MultiCurrencyPriceProvider for Pallet<T> {
+        fn average_price_of(currency_id: CurrencyId) -> Option<FixedU128> {
+            if !Self::is_tracked(&currency_id) {
+                return None;
+            }
+
+            Some(
+                ValuesCircularBuffer::<T>::get(currency_id)
+                    .moving_average::<T::MovingAverageStrategy>(),
+            )
         }
     }
 }
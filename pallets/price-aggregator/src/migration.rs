@@ -0,0 +1,120 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::UncheckedOnRuntimeUpgrade;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Exports for versioned migration `type`s for this pallet.
+pub mod versioned {
+    use super::*;
+
+    /// Migration V1 to V2 wrapped in a [`frame_support::migrations::VersionedMigration`], ensuring
+    /// the migration is only performed when on-chain version is 1.
+    pub type V1ToV2<T> = frame_support::migrations::VersionedMigration<
+        1,
+        2,
+        v2::VersionMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+mod v2 {
+    use super::*;
+
+    /// Native currency storage values, prior to the introduction of multi-currency support.
+    mod v1 {
+        use super::*;
+
+        #[frame_support::storage_alias]
+        pub type CurrentBlockValues<T: Config> = StorageValue<
+            Pallet<T>,
+            BoundedVec<CurrencyAmount, <T as Config>::MaxValuesPerBlock>,
+            ValueQuery,
+        >;
+
+        #[frame_support::storage_alias]
+        pub type IntermediateValueAggregator<T: Config> =
+            StorageValue<Pallet<T>, ValueAggregator, ValueQuery>;
+
+        #[frame_support::storage_alias]
+        pub type ValuesCircularBuffer<T: Config> = StorageValue<
+            Pallet<T>,
+            CircularBuffer<<T as Config>::CircularBufferLength>,
+            ValueQuery,
+        >;
+    }
+
+    /// Moves the native currency storage values into the per-currency storage maps.
+    pub struct VersionMigrateV1ToV2<T>(PhantomData<T>);
+    impl<T: Config> UncheckedOnRuntimeUpgrade for VersionMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let native_currency_id = T::NativeCurrencyId::get();
+
+            // Old storage values are located at the prefix of the new storage maps,
+            // so they must be taken (removed) before the new entries are inserted.
+            let block_values = v1::CurrentBlockValues::<T>::take();
+            let intermediate_aggregator = v1::IntermediateValueAggregator::<T>::take();
+            let circular_buffer = v1::ValuesCircularBuffer::<T>::take();
+
+            CurrentBlockValues::<T>::insert(native_currency_id, block_values);
+            IntermediateValueAggregator::<T>::insert(native_currency_id, intermediate_aggregator);
+            ValuesCircularBuffer::<T>::insert(native_currency_id, circular_buffer);
+
+            T::DbWeight::get().reads_writes(3, 6)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let state = (
+                v1::IntermediateValueAggregator::<T>::get(),
+                v1::ValuesCircularBuffer::<T>::get(),
+            );
+
+            Ok(state.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (old_aggregator, old_buffer): (
+                ValueAggregator,
+                CircularBuffer<T::CircularBufferLength>,
+            ) = Decode::decode(&mut state.as_ref())
+                .map_err(|_| "Cannot decode data from pre_upgrade")?;
+
+            let native_currency_id = T::NativeCurrencyId::get();
+            ensure!(
+                IntermediateValueAggregator::<T>::get(native_currency_id) == old_aggregator,
+                "Intermediate value aggregator must be migrated."
+            );
+            ensure!(
+                ValuesCircularBuffer::<T>::get(native_currency_id) == old_buffer,
+                "Circular buffer must be migrated."
+            );
+            ensure!(
+                TrackedCurrencies::<T>::get().is_empty(),
+                "No additional currencies are expected to be tracked."
+            );
+
+            Ok(())
+        }
+    }
+}
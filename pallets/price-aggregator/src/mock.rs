@@ -17,7 +17,8 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, IntermediateValueAggregator,
+    self as pallet_price_aggregator, AverageBlockValue, BlockNumberFor, DeviationAction,
+    IntermediateValueAggregator, SimpleMovingAverage,
 };
 
 use frame_support::{
@@ -25,10 +26,14 @@ use frame_support::{
     traits::{ConstU128, ConstU32, Hooks},
     weights::Weight,
 };
+use frame_system::EnsureRoot;
 use sp_io::TestExternalities;
-use sp_runtime::BuildStorage;
+use sp_runtime::{BuildStorage, Perbill};
 
-use astar_primitives::{oracle::CurrencyId, Balance, BlockNumber};
+use astar_primitives::{
+    oracle::{CurrencyAmount, CurrencyId, PriceProvider},
+    Balance, BlockNumber,
+};
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -54,6 +59,17 @@ impl pallet_balances::Config for Test {
 parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
     pub const AggregationDuration: BlockNumberFor<Test> = 16;
+    pub const MaxBlockDeviation: Perbill = Perbill::from_percent(50);
+    pub const MaxAggregationDeviation: Perbill = Perbill::from_percent(20);
+    pub static MockDeviationAction: DeviationAction = DeviationAction::Clamp;
+    pub const FallbackPriceValue: CurrencyAmount = CurrencyAmount::from_rational(42, 100);
+}
+
+pub struct MockFallbackPriceProvider;
+impl PriceProvider for MockFallbackPriceProvider {
+    fn average_price() -> CurrencyAmount {
+        FallbackPriceValue::get()
+    }
 }
 
 impl pallet_price_aggregator::Config for Test {
@@ -61,9 +77,20 @@ impl pallet_price_aggregator::Config for Test {
     // Should at least be 3 for tests to work properly
     type MaxValuesPerBlock = ConstU32<4>;
     type ProcessBlockValues = AverageBlockValue;
+    type MovingAverageStrategy = SimpleMovingAverage;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<2>;
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    type MaxBlockDeviation = MaxBlockDeviation;
+    type MaxAggregationDeviation = MaxAggregationDeviation;
+    type DeviationAction = MockDeviationAction;
+    type ResetOrigin = EnsureRoot<u64>;
+    type ManagerOrigin = EnsureRoot<u64>;
+    type StalenessThreshold = ConstU32<5>;
+    type FallbackPrice = FallbackToProvider<MockFallbackPriceProvider>;
+    type FallbackThreshold = ConstU32<10>;
+    type MaxDataFeeders = ConstU32<4>;
     type WeightInfo = ();
 }
 
@@ -85,7 +112,7 @@ impl ExtBuilder {
         let mut ext = TestExternalities::from(storage);
         ext.execute_with(|| {
             // 1. Set the initial limit block for the intermediate value aggregator
-            IntermediateValueAggregator::<Test>::mutate(|v| {
+            IntermediateValueAggregator::<Test>::mutate(NativeCurrencyId::get(), |v| {
                 v.limit_block =
                     <Test as pallet_price_aggregator::Config>::AggregationDuration::get() + 1
             });
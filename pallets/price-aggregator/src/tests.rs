@@ -18,21 +18,24 @@
 
 use crate::mock::*;
 use crate::{
-    pallet::Config, AverageBlockValue, CircularBuffer, CurrentBlockValues, Event,
-    IntermediateValueAggregator, MedianBlockValue, ProcessBlockValues, ValueAggregator,
-    ValuesCircularBuffer,
+    pallet::Config, AverageBlockValue, BlocksWithoutValidValue, CircularBuffer, CurrentBlockValues,
+    DeviationAction, DeviationBand, Error, Event, ExponentialMovingAverage,
+    IntermediateValueAggregator, LastSubmission, MedianBlockValue, MovingAverageStrategy,
+    ProcessBlockValues, SimpleMovingAverage, TimeWeightedAverage, TrimmedMeanAverage,
+    ValueAggregator, ValuesCircularBuffer,
 };
 
 use astar_primitives::oracle::{CurrencyAmount, CurrencyId};
 
 use orml_traits::OnNewData;
 
+use astar_primitives::oracle::{MultiCurrencyPriceProvider, PriceProvider};
 use frame_support::{
-    assert_storage_noop,
-    traits::{Get, Hooks},
+    assert_noop, assert_ok, assert_storage_noop, parameter_types,
+    traits::{ChangeMembers, Get, Hooks},
     BoundedVec,
 };
-use sp_runtime::{traits::Zero, Saturating};
+use sp_runtime::{traits::Zero, DispatchError, PerThing, Perbill, Saturating};
 
 pub use num_traits::Bounded;
 
@@ -137,6 +140,322 @@ fn median_block_value_works() {
     );
 }
 
+parameter_types! {
+    pub const HalfAlpha: Perbill = Perbill::from_percent(50);
+    pub const FullAlpha: Perbill = Perbill::from_percent(100);
+    pub const TenPercentTrim: Perbill = Perbill::from_percent(10);
+    pub const QuarterTrim: Perbill = Perbill::from_percent(25);
+    pub const FullTrim: Perbill = Perbill::from_percent(100);
+}
+
+/// Simple deterministic pseudo-random generator (xorshift), used for property checks.
+struct XorShift(u64);
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a random non-zero currency amount, in range `(0, 100]`.
+    fn next_amount(&mut self) -> CurrencyAmount {
+        CurrencyAmount::from_rational((self.next() % 100_000) as u128 + 1, 1000)
+    }
+}
+
+/// Calculates the moving average using all of the supported strategies.
+fn all_strategies(values: &[CurrencyAmount]) -> Vec<CurrencyAmount> {
+    vec![
+        SimpleMovingAverage::calculate(values),
+        ExponentialMovingAverage::<HalfAlpha>::calculate(values),
+        TimeWeightedAverage::calculate(values),
+        TrimmedMeanAverage::<QuarterTrim>::calculate(values),
+    ]
+}
+
+#[test]
+fn simple_moving_average_works() {
+    // 0. Empty vec check
+    assert!(SimpleMovingAverage::calculate(&[]).is_zero());
+
+    // 1. Single value check
+    let amount = CurrencyAmount::from_rational(15, 10);
+    assert_eq!(SimpleMovingAverage::calculate(&[amount]), amount);
+
+    // 2. Multiple values check
+    let values = vec![
+        CurrencyAmount::from_rational(5, 10),
+        CurrencyAmount::from_rational(15, 10),
+        CurrencyAmount::from_rational(40, 10),
+    ];
+    assert_eq!(
+        SimpleMovingAverage::calculate(&values),
+        CurrencyAmount::from_rational(20, 10)
+    );
+}
+
+#[test]
+fn exponential_moving_average_works() {
+    // 0. Empty vec check
+    assert!(ExponentialMovingAverage::<HalfAlpha>::calculate(&[]).is_zero());
+
+    // 1. Single value check, it's used as the seed
+    let amount = CurrencyAmount::from_rational(15, 10);
+    assert_eq!(
+        ExponentialMovingAverage::<HalfAlpha>::calculate(&[amount]),
+        amount
+    );
+
+    // 2. Multiple values check
+    let values = vec![
+        CurrencyAmount::from_rational(8, 10),
+        CurrencyAmount::from_rational(16, 10),
+        CurrencyAmount::from_rational(4, 10),
+    ];
+    // ema_1 = 0.8
+    // ema_2 = 0.5 * 1.6 + 0.5 * 0.8 = 1.2
+    // ema_3 = 0.5 * 0.4 + 0.5 * 1.2 = 0.8
+    assert_eq!(
+        ExponentialMovingAverage::<HalfAlpha>::calculate(&values),
+        CurrencyAmount::from_rational(8, 10)
+    );
+
+    // 3. With full alpha, only the latest value matters
+    assert_eq!(
+        ExponentialMovingAverage::<FullAlpha>::calculate(&values),
+        values[2]
+    );
+}
+
+#[test]
+fn time_weighted_average_works() {
+    // 0. Empty vec check
+    assert!(TimeWeightedAverage::calculate(&[]).is_zero());
+
+    // 1. Single value check
+    let amount = CurrencyAmount::from_rational(15, 10);
+    assert_eq!(TimeWeightedAverage::calculate(&[amount]), amount);
+
+    // 2. Multiple values check, newer values have more weight
+    let values = vec![
+        CurrencyAmount::from_rational(10, 10),
+        CurrencyAmount::from_rational(20, 10),
+        CurrencyAmount::from_rational(40, 10),
+    ];
+    // (1 * 1.0 + 2 * 2.0 + 3 * 4.0) / 6 = 17 / 6
+    assert_eq!(
+        TimeWeightedAverage::calculate(&values),
+        CurrencyAmount::from_rational(17, 6)
+    );
+
+    // 3. Order matters
+    let reversed_values: Vec<_> = values.iter().rev().copied().collect();
+    assert_eq!(
+        TimeWeightedAverage::calculate(&reversed_values),
+        CurrencyAmount::from_rational(11, 6)
+    );
+}
+
+#[test]
+fn trimmed_mean_average_works() {
+    // 0. Empty vec check
+    assert!(TrimmedMeanAverage::<QuarterTrim>::calculate(&[]).is_zero());
+
+    // 1. Single value check
+    let amount = CurrencyAmount::from_rational(15, 10);
+    assert_eq!(
+        TrimmedMeanAverage::<QuarterTrim>::calculate(&[amount]),
+        amount
+    );
+
+    // 2. Outliers on both ends are dropped
+    let values = vec![
+        CurrencyAmount::from_rational(1000, 10),
+        CurrencyAmount::from_rational(10, 10),
+        CurrencyAmount::from_rational(20, 10),
+        CurrencyAmount::from_rational(1, 1000),
+    ];
+    assert_eq!(
+        TrimmedMeanAverage::<QuarterTrim>::calculate(&values),
+        CurrencyAmount::from_rational(15, 10)
+    );
+
+    // 3. Trim ratio is rounded down, so nothing is dropped here
+    assert_eq!(
+        TrimmedMeanAverage::<TenPercentTrim>::calculate(&values),
+        SimpleMovingAverage::calculate(&values)
+    );
+
+    // 4. Excessive trim ratio always keeps the middle value(s)
+    assert_eq!(
+        TrimmedMeanAverage::<FullTrim>::calculate(&values),
+        CurrencyAmount::from_rational(15, 10)
+    );
+    assert_eq!(
+        TrimmedMeanAverage::<FullTrim>::calculate(&values[1..]),
+        CurrencyAmount::from_rational(10, 10)
+    );
+}
+
+#[test]
+fn moving_average_strategies_are_bounded_by_input_values() {
+    let mut rng = XorShift(0x5eed_1234_abcd_0001);
+
+    for _ in 0..500 {
+        let len = (rng.next() % 16) as usize + 1;
+        let values: Vec<_> = (0..len).map(|_| rng.next_amount()).collect();
+
+        let min = *values.iter().min().expect("Vec is not empty.");
+        let max = *values.iter().max().expect("Vec is not empty.");
+
+        // Allow for a tiny rounding error due to fixed point arithmetic.
+        let epsilon = CurrencyAmount::from_inner(1_000);
+        for average in all_strategies(&values) {
+            assert!(
+                average.saturating_add(epsilon) >= min && average <= max.saturating_add(epsilon),
+                "Average {:?} must be within [{:?}, {:?}] for values {:?}",
+                average,
+                min,
+                max,
+                values
+            );
+        }
+    }
+}
+
+#[test]
+fn moving_average_strategies_are_stable_for_constant_values() {
+    let mut rng = XorShift(0x0dd_ba11_cafe_babe);
+
+    for len in 1..=16 {
+        let amount = rng.next_amount();
+        let values = vec![amount; len];
+
+        let epsilon = CurrencyAmount::from_inner(1_000);
+        for average in all_strategies(&values) {
+            assert!(
+                average.saturating_add(epsilon) >= amount
+                    && average <= amount.saturating_add(epsilon),
+                "Average {:?} must equal {:?} for constant values.",
+                average,
+                amount
+            );
+        }
+    }
+}
+
+#[test]
+fn trimmed_mean_resists_outlier_manipulation() {
+    let mut rng = XorShift(0xbad_c0ff_ee00_0001);
+
+    for _ in 0..200 {
+        // Honest values are close to 1.0
+        let len = (rng.next() % 12) as usize + 8;
+        let mut values: Vec<_> = (0..len)
+            .map(|_| CurrencyAmount::from_rational(950 + (rng.next() % 100) as u128, 1000))
+            .collect();
+        let honest_min = *values.iter().min().expect("Vec is not empty.");
+        let honest_max = *values.iter().max().expect("Vec is not empty.");
+
+        // Attacker manages to replace up to 'trim' values with extreme prices.
+        let manipulated = QuarterTrim::get().mul_floor(len as u32) as usize;
+        for i in 0..manipulated {
+            values[(rng.next() as usize) % len] = if i % 2 == 0 {
+                CurrencyAmount::from_u32(1_000_000)
+            } else {
+                CurrencyAmount::from_rational(1, 1_000_000)
+            };
+        }
+
+        // Allow for a tiny rounding error due to fixed point arithmetic.
+        let epsilon = CurrencyAmount::from_inner(1_000);
+        let trimmed_mean = TrimmedMeanAverage::<QuarterTrim>::calculate(&values);
+        assert!(
+            trimmed_mean.saturating_add(epsilon) >= honest_min
+                && trimmed_mean <= honest_max.saturating_add(epsilon),
+            "Trimmed mean {:?} must stay within honest range [{:?}, {:?}].",
+            trimmed_mean,
+            honest_min,
+            honest_max
+        );
+    }
+}
+
+#[test]
+fn exponential_moving_average_limits_single_value_manipulation() {
+    let mut rng = XorShift(0x1234_5678_9abc_def0);
+
+    for _ in 0..200 {
+        let len = (rng.next() % 15) as usize + 2;
+        let mut values: Vec<_> = (0..len).map(|_| rng.next_amount()).collect();
+        let honest_ema = ExponentialMovingAverage::<HalfAlpha>::calculate(&values);
+
+        // Manipulate a single, non-latest value, and verify its impact is bounded by its weight.
+        let index = (rng.next() as usize) % (len - 1);
+        let manipulated_value = CurrencyAmount::from_u32(1_000);
+        let delta = manipulated_value.saturating_sub(values[index]);
+        values[index] = manipulated_value;
+        let manipulated_ema = ExponentialMovingAverage::<HalfAlpha>::calculate(&values);
+
+        // Seed value is decayed by every subsequent value, other values are additionally scaled by alpha.
+        let decay_steps = if index == 0 { len - 1 } else { len - index };
+        let max_impact =
+            delta.saturating_mul(CurrencyAmount::from_rational(1, 1_u128 << decay_steps));
+        let epsilon = CurrencyAmount::from_inner(1_000);
+        assert!(
+            manipulated_ema.saturating_sub(honest_ema) <= max_impact.saturating_add(epsilon),
+            "Impact of a single manipulated value must be bounded."
+        );
+    }
+}
+
+#[test]
+fn circular_buffer_ordered_values_works() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 4;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+
+    let amounts: Vec<_> = (1..=BUFFER_SIZE as u128 * 2)
+        .map(|x| CurrencyAmount::from_rational(x, 10))
+        .collect();
+
+    // 1. Empty buffer check
+    let mut circular_buffer = CircularBuffer::<BufferSize>::default();
+    assert!(circular_buffer.ordered_values().is_empty());
+
+    // 2. Partially filled buffer is already ordered
+    circular_buffer.add(amounts[0]);
+    circular_buffer.add(amounts[1]);
+    assert_eq!(circular_buffer.ordered_values(), amounts[0..2].to_vec());
+
+    // 3. Fill up the buffer and keep adding, verify values are always in chronological order
+    for x in 2..amounts.len() {
+        circular_buffer.add(amounts[x]);
+
+        let start = (x + 1).saturating_sub(BUFFER_SIZE as usize);
+        assert_eq!(
+            circular_buffer.ordered_values(),
+            amounts[start..=x].to_vec()
+        );
+    }
+
+    // 4. Strategy is applied to the ordered values
+    assert_eq!(
+        circular_buffer.moving_average::<TimeWeightedAverage>(),
+        TimeWeightedAverage::calculate(&amounts[amounts.len() - BUFFER_SIZE as usize..])
+    );
+    assert_eq!(
+        circular_buffer.moving_average::<SimpleMovingAverage>(),
+        circular_buffer.average()
+    );
+}
+
 #[test]
 fn value_aggregator_basic_checks() {
     let limit_block = 10;
@@ -308,7 +627,7 @@ fn on_new_data_works_as_expected() {
     ExtBuilder::build().execute_with(|| {
         // 0. Initial sanity check
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).is_empty(),
             "Init state must be empty."
         );
 
@@ -318,7 +637,7 @@ fn on_new_data_works_as_expected() {
         let amount_1 = CurrencyAmount::from_rational(15, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_1);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
             vec![amount_1],
         );
 
@@ -340,7 +659,7 @@ fn on_new_data_works_as_expected() {
         let amount_2 = CurrencyAmount::from_rational(3, 10);
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_2);
         assert_eq!(
-            CurrentBlockValues::<Test>::get().into_inner(),
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
             vec![amount_1, amount_2],
         );
 
@@ -355,7 +674,10 @@ fn on_new_data_works_as_expected() {
         }
 
         assert_eq!(result.len(), limit as usize, "Sanity check.");
-        assert_eq!(CurrentBlockValues::<Test>::get().into_inner(), result);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            result
+        );
 
         // 5. Try to add one more value, overflowing the buffer, verify no state change
         assert_storage_noop!(PriceAggregator::on_new_data(
@@ -382,17 +704,19 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_finalize(block_number_1);
 
         assert!(
-            CurrentBlockValues::<Test>::get().is_empty(),
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).is_empty(),
             "Buffer must be empty after the finalization."
         );
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
         assert_eq!(intermediate_value_aggregator.count, 1);
 
         let average_amount_1 = CurrencyAmount::from_rational(15, 10);
         assert_eq!(intermediate_value_aggregator.total, average_amount_1);
 
         // 3. Move to the next block, but for this one no new data is added
-        let intermediate_value_snapshot = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_snapshot =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
 
         let block_number_2 = block_number_1 + 1;
         System::set_block_number(block_number_2);
@@ -401,7 +725,7 @@ fn on_finalize_updates_aggregated_data() {
         // No new data is added, everything must still work without breaking
         PriceAggregator::on_finalize(block_number_2);
         assert_eq!(
-            IntermediateValueAggregator::<Test>::get(),
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get()),
             intermediate_value_snapshot,
             "No new data was added, so the state must remain the same."
         );
@@ -416,7 +740,8 @@ fn on_finalize_updates_aggregated_data() {
         PriceAggregator::on_new_data(&dummy_account_1, &native_currency_id, &amount_3);
         PriceAggregator::on_finalize(block_number_3);
 
-        let intermediate_value_aggregator = IntermediateValueAggregator::<Test>::get();
+        let intermediate_value_aggregator =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
         assert_eq!(
             intermediate_value_aggregator.count, 2,
             "Count must be 2 since we added only 2 new values."
@@ -438,7 +763,8 @@ fn on_finalize_updates_circular_buffer() {
         // 1. Advance just until limit block is reached, checking appropriate storage items along the way
         let mut total = CurrencyAmount::zero();
         let current_block = System::block_number();
-        let limit_block = IntermediateValueAggregator::<Test>::get().limit_block;
+        let limit_block =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get()).limit_block;
 
         for block in current_block..limit_block {
             // Add new data
@@ -449,12 +775,14 @@ fn on_finalize_updates_circular_buffer() {
             // Finalize the block
             PriceAggregator::on_finalize(block);
             assert_eq!(
-                IntermediateValueAggregator::<Test>::get().total,
+                IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get()).total,
                 total,
                 "Check total is updated as expected."
             );
             assert!(
-                ValuesCircularBuffer::<Test>::get().buffer.is_empty(),
+                ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get())
+                    .buffer
+                    .is_empty(),
                 "Circular buffer is expected to remain empty until limit block is reached."
             );
 
@@ -472,7 +800,8 @@ fn on_finalize_updates_circular_buffer() {
         PriceAggregator::on_finalize(current_block);
 
         // Check that value aggregator is reset & new block limit is correct
-        let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+        let reset_intermediate_aggregator =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
         assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
         assert_eq!(reset_intermediate_aggregator.count, 0);
         assert_eq!(
@@ -481,7 +810,7 @@ fn on_finalize_updates_circular_buffer() {
         );
 
         // Check that circular buffer was updated as expected
-        let circular_buffer = ValuesCircularBuffer::<Test>::get();
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
         let expected_average = total * CurrencyAmount::from_rational(1, limit_block as u128 - 1);
         assert_eq!(
             circular_buffer.buffer.clone().into_inner(),
@@ -492,6 +821,7 @@ fn on_finalize_updates_circular_buffer() {
         // Verify deposited event
         System::assert_last_event(RuntimeEvent::PriceAggregator(
             Event::AverageAggregatedValue {
+                currency_id: NativeCurrencyId::get(),
                 value: expected_average,
             },
         ));
@@ -526,16 +856,18 @@ fn circular_buffer_really_is_circular() {
         // 1. Fill up the circular buffer
         for x in 0..circular_buffer_length {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
             assert_eq!(circular_buffer.buffer.len(), x as usize + 1);
             assert_eq!(circular_buffer.head, (x + 1) % circular_buffer_length);
 
             // Check that intermediate aggregator is reset & limit block is updated
-            let reset_intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let reset_intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
             assert_eq!(reset_intermediate_aggregator.total, CurrencyAmount::zero());
             assert_eq!(reset_intermediate_aggregator.count, 0);
             assert_eq!(
@@ -547,11 +879,12 @@ fn circular_buffer_really_is_circular() {
         // 2. Continue adding the data, verify circular buffer is updated as expected
         for x in 0..circular_buffer_length * 3 {
             // Advance until circular buffer is updated
-            let intermediate_aggregator = IntermediateValueAggregator::<Test>::get();
+            let intermediate_aggregator =
+                IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
             advance_to_block(intermediate_aggregator.limit_block + 1);
 
             // Check that circular buffer is updated as expected
-            let circular_buffer = ValuesCircularBuffer::<Test>::get();
+            let circular_buffer = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
             assert_eq!(
                 circular_buffer.buffer.len(),
                 circular_buffer_length as usize
@@ -560,3 +893,831 @@ fn circular_buffer_really_is_circular() {
         }
     })
 }
+
+#[test]
+fn average_price_uses_configured_strategy() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Initial sanity check
+        assert!(PriceAggregator::average_price().is_zero());
+
+        // 1. Fill up the circular buffer with some values
+        let amounts = vec![
+            CurrencyAmount::from_rational(11, 10),
+            CurrencyAmount::from_rational(17, 10),
+            CurrencyAmount::from_rational(5, 10),
+        ];
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            amounts.iter().for_each(|amount| buffer.add(*amount))
+        });
+
+        // 2. Verify the configured strategy is used
+        assert_eq!(
+            PriceAggregator::average_price(),
+            <<Test as Config>::MovingAverageStrategy as MovingAverageStrategy>::calculate(&amounts)
+        );
+    })
+}
+
+#[test]
+fn deviation_band_works() {
+    let reference = CurrencyAmount::from_rational(12, 10);
+
+    // 1. Band is symmetric in relative terms
+    let band = DeviationBand::new(reference, Perbill::from_percent(100));
+    assert_eq!(band.lower, CurrencyAmount::from_rational(6, 10));
+    assert_eq!(band.upper, CurrencyAmount::from_rational(24, 10));
+
+    let band = DeviationBand::new(reference, Perbill::from_percent(50));
+    assert_eq!(band.lower, CurrencyAmount::from_rational(8, 10));
+    assert_eq!(band.upper, CurrencyAmount::from_rational(18, 10));
+
+    // 2. Bounds are inclusive
+    assert!(band.contains(reference));
+    assert!(band.contains(band.lower));
+    assert!(band.contains(band.upper));
+    assert!(!band.contains(CurrencyAmount::from_rational(79, 100)));
+    assert!(!band.contains(CurrencyAmount::from_rational(181, 100)));
+
+    // 3. Clamping works
+    assert_eq!(band.clamp(reference), reference);
+    assert_eq!(band.clamp(CurrencyAmount::zero()), band.lower);
+    assert_eq!(band.clamp(CurrencyAmount::max_value()), band.upper);
+
+    // 4. Zero deviation allows only the reference value
+    let band = DeviationBand::new(reference, Perbill::zero());
+    assert_eq!(band.lower, reference);
+    assert_eq!(band.upper, reference);
+}
+
+#[test]
+fn circular_buffer_latest_works() {
+    // 0. Buffer size prep
+    const BUFFER_SIZE: u32 = 3;
+    struct BufferSize;
+    impl Get<u32> for BufferSize {
+        fn get() -> u32 {
+            BUFFER_SIZE
+        }
+    }
+
+    let mut circular_buffer = CircularBuffer::<BufferSize>::default();
+    assert!(circular_buffer.latest().is_none());
+
+    for x in 1..=BUFFER_SIZE as u128 * 3 {
+        let amount = CurrencyAmount::from_rational(x, 10);
+        circular_buffer.add(amount);
+        assert_eq!(circular_buffer.latest(), Some(amount));
+    }
+}
+
+#[test]
+fn on_new_data_clamps_deviating_values() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Prepare the reference price
+        let reference = CurrencyAmount::from_rational(12, 10);
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            buffer.add(reference)
+        });
+        let band = DeviationBand::new(reference, <Test as Config>::MaxBlockDeviation::get());
+
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        // 1. Value within the band is accepted as is
+        let amount_1 = CurrencyAmount::from_rational(15, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            vec![amount_1]
+        );
+
+        // 2. Too high value is clamped to the upper bound
+        let amount_2 = CurrencyAmount::from_u32(100);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_2);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            vec![amount_1, band.upper]
+        );
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::BlockValueClamped {
+            currency_id: NativeCurrencyId::get(),
+            value: amount_2,
+            clamped: band.upper,
+        }));
+
+        // 3. Too low value is clamped to the lower bound
+        let amount_3 = CurrencyAmount::from_rational(1, 100);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_3);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            vec![amount_1, band.upper, band.lower]
+        );
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::BlockValueClamped {
+            currency_id: NativeCurrencyId::get(),
+            value: amount_3,
+            clamped: band.lower,
+        }));
+    })
+}
+
+#[test]
+fn on_new_data_rejects_deviating_values() {
+    ExtBuilder::build().execute_with(|| {
+        MockDeviationAction::set(DeviationAction::Reject);
+
+        // 0. Prepare the reference price
+        let reference = CurrencyAmount::from_rational(12, 10);
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            buffer.add(reference)
+        });
+
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+
+        // 1. Value within the band is accepted as is
+        let amount_1 = CurrencyAmount::from_rational(9, 10);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount_1);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            vec![amount_1]
+        );
+
+        // 2. Values outside the band are rejected, and aren't tracked as submissions
+        let rejecting_account = 124;
+        for amount in [
+            CurrencyAmount::from_u32(100),
+            CurrencyAmount::from_rational(1, 100),
+        ] {
+            PriceAggregator::on_new_data(&rejecting_account, &native_currency_id, &amount);
+            assert!(LastSubmission::<Test>::get(native_currency_id, rejecting_account).is_none());
+            assert_eq!(
+                CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+                vec![amount_1]
+            );
+            System::assert_last_event(RuntimeEvent::PriceAggregator(Event::BlockValueRejected {
+                currency_id: NativeCurrencyId::get(),
+                value: amount,
+                reference,
+            }));
+        }
+    })
+}
+
+#[test]
+fn deviating_aggregated_value_is_clamped() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Prepare the reference price
+        let reference = CurrencyAmount::from_rational(12, 10);
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            buffer.add(reference)
+        });
+        let band = DeviationBand::new(reference, <Test as Config>::MaxAggregationDeviation::get());
+
+        // 1. Prepare the aggregated value that exceeds the upper bound
+        let aggregated_value = CurrencyAmount::from_rational(2, 1);
+        IntermediateValueAggregator::<Test>::insert(
+            NativeCurrencyId::get(),
+            ValueAggregator {
+                total: aggregated_value,
+                count: 1,
+                limit_block: System::block_number(),
+            },
+        );
+
+        // 2. Process the intermediate value, expect the clamped value to be pushed into the buffer
+        PriceAggregator::process_intermediate_aggregated_values(
+            NativeCurrencyId::get(),
+            System::block_number(),
+        );
+
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
+        assert_eq!(
+            circular_buffer.buffer.into_inner(),
+            vec![reference, band.upper]
+        );
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AggregatedValueClamped {
+                currency_id: NativeCurrencyId::get(),
+                value: aggregated_value,
+                clamped: band.upper,
+            },
+        ));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency_id: NativeCurrencyId::get(),
+                value: band.upper,
+            },
+        ));
+    })
+}
+
+#[test]
+fn deviating_aggregated_value_is_rejected() {
+    ExtBuilder::build().execute_with(|| {
+        MockDeviationAction::set(DeviationAction::Reject);
+
+        // 0. Prepare the reference price
+        let reference = CurrencyAmount::from_rational(12, 10);
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            buffer.add(reference)
+        });
+
+        // 1. Prepare the aggregated value that exceeds the lower bound
+        let aggregated_value = CurrencyAmount::from_rational(5, 10);
+        let limit_block = System::block_number();
+        IntermediateValueAggregator::<Test>::insert(
+            NativeCurrencyId::get(),
+            ValueAggregator {
+                total: aggregated_value,
+                count: 1,
+                limit_block,
+            },
+        );
+
+        // 2. Process the intermediate value, expect the buffer to remain unchanged
+        let buffer_snapshot = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
+        PriceAggregator::process_intermediate_aggregated_values(
+            NativeCurrencyId::get(),
+            limit_block,
+        );
+
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get()),
+            buffer_snapshot
+        );
+        System::assert_last_event(RuntimeEvent::PriceAggregator(
+            Event::AggregatedValueRejected {
+                currency_id: NativeCurrencyId::get(),
+                value: aggregated_value,
+                reference,
+            },
+        ));
+
+        // 3. Intermediate aggregator is still reset
+        let reset_intermediate_aggregator =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get());
+        assert!(reset_intermediate_aggregator.count.is_zero());
+        assert_eq!(
+            reset_intermediate_aggregator.limit_block,
+            limit_block + <Test as Config>::AggregationDuration::get()
+        );
+    })
+}
+
+#[test]
+fn reset_circular_buffer_works() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Prepare some state
+        let dummy_account = 123;
+        let native_currency_id = <Test as Config>::NativeCurrencyId::get();
+        ValuesCircularBuffer::<Test>::mutate(NativeCurrencyId::get(), |buffer| {
+            buffer.add(CurrencyAmount::from_rational(12, 10))
+        });
+        PriceAggregator::on_new_data(
+            &dummy_account,
+            &native_currency_id,
+            &CurrencyAmount::from_rational(13, 10),
+        );
+        IntermediateValueAggregator::<Test>::mutate(NativeCurrencyId::get(), |aggregator| {
+            aggregator.total = CurrencyAmount::from_rational(11, 10);
+            aggregator.count = 1;
+        });
+        let limit_block =
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get()).limit_block;
+
+        // 1. Reset the buffer, verify state is as expected
+        let values: BoundedVec<_, _> = vec![
+            CurrencyAmount::from_rational(5, 1),
+            CurrencyAmount::from_rational(6, 1),
+        ]
+        .try_into()
+        .expect("Must work since size is within the bound.");
+        assert_ok!(PriceAggregator::reset_circular_buffer(
+            RuntimeOrigin::root(),
+            NativeCurrencyId::get(),
+            values.clone()
+        ));
+
+        let circular_buffer = ValuesCircularBuffer::<Test>::get(NativeCurrencyId::get());
+        assert_eq!(circular_buffer.buffer, values);
+        assert_eq!(circular_buffer.head, values.len() as u32);
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(NativeCurrencyId::get()),
+            ValueAggregator::new(limit_block)
+        );
+        assert!(CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).is_empty());
+        assert_eq!(
+            PriceAggregator::average_price(),
+            CurrencyAmount::from_rational(55, 10)
+        );
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::CircularBufferReset {
+            currency_id: NativeCurrencyId::get(),
+            values,
+        }));
+
+        // 2. New values now act as the reference price
+        let amount = CurrencyAmount::from_rational(7, 1);
+        PriceAggregator::on_new_data(&dummy_account, &native_currency_id, &amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).into_inner(),
+            vec![amount]
+        );
+    })
+}
+
+#[test]
+fn reset_circular_buffer_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let values: BoundedVec<_, _> = vec![CurrencyAmount::from_rational(5, 1)]
+            .try_into()
+            .expect("Must work since size is within the bound.");
+
+        // 1. Non-privileged origin
+        assert_noop!(
+            PriceAggregator::reset_circular_buffer(
+                RuntimeOrigin::signed(1),
+                NativeCurrencyId::get(),
+                values
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // 2. Empty buffer
+        assert_noop!(
+            PriceAggregator::reset_circular_buffer(
+                RuntimeOrigin::root(),
+                NativeCurrencyId::get(),
+                Default::default()
+            ),
+            Error::<Test>::EmptyCircularBuffer
+        );
+
+        // 3. Untracked currency
+        assert_noop!(
+            PriceAggregator::reset_circular_buffer(
+                RuntimeOrigin::root(),
+                CurrencyId::SDN,
+                values.clone()
+            ),
+            Error::<Test>::CurrencyNotTracked
+        );
+
+        // 4. Zero value
+        let values: BoundedVec<_, _> =
+            vec![CurrencyAmount::from_rational(5, 1), CurrencyAmount::zero()]
+                .try_into()
+                .expect("Must work since size is within the bound.");
+        assert_noop!(
+            PriceAggregator::reset_circular_buffer(
+                RuntimeOrigin::root(),
+                NativeCurrencyId::get(),
+                values
+            ),
+            Error::<Test>::ZeroValue
+        );
+    })
+}
+
+#[test]
+fn register_currency_works() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Initial sanity check
+        let currency_id = CurrencyId::SDN;
+        assert_eq!(
+            PriceAggregator::tracked_currencies(),
+            vec![NativeCurrencyId::get()]
+        );
+        assert!(!PriceAggregator::is_tracked(&currency_id));
+        assert!(PriceAggregator::average_price_of(currency_id).is_none());
+
+        // 1. Register a new currency, verify state is as expected
+        let values: BoundedVec<_, _> = vec![
+            CurrencyAmount::from_rational(3, 10),
+            CurrencyAmount::from_rational(5, 10),
+        ]
+        .try_into()
+        .expect("Must work since size is within the bound.");
+        assert_ok!(PriceAggregator::register_currency(
+            RuntimeOrigin::root(),
+            currency_id,
+            values.clone()
+        ));
+
+        assert_eq!(
+            PriceAggregator::tracked_currencies(),
+            vec![NativeCurrencyId::get(), currency_id]
+        );
+        assert!(PriceAggregator::is_tracked(&currency_id));
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(currency_id).buffer,
+            values
+        );
+        assert_eq!(
+            IntermediateValueAggregator::<Test>::get(currency_id),
+            ValueAggregator::new(
+                System::block_number() + <Test as Config>::AggregationDuration::get()
+            )
+        );
+        assert_eq!(
+            PriceAggregator::average_price_of(currency_id),
+            Some(CurrencyAmount::from_rational(4, 10))
+        );
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::CurrencyRegistered {
+            currency_id,
+        }));
+
+        // 2. New data for the registered currency is accepted
+        let amount = CurrencyAmount::from_rational(6, 10);
+        PriceAggregator::on_new_data(&123, &currency_id, &amount);
+        assert_eq!(
+            CurrentBlockValues::<Test>::get(currency_id).into_inner(),
+            vec![amount]
+        );
+        assert!(CurrentBlockValues::<Test>::get(NativeCurrencyId::get()).is_empty());
+    })
+}
+
+#[test]
+fn register_currency_fails() {
+    ExtBuilder::build().execute_with(|| {
+        let values: BoundedVec<_, _> = vec![CurrencyAmount::from_rational(5, 1)]
+            .try_into()
+            .expect("Must work since size is within the bound.");
+
+        // 1. Non-privileged origin
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::signed(1),
+                CurrencyId::SDN,
+                values.clone()
+            ),
+            DispatchError::BadOrigin
+        );
+
+        // 2. Native currency is already tracked
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                NativeCurrencyId::get(),
+                values.clone()
+            ),
+            Error::<Test>::CurrencyAlreadyTracked
+        );
+
+        // 3. Invalid initial values
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::SDN,
+                Default::default()
+            ),
+            Error::<Test>::EmptyCircularBuffer
+        );
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::SDN,
+                vec![CurrencyAmount::zero()].try_into().unwrap()
+            ),
+            Error::<Test>::ZeroValue
+        );
+
+        // 4. Already registered currency
+        assert_ok!(PriceAggregator::register_currency(
+            RuntimeOrigin::root(),
+            CurrencyId::SDN,
+            values.clone()
+        ));
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::SDN,
+                values.clone()
+            ),
+            Error::<Test>::CurrencyAlreadyTracked
+        );
+
+        // 5. Too many tracked currencies
+        let limit = <Test as Config>::MaxTrackedCurrencies::get();
+        for x in 1..limit {
+            assert_ok!(PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::Asset(x.into()),
+                values.clone()
+            ));
+        }
+        assert_eq!(TrackedCurrencies::<Test>::get().len(), limit as usize);
+        assert_noop!(
+            PriceAggregator::register_currency(
+                RuntimeOrigin::root(),
+                CurrencyId::DOT,
+                values.clone()
+            ),
+            Error::<Test>::TooManyTrackedCurrencies
+        );
+    })
+}
+
+#[test]
+fn unregister_currency_works() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Register a currency and add some data
+        let currency_id = CurrencyId::DOT;
+        assert_ok!(PriceAggregator::register_currency(
+            RuntimeOrigin::root(),
+            currency_id,
+            vec![CurrencyAmount::from_rational(5, 1)]
+                .try_into()
+                .unwrap()
+        ));
+        PriceAggregator::on_new_data(&123, &currency_id, &CurrencyAmount::from_rational(6, 1));
+        assert!(LastSubmission::<Test>::contains_key(currency_id, 123));
+
+        // 1. Unregister the currency, verify state is as expected
+        assert_ok!(PriceAggregator::unregister_currency(
+            RuntimeOrigin::root(),
+            currency_id
+        ));
+
+        assert!(!PriceAggregator::is_tracked(&currency_id));
+        assert!(!ValuesCircularBuffer::<Test>::contains_key(currency_id));
+        assert!(!IntermediateValueAggregator::<Test>::contains_key(
+            currency_id
+        ));
+        assert!(!CurrentBlockValues::<Test>::contains_key(currency_id));
+        assert!(!LastSubmission::<Test>::contains_key(currency_id, 123));
+        assert!(PriceAggregator::average_price_of(currency_id).is_none());
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::CurrencyUnregistered {
+            currency_id,
+        }));
+
+        // 2. New data for the unregistered currency is ignored
+        assert_storage_noop!(PriceAggregator::on_new_data(
+            &123,
+            &currency_id,
+            &CurrencyAmount::from_rational(6, 1)
+        ));
+    })
+}
+
+#[test]
+fn unregister_currency_fails() {
+    ExtBuilder::build().execute_with(|| {
+        // 1. Non-privileged origin
+        assert_noop!(
+            PriceAggregator::unregister_currency(RuntimeOrigin::signed(1), CurrencyId::SDN),
+            DispatchError::BadOrigin
+        );
+
+        // 2. Native currency cannot be unregistered
+        assert_noop!(
+            PriceAggregator::unregister_currency(RuntimeOrigin::root(), NativeCurrencyId::get()),
+            Error::<Test>::NativeCurrencyCannotBeUnregistered
+        );
+
+        // 3. Untracked currency
+        assert_noop!(
+            PriceAggregator::unregister_currency(RuntimeOrigin::root(), CurrencyId::SDN),
+            Error::<Test>::CurrencyNotTracked
+        );
+    })
+}
+
+#[test]
+fn currencies_are_aggregated_independently() {
+    ExtBuilder::build().execute_with(|| {
+        // 0. Register an additional currency, aligning its limit block with the native currency one
+        let native_currency_id = NativeCurrencyId::get();
+        let currency_id = CurrencyId::USDT;
+        let init_value = CurrencyAmount::from_rational(1, 1);
+        assert_ok!(PriceAggregator::register_currency(
+            RuntimeOrigin::root(),
+            currency_id,
+            vec![init_value].try_into().unwrap()
+        ));
+        let limit_block = IntermediateValueAggregator::<Test>::get(native_currency_id).limit_block;
+        IntermediateValueAggregator::<Test>::insert(currency_id, ValueAggregator::new(limit_block));
+
+        // 1. Feed different values for each currency until the limit block is reached
+        let native_amount = CurrencyAmount::from_rational(15, 10);
+        let other_amount = CurrencyAmount::from_rational(11, 10);
+        let init_block = System::block_number();
+        for block in init_block..=limit_block {
+            PriceAggregator::on_new_data(&123, &native_currency_id, &native_amount);
+            PriceAggregator::on_new_data(&123, &currency_id, &other_amount);
+
+            PriceAggregator::on_finalize(block);
+
+            let new_block = block + 1;
+            System::set_block_number(new_block);
+            PriceAggregator::on_initialize(new_block);
+        }
+
+        // 2. Verify each currency has its own circular buffer
+        let count = limit_block - init_block + 1;
+        let expected_average = |amount: CurrencyAmount| {
+            ValueAggregator {
+                total: amount * CurrencyAmount::from_u32(count),
+                count,
+                limit_block,
+            }
+            .average()
+        };
+        let expected_native_average = expected_average(native_amount);
+        let expected_other_average = expected_average(other_amount);
+
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(native_currency_id)
+                .buffer
+                .into_inner(),
+            vec![expected_native_average]
+        );
+        assert_eq!(
+            ValuesCircularBuffer::<Test>::get(currency_id)
+                .buffer
+                .into_inner(),
+            vec![init_value, expected_other_average]
+        );
+        assert_eq!(PriceAggregator::average_price(), expected_native_average);
+        assert_eq!(
+            PriceAggregator::average_price_of(native_currency_id),
+            Some(expected_native_average)
+        );
+        assert_eq!(
+            PriceAggregator::average_price_of(currency_id),
+            Some(SimpleMovingAverage::calculate(&[
+                init_value,
+                expected_other_average
+            ]))
+        );
+        System::assert_has_event(RuntimeEvent::PriceAggregator(
+            Event::AverageAggregatedValue {
+                currency_id,
+                value: expected_other_average,
+            },
+        ));
+    })
+}
+
+/// Finalizes the current block, and initializes the next one, without submitting any data.
+fn advance_blocks(blocks: u32) {
+    for _ in 0..blocks {
+        let block = System::block_number();
+        PriceAggregator::on_finalize(block);
+
+        System::set_block_number(block + 1);
+        PriceAggregator::on_initialize(block + 1);
+    }
+}
+
+#[test]
+fn last_submission_is_tracked() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+        let amount = CurrencyAmount::from_rational(15, 10);
+        let (account_1, account_2) = (1, 2);
+
+        // 1. Submit value from the first account
+        PriceAggregator::on_new_data(&account_1, &native_currency_id, &amount);
+        let submission_block_1 = System::block_number();
+        assert_eq!(
+            LastSubmission::<Test>::get(native_currency_id, account_1),
+            Some(submission_block_1)
+        );
+        assert!(LastSubmission::<Test>::get(native_currency_id, account_2).is_none());
+
+        // 2. Advance few blocks, and submit value from the second account
+        advance_blocks(3);
+        PriceAggregator::on_new_data(&account_2, &native_currency_id, &amount);
+        assert_eq!(
+            LastSubmission::<Test>::get(native_currency_id, account_1),
+            Some(submission_block_1)
+        );
+        assert_eq!(
+            LastSubmission::<Test>::get(native_currency_id, account_2),
+            Some(System::block_number())
+        );
+
+        // 3. Submission for an untracked currency is ignored
+        advance_blocks(1);
+        assert_storage_noop!(PriceAggregator::on_new_data(
+            &account_1,
+            &CurrencyId::SDN,
+            &amount
+        ));
+    })
+}
+
+#[test]
+fn last_submission_is_cleared_for_removed_data_feeders() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+        let currency_id = CurrencyId::DOT;
+        assert_ok!(PriceAggregator::register_currency(
+            RuntimeOrigin::root(),
+            currency_id,
+            vec![CurrencyAmount::from_rational(15, 10)]
+                .try_into()
+                .unwrap()
+        ));
+        let amount = CurrencyAmount::from_rational(15, 10);
+        let (account_1, account_2) = (1, 2);
+
+        // 1. Both accounts submit values for both currencies
+        for who in [account_1, account_2] {
+            for currency in [native_currency_id, currency_id] {
+                PriceAggregator::on_new_data(&who, &currency, &amount);
+                assert!(LastSubmission::<Test>::contains_key(currency, who));
+            }
+        }
+
+        // 2. Remove the first account from the data feeders, only its submissions are cleared
+        <PriceAggregator as ChangeMembers<u64>>::change_members_sorted(
+            &[],
+            &[account_1],
+            &[account_2],
+        );
+        for currency in [native_currency_id, currency_id] {
+            assert!(!LastSubmission::<Test>::contains_key(currency, account_1));
+            assert!(LastSubmission::<Test>::contains_key(currency, account_2));
+        }
+    })
+}
+
+#[test]
+fn price_feed_staleness_is_detected() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+        let threshold: u32 = <Test as Config>::StalenessThreshold::get();
+
+        // 1. Advance just below the threshold, price feed isn't stale yet
+        advance_blocks(threshold - 1);
+        assert_eq!(
+            BlocksWithoutValidValue::<Test>::get(native_currency_id),
+            threshold - 1
+        );
+        assert!(!PriceAggregator::is_stale(native_currency_id));
+
+        // 2. Reach the threshold, expect the event
+        advance_blocks(1);
+        assert!(PriceAggregator::is_stale(native_currency_id));
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::PriceFeedStale {
+            currency_id: native_currency_id,
+            blocks: threshold,
+        }));
+
+        // 3. Keep advancing, expect no additional events
+        let events_len = System::events().len();
+        advance_blocks(3);
+        assert_eq!(System::events().len(), events_len);
+        assert_eq!(
+            BlocksWithoutValidValue::<Test>::get(native_currency_id),
+            threshold + 3
+        );
+
+        // 4. Submit a valid value, expect the price feed to resume
+        PriceAggregator::on_new_data(
+            &1,
+            &native_currency_id,
+            &CurrencyAmount::from_rational(15, 10),
+        );
+        advance_blocks(1);
+        assert!(!PriceAggregator::is_stale(native_currency_id));
+        assert!(BlocksWithoutValidValue::<Test>::get(native_currency_id).is_zero());
+        System::assert_last_event(RuntimeEvent::PriceAggregator(Event::PriceFeedResumed {
+            currency_id: native_currency_id,
+            stale_blocks: threshold + 3,
+        }));
+    })
+}
+
+#[test]
+fn fallback_price_is_used_when_price_feed_is_stale() {
+    ExtBuilder::build().execute_with(|| {
+        let native_currency_id = NativeCurrencyId::get();
+        let threshold: u32 = <Test as Config>::FallbackThreshold::get();
+
+        // 0. Prepare the moving average
+        let amount = CurrencyAmount::from_rational(15, 10);
+        ValuesCircularBuffer::<Test>::mutate(native_currency_id, |buffer| buffer.add(amount));
+        assert!(amount != FallbackPriceValue::get(), "Sanity check.");
+
+        // 1. Advance just below the threshold, moving average is still used
+        advance_blocks(threshold - 1);
+        assert_eq!(PriceAggregator::average_price(), amount);
+
+        // 2. Reach the threshold, fallback price is used
+        advance_blocks(1);
+        assert_eq!(PriceAggregator::average_price(), FallbackPriceValue::get());
+
+        // 3. Fallback isn't used for other currencies
+        assert_eq!(
+            PriceAggregator::average_price_of(native_currency_id),
+            Some(amount)
+        );
+
+        // 4. Submit a valid value, expect the moving average to be used again
+        PriceAggregator::on_new_data(&1, &native_currency_id, &amount);
+        advance_blocks(1);
+        assert_eq!(PriceAggregator::average_price(), amount);
+    })
+}
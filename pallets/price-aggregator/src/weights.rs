@@ -51,6 +51,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn process_block_aggregated_values() -> Weight;
 	fn process_intermediate_aggregated_values() -> Weight;
+	fn reset_circular_buffer() -> Weight;
+	fn register_currency() -> Weight;
+	fn unregister_currency(f: u32, ) -> Weight;
 }
 
 /// Weights for pallet_price_aggregator using the Substrate node and recommended hardware.
@@ -64,7 +67,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(3_520_000, 0)
 	}
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `102`
@@ -74,6 +77,59 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:1 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	fn reset_circular_buffer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1512`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_593_000, 1512)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(137), added: 632, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn register_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `1622`
+		// Minimum execution time: 13_415_000 picoseconds.
+		Weight::from_parts(13_872_000, 1622)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(137), added: 632, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::LastSubmission` (r:0 w:16)
+	/// Proof: `PriceAggregator::LastSubmission` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `f` is `[0, 16]`.
+	fn unregister_currency(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158 + f * (70 ±0)`
+		//  Estimated: `1622`
+		// Minimum execution time: 14_081_000 picoseconds.
+		Weight::from_parts(14_530_000, 1622)
+			// Standard Error: 2_117
+			.saturating_add(Weight::from_parts(1_236_402, 0).saturating_mul(f.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+	}
 }
 
 // For backwards compatibility and tests
@@ -86,7 +142,7 @@ impl WeightInfo for () {
 		Weight::from_parts(3_520_000, 0)
 	}
 	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:1 w:1)
-	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: Some(1), `max_size`: Some(117), added: 612, mode: `MaxEncodedLen`)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
 	fn process_intermediate_aggregated_values() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `102`
@@ -96,4 +152,57 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:1 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	fn reset_circular_buffer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1512`
+		// Minimum execution time: 11_204_000 picoseconds.
+		Weight::from_parts(11_593_000, 1512)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(137), added: 632, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn register_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `118`
+		//  Estimated: `1622`
+		// Minimum execution time: 13_415_000 picoseconds.
+		Weight::from_parts(13_872_000, 1622)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `PriceAggregator::TrackedCurrencies` (r:1 w:1)
+	/// Proof: `PriceAggregator::TrackedCurrencies` (`max_values`: Some(1), `max_size`: Some(137), added: 632, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::ValuesCircularBuffer` (r:0 w:1)
+	/// Proof: `PriceAggregator::ValuesCircularBuffer` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::IntermediateValueAggregator` (r:0 w:1)
+	/// Proof: `PriceAggregator::IntermediateValueAggregator` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::CurrentBlockValues` (r:0 w:1)
+	/// Proof: `PriceAggregator::CurrentBlockValues` (`max_values`: None, `max_size`: Some(154), added: 2629, mode: `MaxEncodedLen`)
+	/// Storage: `PriceAggregator::LastSubmission` (r:0 w:16)
+	/// Proof: `PriceAggregator::LastSubmission` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// The range of component `f` is `[0, 16]`.
+	fn unregister_currency(f: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `158 + f * (70 ±0)`
+		//  Estimated: `1622`
+		// Minimum execution time: 14_081_000 picoseconds.
+		Weight::from_parts(14_530_000, 1622)
+			// Standard Error: 2_117
+			.saturating_add(Weight::from_parts(1_236_402, 0).saturating_mul(f.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+	}
 }
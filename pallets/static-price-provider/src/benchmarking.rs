@@ -0,0 +1,127 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+use frame_system::pallet_prelude::*;
+
+/// Fill the scheduled changes queue, leaving `free` slots unused.
+fn fill_scheduled_changes<T: Config>(free: u32)
+where
+    BlockNumberFor<T>: From<u32>,
+{
+    let changes: Vec<_> = (0..T::MaxScheduledChanges::get().saturating_sub(free))
+        .map(|idx| ((100 + idx).into(), FixedU128::from(2)))
+        .collect();
+    ScheduledChanges::<T>::put(BoundedVec::try_from(changes).expect("Within bounds; QED"));
+}
+
+/// Price ramp which starts in the future.
+fn price_ramp<T: Config>() -> PriceRamp<BlockNumberFor<T>>
+where
+    BlockNumberFor<T>: From<u32>,
+{
+    PriceRamp {
+        start: 10.into(),
+        end: 20.into(),
+        start_price: FixedU128::from(2),
+        end_price: FixedU128::from(3),
+    }
+}
+
+#[benchmarks(
+    where
+        BlockNumberFor<T>: From<u32>
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn schedule_price_change() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        // Worst case is inserting at the front of an almost full queue.
+        fill_scheduled_changes::<T>(1);
+        let at: BlockNumberFor<T> = 10.into();
+        let price = FixedU128::from(3);
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, at, price);
+
+        let changes = ScheduledChanges::<T>::get();
+        assert_eq!(changes.len() as u32, T::MaxScheduledChanges::get());
+        assert_eq!(changes[0], (at, price));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_price_ramp() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let ramp = price_ramp::<T>();
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            ramp.start,
+            ramp.end,
+            ramp.start_price,
+            ramp.end_price,
+        );
+
+        assert_eq!(ActiveRamp::<T>::get(), Some(ramp));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_scheduled_changes() -> Result<(), BenchmarkError> {
+        let origin =
+            T::GovernanceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        fill_scheduled_changes::<T>(0);
+        ActiveRamp::<T>::put(price_ramp::<T>());
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert!(ScheduledChanges::<T>::get().is_empty());
+        assert!(ActiveRamp::<T>::get().is_none());
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::benchmarking::tests::new_test_ext(),
+        crate::mock::Test,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use sp_io::TestExternalities;
+
+    pub fn new_test_ext() -> TestExternalities {
+        mock::ExternalityBuilder::build()
+    }
+}
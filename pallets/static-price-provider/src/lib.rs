@@ -27,14 +27,29 @@
 //! Only the root can set the price.
 //!
 //! Network maintainers must ensure to update the price at appropriate times so that inflation & dApp Staking rewards are calculated correctly.
+//!
+//! ## Scheduled Price Changes
+//!
+//! Instead of setting the price immediately, [`GovernanceOrigin`](`Config::GovernanceOrigin`) can schedule the price changes upfront:
+//! * `schedule_price_change`: queues a price to be set at the given block, up to [`MaxScheduledChanges`](`Config::MaxScheduledChanges`) changes.
+//! * `schedule_price_ramp`: linearly changes the price between two prices over the given block range, one ramp at a time.
+//! * `cancel_scheduled_changes`: removes all the queued changes & the ramp.
+//!
+//! Scheduled changes are applied in `on_initialize`. While a ramp is ongoing, from its start block up to & including its end block,
+//! it takes precedence over the queued changes. Changes queued for the blocks after the ramp's end are applied as usual.
+//! [`Pallet::price_at`] follows the same order & can be used to see the price which will be active at a future block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
 use frame_system::{ensure_root, pallet_prelude::*};
 pub use pallet::*;
-use sp_arithmetic::{fixed_point::FixedU128, traits::Zero};
-use sp_std::marker::PhantomData;
+use sp_arithmetic::{
+    fixed_point::FixedU128,
+    traits::{AtLeast32BitUnsigned, SaturatedConversion, Saturating, Zero},
+    FixedPointNumber,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
 use astar_primitives::oracle::PriceProvider;
 
@@ -43,6 +58,12 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
 #[frame_support::pallet]
 pub mod pallet {
 
@@ -59,6 +80,16 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin allowed to schedule & cancel the price changes.
+        type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// Maximum number of queued price changes.
+        #[pallet::constant]
+        type MaxScheduledChanges: Get<u32>;
+
+        /// Weight information for extrinsics & functions of this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::event]
@@ -66,12 +97,29 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// New static native currency price has been set.
         PriceSet { price: FixedU128 },
+        /// Price change has been scheduled for the given block.
+        PriceChangeScheduled {
+            at: BlockNumberFor<T>,
+            price: FixedU128,
+        },
+        /// Linear price ramp has been scheduled.
+        PriceRampScheduled { ramp: PriceRamp<BlockNumberFor<T>> },
+        /// Price ramp has finished, its final price is active.
+        PriceRampFinished { price: FixedU128 },
+        /// All the scheduled price changes have been cancelled.
+        ScheduledChangesCancelled,
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Zero is invalid value for the price (hopefully).
         ZeroPrice,
+        /// Price change can only be scheduled for a future block.
+        BlockInPast,
+        /// Maximum number of queued price changes has been reached.
+        TooManyScheduledChanges,
+        /// Ramp must end after it starts.
+        InvalidRampPeriod,
     }
 
     /// Default value handler for active price.
@@ -88,6 +136,59 @@ pub mod pallet {
     #[pallet::whitelist_storage]
     pub type ActivePrice<T: Config> = StorageValue<_, FixedU128, ValueQuery, DefaultActivePrice>;
 
+    /// Queued price changes, sorted by the block at which they are applied.
+    #[pallet::storage]
+    pub type ScheduledChanges<T: Config> = StorageValue<
+        _,
+        BoundedVec<(BlockNumberFor<T>, FixedU128), T::MaxScheduledChanges>,
+        ValueQuery,
+    >;
+
+    /// Scheduled or ongoing linear price ramp.
+    #[pallet::storage]
+    pub type ActiveRamp<T: Config> = StorageValue<_, PriceRamp<BlockNumberFor<T>>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut writes = 0;
+
+            // Changes which are due are consumed, even if they are superseded by the ongoing ramp.
+            let mut changes = ScheduledChanges::<T>::get();
+            let due = changes.iter().take_while(|(at, _)| *at <= now).count();
+            let queued_price = if due > 0 {
+                let price = changes[due - 1].1;
+                changes.retain(|(at, _)| *at > now);
+                ScheduledChanges::<T>::put(changes);
+                writes += 1;
+                Some(price)
+            } else {
+                None
+            };
+
+            if let Some(ramp) = ActiveRamp::<T>::get().filter(|ramp| ramp.start <= now) {
+                // Ongoing ramp takes precedence over the queued changes.
+                // Only the final price of the ramp is announced, not each intermediate step.
+                ActivePrice::<T>::put(ramp.price_at(now));
+                writes += 1;
+
+                if ramp.end <= now {
+                    ActiveRamp::<T>::kill();
+                    Self::deposit_event(Event::<T>::PriceRampFinished {
+                        price: ramp.end_price,
+                    });
+                    writes += 1;
+                }
+            } else if let Some(price) = queued_price {
+                ActivePrice::<T>::put(price);
+                Self::deposit_event(Event::<T>::PriceSet { price });
+                writes += 1;
+            }
+
+            T::DbWeight::get().reads_writes(2, writes)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Privileged action used to set the active native currency price.
@@ -105,6 +206,115 @@ pub mod pallet {
 
             Ok(().into())
         }
+
+        /// Schedule the active native currency price to be set at the given future block.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::schedule_price_change())]
+        pub fn schedule_price_change(
+            origin: OriginFor<T>,
+            at: BlockNumberFor<T>,
+            price: FixedU128,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(!price.is_zero(), Error::<T>::ZeroPrice);
+            ensure!(
+                at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::BlockInPast
+            );
+
+            ScheduledChanges::<T>::try_mutate(|changes| {
+                // keep the changes sorted, changes for the same block are applied in order
+                let index = changes.partition_point(|(block, _)| *block <= at);
+                changes
+                    .try_insert(index, (at, price))
+                    .map_err(|_| Error::<T>::TooManyScheduledChanges)
+            })?;
+
+            Self::deposit_event(Event::<T>::PriceChangeScheduled { at, price });
+
+            Ok(())
+        }
+
+        /// Schedule a linear price change from `start_price` at block `start` to `end_price` at block `end`.
+        ///
+        /// Replaces the previously scheduled ramp, if any.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::schedule_price_ramp())]
+        pub fn schedule_price_ramp(
+            origin: OriginFor<T>,
+            start: BlockNumberFor<T>,
+            end: BlockNumberFor<T>,
+            start_price: FixedU128,
+            end_price: FixedU128,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(
+                !start_price.is_zero() && !end_price.is_zero(),
+                Error::<T>::ZeroPrice
+            );
+            ensure!(
+                start > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::BlockInPast
+            );
+            ensure!(end > start, Error::<T>::InvalidRampPeriod);
+
+            let ramp = PriceRamp {
+                start,
+                end,
+                start_price,
+                end_price,
+            };
+            ActiveRamp::<T>::put(ramp.clone());
+
+            Self::deposit_event(Event::<T>::PriceRampScheduled { ramp });
+
+            Ok(())
+        }
+
+        /// Cancel all the queued price changes & the price ramp.
+        ///
+        /// Price which is already active is kept.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::cancel_scheduled_changes())]
+        pub fn cancel_scheduled_changes(origin: OriginFor<T>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+
+            ScheduledChanges::<T>::kill();
+            ActiveRamp::<T>::kill();
+
+            Self::deposit_event(Event::<T>::ScheduledChangesCancelled);
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Price which will be active at the given block, according to the scheduled changes.
+        ///
+        /// For past blocks, the current active price is returned.
+        pub fn price_at(block: BlockNumberFor<T>) -> FixedU128 {
+            if block <= frame_system::Pallet::<T>::block_number() {
+                return ActivePrice::<T>::get();
+            }
+
+            let changes = ScheduledChanges::<T>::get();
+            match ActiveRamp::<T>::get().filter(|ramp| ramp.start <= block) {
+                // Ongoing ramp takes precedence over the queued changes.
+                Some(ramp) if block <= ramp.end => ramp.price_at(block),
+                // Once the ramp is finished, only the changes queued after its end apply.
+                Some(ramp) => changes
+                    .into_iter()
+                    .filter(|(at, _)| *at > ramp.end)
+                    .take_while(|(at, _)| *at <= block)
+                    .last()
+                    .map_or(ramp.end_price, |(_, price)| price),
+                None => changes
+                    .into_iter()
+                    .take_while(|(at, _)| *at <= block)
+                    .last()
+                    .map_or_else(ActivePrice::<T>::get, |(_, price)| price),
+            }
+        }
     }
 
     impl<T: Config> PriceProvider for Pallet<T> {
@@ -113,3 +323,37 @@ pub mod pallet {
         }
     }
 }
+
+/// Linear change of the price over a block range.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub struct PriceRamp<BlockNumber> {
+    /// Block at which the ramp starts, with `start_price` being active.
+    pub start: BlockNumber,
+    /// Block at which the ramp ends, with `end_price` being active.
+    pub end: BlockNumber,
+    /// Price at the start of the ramp.
+    pub start_price: FixedU128,
+    /// Price at the end of the ramp.
+    pub end_price: FixedU128,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> PriceRamp<BlockNumber> {
+    /// Price of the ramp at the given block, clamped to the ramp's range.
+    pub fn price_at(&self, block: BlockNumber) -> FixedU128 {
+        let block = block.clamp(self.start, self.end);
+        let elapsed: u128 = block.saturating_sub(self.start).saturated_into();
+        let duration: u128 = self.end.saturating_sub(self.start).saturated_into();
+        if duration.is_zero() {
+            return self.end_price;
+        }
+        let progress = FixedU128::saturating_from_rational(elapsed, duration);
+
+        if self.end_price >= self.start_price {
+            self.start_price
+                .saturating_add(progress.saturating_mul(self.end_price - self.start_price))
+        } else {
+            self.start_price
+                .saturating_sub(progress.saturating_mul(self.start_price - self.end_price))
+        }
+    }
+}
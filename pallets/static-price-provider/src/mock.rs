@@ -19,8 +19,11 @@
 use crate::{self as pallet_static_price_provider};
 
 use frame_support::{
-    construct_runtime, derive_impl, parameter_types, traits::ConstU128, weights::Weight,
+    construct_runtime, derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, Hooks},
+    weights::Weight,
 };
+use frame_system::EnsureRoot;
 use sp_io::TestExternalities;
 use sp_runtime::BuildStorage;
 
@@ -49,6 +52,9 @@ impl pallet_balances::Config for Test {
 
 impl pallet_static_price_provider::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type GovernanceOrigin = EnsureRoot<u64>;
+    type MaxScheduledChanges = ConstU32<3>;
+    type WeightInfo = ();
 }
 
 construct_runtime!(
@@ -74,3 +80,11 @@ impl ExternalityBuilder {
         ext
     }
 }
+
+/// Advance blocks until the given block number, initializing the pallet on each.
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        StaticPriceProvider::on_initialize(System::block_number());
+    }
+}
@@ -58,3 +58,335 @@ fn force_set_price_with_invalid_origin_fails() {
         );
     })
 }
+
+#[test]
+fn scheduled_price_changes_are_applied() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_price = ActivePrice::<Test>::get();
+        let (price_1, price_2, price_3) = (2.into(), 3.into(), 4.into());
+
+        // schedule out of order, changes for the same block are applied in order
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            5,
+            price_2
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            3,
+            price_1
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            5,
+            price_3
+        ));
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(
+            Event::PriceChangeScheduled {
+                at: 5,
+                price: price_3,
+            },
+        ));
+        assert_eq!(
+            ScheduledChanges::<Test>::get().into_inner(),
+            vec![(3, price_1), (5, price_2), (5, price_3)]
+        );
+
+        // view of the upcoming changes
+        assert_eq!(StaticPriceProvider::price_at(2), init_price);
+        assert_eq!(StaticPriceProvider::price_at(4), price_1);
+        assert_eq!(StaticPriceProvider::price_at(5), price_3);
+
+        run_to_block(2);
+        assert_eq!(StaticPriceProvider::average_price(), init_price);
+
+        run_to_block(3);
+        assert_eq!(StaticPriceProvider::average_price(), price_1);
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(Event::PriceSet {
+            price: price_1,
+        }));
+
+        run_to_block(4);
+        assert_eq!(StaticPriceProvider::average_price(), price_1);
+
+        run_to_block(5);
+        assert_eq!(StaticPriceProvider::average_price(), price_3);
+        assert!(ScheduledChanges::<Test>::get().is_empty());
+    })
+}
+
+#[test]
+fn scheduled_price_ramp_is_applied() {
+    ExternalityBuilder::build().execute_with(|| {
+        let (start_price, end_price) = (FixedU128::from(2), FixedU128::from(1));
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            3,
+            7,
+            start_price,
+            end_price
+        ));
+        let ramp = PriceRamp {
+            start: 3,
+            end: 7,
+            start_price,
+            end_price,
+        };
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(
+            Event::PriceRampScheduled { ramp },
+        ));
+        assert_eq!(
+            StaticPriceProvider::price_at(5),
+            FixedU128::from_rational(3, 2)
+        );
+
+        run_to_block(2);
+        assert_eq!(
+            StaticPriceProvider::average_price(),
+            FixedU128::from_rational(1, 10)
+        );
+
+        // price decreases by 0.25 each block
+        let expected = [(3, 8), (4, 7), (5, 6), (6, 5), (7, 4), (8, 4)];
+        for (block, quarters) in expected {
+            run_to_block(block);
+            assert_eq!(
+                StaticPriceProvider::average_price(),
+                FixedU128::from_rational(quarters, 4)
+            );
+        }
+
+        // ramp is finished & removed
+        assert!(ActiveRamp::<Test>::get().is_none());
+        assert!(System::events().iter().any(|e| e.event
+            == RuntimeEvent::StaticPriceProvider(Event::PriceRampFinished { price: end_price })));
+    })
+}
+
+#[test]
+fn price_ramp_can_increase_price() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            2,
+            4,
+            1.into(),
+            3.into()
+        ));
+
+        run_to_block(3);
+        assert_eq!(StaticPriceProvider::average_price(), 2.into());
+        run_to_block(4);
+        assert_eq!(StaticPriceProvider::average_price(), 3.into());
+    })
+}
+
+#[test]
+fn cancel_scheduled_changes_works() {
+    ExternalityBuilder::build().execute_with(|| {
+        let init_price = ActivePrice::<Test>::get();
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            3,
+            2.into()
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            4,
+            6,
+            3.into(),
+            4.into()
+        ));
+
+        assert_ok!(StaticPriceProvider::cancel_scheduled_changes(
+            RuntimeOrigin::root()
+        ));
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(
+            Event::ScheduledChangesCancelled,
+        ));
+
+        run_to_block(10);
+        assert_eq!(StaticPriceProvider::average_price(), init_price);
+    })
+}
+
+#[test]
+fn schedule_price_changes_with_invalid_params_fails() {
+    ExternalityBuilder::build().execute_with(|| {
+        run_to_block(5);
+
+        assert_noop!(
+            StaticPriceProvider::schedule_price_change(RuntimeOrigin::signed(1), 10, 1.into()),
+            BadOrigin
+        );
+        assert_noop!(
+            StaticPriceProvider::schedule_price_change(RuntimeOrigin::root(), 10, 0.into()),
+            Error::<Test>::ZeroPrice
+        );
+        assert_noop!(
+            StaticPriceProvider::schedule_price_change(RuntimeOrigin::root(), 5, 1.into()),
+            Error::<Test>::BlockInPast
+        );
+
+        for block in 6..9 {
+            assert_ok!(StaticPriceProvider::schedule_price_change(
+                RuntimeOrigin::root(),
+                block,
+                1.into()
+            ));
+        }
+        assert_noop!(
+            StaticPriceProvider::schedule_price_change(RuntimeOrigin::root(), 10, 1.into()),
+            Error::<Test>::TooManyScheduledChanges
+        );
+
+        assert_noop!(
+            StaticPriceProvider::schedule_price_ramp(
+                RuntimeOrigin::signed(1),
+                6,
+                8,
+                1.into(),
+                2.into()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            StaticPriceProvider::schedule_price_ramp(
+                RuntimeOrigin::root(),
+                6,
+                8,
+                0.into(),
+                2.into()
+            ),
+            Error::<Test>::ZeroPrice
+        );
+        assert_noop!(
+            StaticPriceProvider::schedule_price_ramp(
+                RuntimeOrigin::root(),
+                5,
+                8,
+                1.into(),
+                2.into()
+            ),
+            Error::<Test>::BlockInPast
+        );
+        assert_noop!(
+            StaticPriceProvider::schedule_price_ramp(
+                RuntimeOrigin::root(),
+                8,
+                8,
+                1.into(),
+                2.into()
+            ),
+            Error::<Test>::InvalidRampPeriod
+        );
+    })
+}
+
+#[test]
+fn price_ramp_does_not_emit_price_set_each_block() {
+    ExternalityBuilder::build().execute_with(|| {
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            3,
+            7,
+            1.into(),
+            3.into()
+        ));
+
+        System::reset_events();
+        run_to_block(8);
+        assert_eq!(
+            System::events()
+                .into_iter()
+                .map(|e| e.event)
+                .collect::<Vec<_>>(),
+            vec![RuntimeEvent::StaticPriceProvider(
+                Event::PriceRampFinished { price: 3.into() }
+            )]
+        );
+    })
+}
+
+#[test]
+fn ongoing_ramp_takes_precedence_over_queued_change() {
+    ExternalityBuilder::build().execute_with(|| {
+        // change queued for a block within the ramp, including its last block
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            3,
+            7,
+            2.into(),
+            1.into()
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            5,
+            10.into()
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            7,
+            20.into()
+        ));
+
+        let expected = [
+            (5, FixedU128::from_rational(3, 2)),
+            (7, FixedU128::from(1)),
+            (9, FixedU128::from(1)),
+        ];
+        for (block, price) in expected {
+            assert_eq!(StaticPriceProvider::price_at(block), price);
+        }
+        for (block, price) in expected {
+            run_to_block(block);
+            assert_eq!(StaticPriceProvider::average_price(), price);
+        }
+
+        // superseded changes are consumed
+        assert!(ScheduledChanges::<Test>::get().is_empty());
+        assert!(ActiveRamp::<Test>::get().is_none());
+    })
+}
+
+#[test]
+fn queued_change_after_ramp_end_is_applied() {
+    ExternalityBuilder::build().execute_with(|| {
+        // change queued before the ramp starts & after it ends
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            2,
+            5.into()
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_ramp(
+            RuntimeOrigin::root(),
+            3,
+            5,
+            1.into(),
+            3.into()
+        ));
+        assert_ok!(StaticPriceProvider::schedule_price_change(
+            RuntimeOrigin::root(),
+            7,
+            10.into()
+        ));
+
+        let expected = [
+            (2, FixedU128::from(5)),
+            (4, FixedU128::from(2)),
+            (6, FixedU128::from(3)),
+            (7, FixedU128::from(10)),
+            (8, FixedU128::from(10)),
+        ];
+        for (block, price) in expected {
+            assert_eq!(StaticPriceProvider::price_at(block), price);
+        }
+        for (block, price) in expected {
+            run_to_block(block);
+            assert_eq!(StaticPriceProvider::average_price(), price);
+        }
+        System::assert_last_event(RuntimeEvent::StaticPriceProvider(Event::PriceSet {
+            price: 10.into(),
+        }));
+    })
+}
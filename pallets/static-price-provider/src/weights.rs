@@ -0,0 +1,99 @@
+
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Placeholder weights for pallet_static_price_provider
+//!
+//! THESE WEIGHTS ARE NOT THE OUTPUT OF A BENCHMARK RUN. They are estimated from the storage
+//! accesses of the calls and must be regenerated with the `pallet_static_price_provider`
+//! benchmarks on the reference hardware before being relied upon:
+//!
+//! ./target/release/astar-collator benchmark pallet --chain=local-dev --steps=50 --repeat=20
+//! --pallet=pallet_static_price_provider --extrinsic=* --wasm-execution=compiled --heap-pages=4096
+//! --output=./pallets/static-price-provider/src/weights.rs --template=./scripts/templates/weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_static_price_provider.
+pub trait WeightInfo {
+	fn schedule_price_change() -> Weight;
+	fn schedule_price_ramp() -> Weight;
+	fn cancel_scheduled_changes() -> Weight;
+}
+
+/// Weights for pallet_static_price_provider using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `StaticPriceProvider::ScheduledChanges` (r:1 w:1)
+	/// Proof: `StaticPriceProvider::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	fn schedule_price_change() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(9_736_000, 1806)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StaticPriceProvider::ActiveRamp` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ActiveRamp` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	fn schedule_price_ramp() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(6_391_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StaticPriceProvider::ScheduledChanges` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `StaticPriceProvider::ActiveRamp` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ActiveRamp` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_changes() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(7_245_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `StaticPriceProvider::ScheduledChanges` (r:1 w:1)
+	/// Proof: `StaticPriceProvider::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	fn schedule_price_change() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(9_736_000, 1806)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StaticPriceProvider::ActiveRamp` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ActiveRamp` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	fn schedule_price_ramp() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(6_391_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `StaticPriceProvider::ScheduledChanges` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ScheduledChanges` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Storage: `StaticPriceProvider::ActiveRamp` (r:0 w:1)
+	/// Proof: `StaticPriceProvider::ActiveRamp` (`max_values`: Some(1), `max_size`: Some(40), added: 535, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_changes() -> Weight {
+		// Placeholder, not measured.
+		Weight::from_parts(7_245_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
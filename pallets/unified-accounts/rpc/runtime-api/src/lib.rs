@@ -0,0 +1,42 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use astar_primitives::evm::{EvmAddress, UnifiedAddress};
+use astar_primitives::AccountId;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+
+    /// Unified Accounts Api.
+    ///
+    /// Used to resolve the account mappings without building the storage keys by hand.
+    pub trait UnifiedAccountsApi {
+
+        /// Get the native accounts of the given evm addresses, either mapped or default ones.
+        fn to_account_ids(evm_addresses: Vec<EvmAddress>) -> Vec<UnifiedAddress<AccountId>>;
+
+        /// Get the evm addresses of the given native accounts, either mapped or default ones.
+        fn to_h160s(account_ids: Vec<AccountId>) -> Vec<UnifiedAddress<EvmAddress>>;
+
+        /// Get the EIP-712 payload which needs to be signed by the evm address
+        /// in order to claim it for the given native account.
+        fn claim_signing_payload(account_id: AccountId) -> [u8; 32];
+    }
+}
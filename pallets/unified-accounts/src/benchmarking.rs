@@ -20,7 +20,7 @@
 
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, migrations::SteppedMigration, weights::WeightMeter};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
@@ -29,6 +29,18 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
     frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Fund the default account id of the evm address, so its native balance has to be moved,
+/// and keep it alive as if the evm address had been unlinked before.
+fn fund_default_account<T: Config>(evm_address: &EvmAddress) {
+    let default_account_id = T::DefaultMappings::to_default_account_id(evm_address);
+    assert_ok!(T::Currency::mint_into(
+        &default_account_id,
+        T::Currency::minimum_balance().saturating_add(T::AccountMappingStorageFee::get())
+    ));
+    frame_system::Pallet::<T>::inc_providers(&default_account_id);
+    UnlinkedDefaultAccounts::<T>::insert(&default_account_id, ());
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -48,6 +60,8 @@ mod benchmarks {
             &caller,
             T::AccountMappingStorageFee::get()
         ));
+        // native balance of the default account id is moved as well
+        fund_default_account::<T>(&evm_address);
         let caller_clone = caller.clone();
 
         #[extrinsic_call]
@@ -84,6 +98,129 @@ mod benchmarks {
         );
     }
 
+    #[benchmark]
+    fn claim_evm_contract_address() {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = EvmAddress::repeat_byte(0x12);
+        T::ContractSignatureVerifier::set_valid_contract(&evm_address);
+        let signature: BoundedVec<u8, T::MaxContractSignatureLength> =
+            vec![1u8; T::MaxContractSignatureLength::get() as usize]
+                .try_into()
+                .unwrap();
+
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        // native balance of the default account id is moved as well
+        fund_default_account::<T>(&evm_address);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), evm_address, signature);
+
+        assert_last_event::<T>(
+            Event::<T>::AccountClaimed {
+                account_id: caller,
+                evm_address,
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn force_unlink_account() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, caller.clone());
+
+        assert!(NativeToEvm::<T>::get(&caller).is_none());
+        assert!(EvmToNative::<T>::get(&evm_address).is_none());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn request_unlink() {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(
+            Event::<T>::UnlinkRequested {
+                account_id: caller,
+                evm_address,
+                unlockable_at: frame_system::Pallet::<T>::block_number()
+                    .saturating_add(T::UnlinkDelay::get()),
+            }
+            .into(),
+        );
+    }
+
+    #[benchmark]
+    fn cancel_unlink() {
+        let caller: T::AccountId = whitelisted_caller();
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        assert_ok!(Pallet::<T>::request_unlink(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert_last_event::<T>(Event::<T>::UnlinkCancelled { account_id: caller }.into());
+    }
+
+    #[benchmark]
+    fn unlink_account() {
+        let caller: T::AccountId = whitelisted_caller();
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        assert_ok!(Pallet::<T>::claim_default_evm_address(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        assert_ok!(Pallet::<T>::request_unlink(
+            RawOrigin::Signed(caller.clone()).into()
+        ));
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number().saturating_add(T::UnlinkDelay::get()),
+        );
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()));
+
+        assert!(NativeToEvm::<T>::get(&caller).is_none());
+        assert!(EvmToNative::<T>::get(&evm_address).is_none());
+    }
+
     #[benchmark]
     fn to_account_id() {
         let caller: T::AccountId = whitelisted_caller();
@@ -159,4 +296,45 @@ mod benchmarks {
             let _ = <Pallet<T> as UnifiedAddressMapper<T::AccountId>>::to_h160_or_default(&caller);
         }
     }
+
+    #[benchmark]
+    fn default_mapping_migration_step() {
+        // worst case: account & its default evm address are unmapped, and the default
+        // account id of the evm address has to be checked before creating the mappings
+        let caller: T::AccountId = whitelisted_caller();
+        assert_ok!(T::Currency::mint_into(
+            &caller,
+            T::AccountMappingStorageFee::get()
+        ));
+        let evm_address = T::DefaultMappings::to_default_h160(&caller);
+        assert!(!NativeToEvm::<T>::contains_key(&caller));
+        assert!(!EvmToNative::<T>::contains_key(&evm_address));
+
+        // resume right before the caller, so it's the one processed in the step
+        let cursor = frame_system::Account::<T>::iter_keys()
+            .take_while(|account_id| *account_id != caller)
+            .last();
+        // enough weight for a single account only
+        let mut meter =
+            WeightMeter::with_limit(<T as Config>::WeightInfo::default_mapping_migration_step());
+
+        #[block]
+        {
+            migration::LazyDefaultMappingMigration::<T>::step(cursor, &mut meter).unwrap();
+        }
+
+        assert_eq!(NativeToEvm::<T>::get(&caller), Some(evm_address));
+    }
+
+    #[benchmark]
+    fn start_default_mappings_migration() -> Result<(), BenchmarkError> {
+        let origin =
+            T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin);
+
+        assert_eq!(DefaultMappingsMigrationCursor::<T>::get(), Some(None));
+        Ok(())
+    }
 }
@@ -38,20 +38,90 @@
 //!    account id given that no prior mapping exists for both and signature provided is valid.
 //! * `claim_default_evm_address`: Creates the double mapping with default evm address given that
 //!    no prior mapping exists.
+//! * `claim_evm_contract_address`: Creates the double mappings for the provided evm smart contract
+//!    wallet (e.g. multisig) with caller account id, given that no prior mapping exists for both
+//!    and the contract accepts the provided signature as per EIP-1271.
+//! * `force_unlink_account`: Removes the double mappings of the given account, callable by
+//!    [`ManagerOrigin`](`crate::Config::ManagerOrigin`) only.
+//! * `request_unlink`: Requests the removal of caller's double mappings, which can be done after
+//!    [`UnlinkDelay`](`crate::Config::UnlinkDelay`) blocks.
+//! * `cancel_unlink`: Cancels the pending unlink request of the caller.
+//! * `unlink_account`: Removes the double mappings of the caller, once the unlink delay has passed.
+//! * `start_default_mappings_migration`: Starts the migration creating the default mappings for
+//!    the existing accounts, callable by [`ManagerOrigin`](`crate::Config::ManagerOrigin`) only.
+//!
+//! Native extrinsics can also be signed by evm keys, see [`Pallet::verify_extrinsic_signature`].
+//!
+//! Claims can also be made from the evm side (e.g. via precompile), using [`Pallet::do_claim_evm_address`]
+//! with the native account ownership proven by a signature over [`Pallet::build_native_signing_payload`],
+//! or using [`Pallet::do_claim_default_account_id`].
+//!
+//! The claim nonce of the native account is bumped on every unlink. Once non-zero, it is part of
+//! both claim signing payloads (`Claim(bytes substrateAddress,uint256 nonce)` and
+//! `NativeClaim(address evmAddress,uint256 nonce)`) so the claim signatures cannot be replayed to
+//! link the accounts again, while first claims keep using the types without the nonce.
 //!
 //! ## Storage Fee
 //! User is also charged a storage fee [`AccountMappingStorageFee`](`crate::Config::AccountMappingStorageFee`)
-//! before mappings are created to prevent storage abuse.
+//! before mappings are created to prevent storage abuse. The fee is either held on the account or
+//! burned, according to the [`UnlinkFeePolicy`](`crate::Config::UnlinkFeePolicy`).
+//!
+//! ## Account Unification
+//! When claiming an evm address, the state held by its default account id is moved to the
+//! claiming account. Native balance is always transferred, while the rest of the state
+//! (like dApp staking ledger, etc) is moved by the
+//! [`OnAccountUnification`](`crate::Config::OnAccountUnification`) hooks.
+//! In case some state cannot be moved, the claim is refused.
+//!
+//! Since the assets held by an account cannot be enumerated, their balances aren't moved. Instead,
+//! the claim is refused while the default account id holds anything the hooks didn't move, which
+//! is detected through its reference counters (each asset balance takes a consumer or a sufficient
+//! reference). Such state, e.g. XC20 balances, has to be transferred by the user beforehand.
 //!
 //! WARNINGS:
-//! * This pallet only handles transfer of native balance only, for the rest of native assets
-//!   hold by evm address like XC20, DAppStaking unclaimed rewards, etc should be transferred
-//!   manually beforehand by user himself otherwise FUNDS WILL BE LOST FOREVER.
-//! * Once mapping is created it cannot be changed.
+//! * Any state of the default account which isn't covered by the configured hooks, nor
+//!   prevents the claim by keeping the account alive, should be transferred manually beforehand
+//!   by user himself otherwise FUNDS WILL BE LOST FOREVER.
+//! * Once mapping is created it cannot be changed, only removed (see below).
+//!
+//! ## Unlinking
+//! In case the evm key is compromised, the mappings can be removed either by
+//! [`ManagerOrigin`](`crate::Config::ManagerOrigin`) or by the account itself after the
+//! [`UnlinkDelay`](`crate::Config::UnlinkDelay`). Depending on the
+//! [`UnlinkFeePolicy`](`crate::Config::UnlinkFeePolicy`), the storage fee is either held when
+//! claiming and released back when unlinking, or burned. Both the account and the evm address
+//! can be claimed again afterwards. While an unlink is pending, extrinsics signed by the evm key
+//! are rejected so a compromised key cannot cancel it.
+//!
+//! No state is moved when unlinking, the account keeps its balances. Since the evm address falls
+//! back to its default account id, the nonce is carried over to it so the evm transactions signed
+//! in the past cannot be replayed. The default account id is kept alive (by a provider reference)
+//! until the evm address is claimed again, so its nonce cannot be reset by reaping it. The same applies to claims, where the nonce of the default
+//! account id is carried over to the claiming account.
+//! Contract state keyed by the evm address (e.g. ownership or ERC20 balances) stays with the evm
+//! address and is not affected by unlinking.
+//!
+//! ## Smart Contract Wallets
+//! Since smart contract wallets cannot produce ECDSA signatures, their ownership is proven by the
+//! contract itself, which has to return the EIP-1271 magic value from `isValidSignature(bytes32,bytes)`
+//! for the claim payload. The call is made by the configured
+//! [`ContractSignatureVerifier`](`crate::Config::ContractSignatureVerifier`), e.g.
+//! [`EvmContractSignatureVerifier`] which calls the contract through the EVM runner with a bounded
+//! gas limit and reverts any state changes made by it.
+//!
+//! ## Default Mappings Migration
+//! [`LazyDefaultMappingMigration`](`crate::migration::LazyDefaultMappingMigration`) is a
+//! multi-block migration which creates the default mappings for all the existing accounts,
+//! without charging the storage fee, so lookups of the existing accounts don't need to rely on
+//! the default mapping conventions. It is started by the [`ManagerOrigin`](`crate::Config::ManagerOrigin`)
+//! via `start_default_mappings_migration` and runs in `on_idle` until all accounts are processed.
+//! Accounts whose default evm address has a funded default account id are skipped, so no funds
+//! become inaccessible.
 //!
 //! ## Traits
 //!
 //! * `UnifiedAddressMapper`: Interface to access pallet's mappings with defaults
+//! * `ContractSignatureVerifier`: Interface for verifying signatures of smart contract wallets
 //!
 //! ## Implementations
 //!
@@ -64,30 +134,39 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use astar_primitives::{
-    evm::{EvmAddress, UnifiedAddressMapper},
+    evm::{EvmAddress, OnAccountUnification, UnifiedAddressMapper},
     Balance,
 };
 use frame_support::{
+    migrations::SteppedMigration,
     pallet_prelude::*,
+    storage::{with_transaction, TransactionOutcome},
     traits::{
-        fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
+        fungible::{
+            Inspect as FungibleInspect, Mutate as FungibleMutate, MutateHold as FungibleMutateHold,
+        },
         tokens::{Fortitude::*, Precision::*, Preservation::*},
         IsType, OnKilledAccount,
     },
+    weights::WeightMeter,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
-use pallet_evm::AddressMapping;
-use precompile_utils::keccak256;
+use pallet_evm::{AddressMapping, GasWeightMapping, IsPrecompileResult, PrecompileSet, Runner};
+use precompile_utils::{
+    keccak256,
+    solidity::codec::{UnboundedBytes, Writer},
+};
 use sp_core::{H160, H256, U256};
 use sp_io::hashing::keccak_256;
 use sp_runtime::{
-    traits::{LookupError, StaticLookup, Zero},
+    traits::{LookupError, Saturating, StaticLookup, Zero},
     MultiAddress,
 };
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, prelude::*};
 
 pub use pallet::*;
 
+pub mod migration;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -99,6 +178,10 @@ mod tests;
 /// ECDSA Signature type, with last bit for recovering address
 type EvmSignature = [u8; 65];
 
+/// Value returned by `isValidSignature(bytes32,bytes)` for valid signatures, which is also its selector.
+/// https://eips.ethereum.org/EIPS/eip-1271
+pub const EIP1271_MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -110,8 +193,11 @@ pub mod pallet {
     pub trait Config: frame_system::Config {
         /// The overarching event type
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
         /// The Currency for managing evm address assets
-        type Currency: FungibleMutate<Self::AccountId, Balance = Balance>;
+        type Currency: FungibleMutate<Self::AccountId, Balance = Balance>
+            + FungibleMutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
         /// Default address conversion
         type DefaultMappings: UnifiedAddressMapper<Self::AccountId>;
         /// EVM chain id
@@ -122,10 +208,35 @@ pub mod pallet {
         /// respectively
         #[pallet::constant]
         type AccountMappingStorageFee: Get<Balance>;
+        /// Hooks for moving the state of the default account id to the claiming account,
+        /// e.g. assets, staking ledgers, etc.
+        type OnAccountUnification: OnAccountUnification<Self::AccountId>;
+        /// Verifier of the signatures made by evm smart contract wallets (EIP-1271)
+        type ContractSignatureVerifier: ContractSignatureVerifier;
+        /// Max length of the signature accepted for evm smart contract wallets
+        #[pallet::constant]
+        type MaxContractSignatureLength: Get<u32>;
+        /// Origin allowed to remove the mappings of any account
+        type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+        /// Number of blocks after which the self-requested unlink can be done
+        #[pallet::constant]
+        type UnlinkDelay: Get<BlockNumberFor<Self>>;
+        /// Whether the storage fee is held and refunded when the mappings are removed,
+        /// or burned
+        #[pallet::constant]
+        type UnlinkFeePolicy: Get<UnlinkFeePolicy>;
         /// Weight information for the extrinsics in this module
         type WeightInfo: WeightInfo;
     }
 
+    /// A reason for holding funds.
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Storage fee of the account mappings.
+        #[codec(index = 0)]
+        AccountMapping,
+    }
+
     #[pallet::error]
     pub enum Error<T> {
         /// AccountId or EvmAddress already mapped
@@ -136,6 +247,18 @@ pub mod pallet {
         InvalidSignature,
         /// Funds unavailable to claim account
         FundsUnavailable,
+        /// AccountId is not mapped to any evm address
+        NotMapped,
+        /// Unlink has already been requested for the account
+        UnlinkAlreadyRequested,
+        /// No unlink request exists for the account
+        UnlinkNotRequested,
+        /// Unlink delay has not passed yet
+        UnlinkDelayNotPassed,
+        /// Default mappings migration is already in progress
+        MigrationInProgress,
+        /// Default account id holds state which cannot be moved, e.g. asset balances
+        DefaultAccountNotEmpty,
     }
 
     #[pallet::event]
@@ -147,6 +270,24 @@ pub mod pallet {
             account_id: T::AccountId,
             evm_address: EvmAddress,
         },
+        /// Unlink of the account requested, which can be done at the given block
+        UnlinkRequested {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
+            unlockable_at: BlockNumberFor<T>,
+        },
+        /// Pending unlink request of the account cancelled
+        UnlinkCancelled { account_id: T::AccountId },
+        /// Double Mapping b/w native and evm address removed
+        AccountUnlinked {
+            account_id: T::AccountId,
+            evm_address: EvmAddress,
+            refunded_fee: Balance,
+        },
+        /// Migration creating the default mappings for the existing accounts started
+        DefaultMappingsMigrationStarted,
+        /// Migration creating the default mappings for the existing accounts completed
+        DefaultMappingsMigrationCompleted,
     }
 
     /// Native accounts for evm address
@@ -161,6 +302,39 @@ pub mod pallet {
     pub type NativeToEvm<T: Config> =
         StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress, OptionQuery>;
 
+    /// Pending unlink requests, with the block from which the unlink can be done
+    /// PendingUnlinks: AccountId => Option<BlockNumber>
+    #[pallet::storage]
+    pub type PendingUnlinks<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+    /// Claim nonces of the native accounts, part of the claim signing payloads and bumped on
+    /// every unlink so the claim signatures cannot be replayed afterwards
+    /// ClaimNonces: AccountId => u32
+    #[pallet::storage]
+    pub type ClaimNonces<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Default account ids of the unlinked evm addresses, kept alive by a provider reference
+    /// until claimed again, so their nonce cannot be reset by reaping them
+    /// UnlinkedDefaultAccounts: AccountId => Option<()>
+    #[pallet::storage]
+    pub type UnlinkedDefaultAccounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Cursor of the default mappings migration, `None` if the migration isn't in progress.
+    /// Inner `None` means no account has been processed yet.
+    #[pallet::storage]
+    pub type DefaultMappingsMigrationCursor<T: Config> =
+        StorageValue<_, Option<T::AccountId>, OptionQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_idle(_block: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::default_mappings_migration_step(remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Claim account mapping between Substrate account and Evm address.
@@ -169,13 +343,19 @@ pub mod pallet {
         /// - `evm_address`: The evm address to bind to the caller's account
         /// - `signature`: A signature generated by the address to prove ownership
         ///
+        /// The state held by the default account id of the evm address is moved to the caller,
+        /// native balance by the pallet itself and the rest by the `OnAccountUnification` hooks.
+        /// If any of the state cannot be moved, the claim fails.
+        ///
         /// WARNING:
-        /// - This extrinsic only handles transfer of native balance, if your EVM
-        /// address contains any other native assets like XC20, DAppStaking unclaimed rewards,
-        /// etc you need to transfer them before hand, otherwise FUNDS WILL BE LOST FOREVER.
-        /// - Once connected user cannot change their mapping EVER.
+        /// - Any state not covered by the `OnAccountUnification` hooks needs to be transferred
+        /// before hand, otherwise FUNDS WILL BE LOST FOREVER.
+        /// - Once connected user cannot change their mapping, only unlink it.
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::claim_evm_address())]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_address()
+                .saturating_add(T::OnAccountUnification::weight())
+        )]
         pub fn claim_evm_address(
             origin: OriginFor<T>,
             evm_address: EvmAddress,
@@ -198,39 +378,13 @@ pub mod pallet {
 
             ensure!(evm_address == address, Error::<T>::InvalidSignature);
 
-            // charge the storage fee
-            Self::charge_storage_fee(&who)?;
-
-            // Check if the default account id already exists for this evm address
-            let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
-            if frame_system::Pallet::<T>::account_exists(&default_account_id) {
-                // Transfer all the free native balance from old account id to the newly
-                // since this `default_account_id` will no longer be connected to evm address
-                // and users cannot access it.
-                // For the reset of the assets types (like XC20, etc) that should be handled by UI.
-                T::Currency::transfer(
-                    &default_account_id,
-                    &who,
-                    T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
-                    Expendable,
-                )?;
-            }
-
-            // create double mappings for the pair
-            EvmToNative::<T>::insert(&evm_address, &who);
-            NativeToEvm::<T>::insert(&who, &evm_address);
-
-            Self::deposit_event(Event::AccountClaimed {
-                account_id: who,
-                evm_address,
-            });
-            Ok(())
+            Self::do_claim_evm_address(who, evm_address)
         }
 
         /// Claim default evm address for given account id
         /// Ensure no prior mapping exists for the account
         ///
-        /// WARNINGS: Once connected user cannot change their mapping EVER.
+        /// WARNINGS: Once connected user cannot change their mapping, only unlink it.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::claim_default_evm_address())]
         pub fn claim_default_evm_address(origin: OriginFor<T>) -> DispatchResult {
@@ -239,12 +393,163 @@ pub mod pallet {
             let _ = Self::do_claim_default_evm_address(who)?;
             Ok(())
         }
+
+        /// Claim account mapping between Substrate account and evm smart contract wallet
+        /// (e.g. multisig) address. Ensure no prior mapping exists for evm address.
+        ///
+        /// - `evm_address`: The address of the contract to bind to the caller's account
+        /// - `signature`: A signature for the claim payload, accepted by the contract's
+        ///   `isValidSignature(bytes32,bytes)` as per EIP-1271
+        ///
+        /// Same as `claim_evm_address`, the state held by the default account id of the
+        /// evm address is moved to the caller.
+        ///
+        /// WARNING:
+        /// - Any state not covered by the `OnAccountUnification` hooks needs to be transferred
+        /// before hand, otherwise FUNDS WILL BE LOST FOREVER.
+        /// - Once connected user cannot change their mapping, only unlink it.
+        #[pallet::call_index(2)]
+        #[pallet::weight(
+            T::WeightInfo::claim_evm_contract_address()
+                .saturating_add(T::ContractSignatureVerifier::weight())
+                .saturating_add(T::OnAccountUnification::weight())
+        )]
+        pub fn claim_evm_contract_address(
+            origin: OriginFor<T>,
+            evm_address: EvmAddress,
+            signature: BoundedVec<u8, T::MaxContractSignatureLength>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            // make sure no prior mapping exists, before calling into the contract
+            ensure!(
+                !NativeToEvm::<T>::contains_key(&who),
+                Error::<T>::AlreadyMapped
+            );
+            ensure!(
+                !EvmToNative::<T>::contains_key(evm_address),
+                Error::<T>::AlreadyMapped
+            );
+
+            let payload_hash = Self::build_signing_payload(&who);
+            ensure!(
+                T::ContractSignatureVerifier::is_valid_signature(
+                    &evm_address,
+                    &payload_hash,
+                    &signature
+                ),
+                Error::<T>::InvalidSignature
+            );
+
+            Self::do_claim_evm_address(who, evm_address)
+        }
+
+        /// Remove the account mappings of the given account, bypassing the unlink delay.
+        ///
+        /// Can only be called by the `ManagerOrigin`, e.g. in case the evm key has been compromised.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::force_unlink_account())]
+        pub fn force_unlink_account(
+            origin: OriginFor<T>,
+            account_id: T::AccountId,
+        ) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            Self::do_unlink(account_id)
+        }
+
+        /// Request the removal of caller's account mappings.
+        ///
+        /// The unlink can be done after `UnlinkDelay` blocks via `unlink_account`.
+        /// While the unlink is pending, extrinsics signed by the evm key are rejected
+        /// (see [`Pallet::verify_extrinsic_signature`]), so it can only be cancelled by the native key.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::request_unlink())]
+        pub fn request_unlink(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let evm_address = NativeToEvm::<T>::get(&who).ok_or(Error::<T>::NotMapped)?;
+            ensure!(
+                !PendingUnlinks::<T>::contains_key(&who),
+                Error::<T>::UnlinkAlreadyRequested
+            );
+
+            let unlockable_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::UnlinkDelay::get());
+            PendingUnlinks::<T>::insert(&who, unlockable_at);
+
+            Self::deposit_event(Event::UnlinkRequested {
+                account_id: who,
+                evm_address,
+                unlockable_at,
+            });
+            Ok(())
+        }
+
+        /// Cancel the pending unlink request of the caller.
+        ///
+        /// Since extrinsics signed by the evm key are rejected while the unlink is pending,
+        /// it can only be cancelled by the native key.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::cancel_unlink())]
+        pub fn cancel_unlink(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                PendingUnlinks::<T>::take(&who).is_some(),
+                Error::<T>::UnlinkNotRequested
+            );
+
+            Self::deposit_event(Event::UnlinkCancelled { account_id: who });
+            Ok(())
+        }
+
+        /// Remove the account mappings of the caller, once the unlink delay has passed.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::unlink_account())]
+        pub fn unlink_account(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let unlockable_at =
+                PendingUnlinks::<T>::get(&who).ok_or(Error::<T>::UnlinkNotRequested)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number() >= unlockable_at,
+                Error::<T>::UnlinkDelayNotPassed
+            );
+
+            Self::do_unlink(who)
+        }
+
+        /// Start the migration creating the default mappings for all the existing accounts.
+        ///
+        /// The accounts are processed in `on_idle`, as much as the remaining weight allows.
+        /// Can only be called by the `ManagerOrigin`.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::start_default_mappings_migration())]
+        pub fn start_default_mappings_migration(origin: OriginFor<T>) -> DispatchResult {
+            T::ManagerOrigin::ensure_origin(origin)?;
+            ensure!(
+                !DefaultMappingsMigrationCursor::<T>::exists(),
+                Error::<T>::MigrationInProgress
+            );
+
+            DefaultMappingsMigrationCursor::<T>::put(None::<T::AccountId>);
+
+            Self::deposit_event(Event::DefaultMappingsMigrationStarted);
+            Ok(())
+        }
     }
 }
 
+/// Policy for the storage fee when the account mappings are removed
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum UnlinkFeePolicy {
+    /// The storage fee is held on the account and released back once the mappings are removed
+    Refund,
+    /// The storage fee is burned
+    Burn,
+}
+
 impl<T: Config> Pallet<T> {
     /// Claim the default evm address
-    fn do_claim_default_evm_address(account_id: T::AccountId) -> Result<EvmAddress, DispatchError> {
+    pub fn do_claim_default_evm_address(
+        account_id: T::AccountId,
+    ) -> Result<EvmAddress, DispatchError> {
         ensure!(
             !NativeToEvm::<T>::contains_key(&account_id),
             Error::<T>::AlreadyMapped
@@ -271,26 +576,237 @@ impl<T: Config> Pallet<T> {
         Ok(evm_address)
     }
 
-    /// Charge the (exact) storage fee (politely) from the user and burn it
-    /// while preserving the account from being reaped.
-    fn charge_storage_fee(who: &T::AccountId) -> Result<Balance, DispatchError> {
+    /// Claim the given evm address for the account, once the ownership of both has been verified.
+    ///
+    /// State held by the default account id of the evm address is moved to the account.
+    pub fn do_claim_evm_address(
+        account_id: T::AccountId,
+        evm_address: EvmAddress,
+    ) -> DispatchResult {
+        // make sure no prior mapping exists
+        ensure!(
+            !NativeToEvm::<T>::contains_key(&account_id),
+            Error::<T>::AlreadyMapped
+        );
+        ensure!(
+            !EvmToNative::<T>::contains_key(evm_address),
+            Error::<T>::AlreadyMapped
+        );
+
+        // charge the storage fee
+        Self::charge_storage_fee(&account_id)?;
+
+        // Check if the default account id already exists for this evm address
+        let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
+        // evm transactions signed so far must not be replayable with the unified account
+        Self::carry_over_nonce(&default_account_id, &account_id);
+        if frame_system::Pallet::<T>::account_exists(&default_account_id) {
+            // Move the rest of the state (like XC20, dApp staking ledger, etc) first,
+            // since it might be preventing the native balance from being transferred.
+            T::OnAccountUnification::on_unification(&default_account_id, &account_id)?;
+            ensure!(
+                !Self::holds_unmovable_state(&default_account_id, &evm_address),
+                Error::<T>::DefaultAccountNotEmpty
+            );
+
+            // Transfer all the free native balance from old account id to the newly
+            // since this `default_account_id` will no longer be connected to evm address
+            // and users cannot access it.
+            T::Currency::transfer(
+                &default_account_id,
+                &account_id,
+                T::Currency::reducible_balance(&default_account_id, Expendable, Polite),
+                Expendable,
+            )?;
+        }
+        Self::release_default_account(&default_account_id)?;
+
+        // create double mappings for the pair
+        EvmToNative::<T>::insert(&evm_address, &account_id);
+        NativeToEvm::<T>::insert(&account_id, &evm_address);
+
+        Self::deposit_event(Event::AccountClaimed {
+            account_id,
+            evm_address,
+        });
+        Ok(())
+    }
+
+    /// Claim the default account id for the given evm address.
+    ///
+    /// Since the evm address is mapped to the account id it already uses, there is no state to move.
+    pub fn do_claim_default_account_id(
+        evm_address: EvmAddress,
+    ) -> Result<T::AccountId, DispatchError> {
+        ensure!(
+            !EvmToNative::<T>::contains_key(&evm_address),
+            Error::<T>::AlreadyMapped
+        );
+        // get the default account id
+        let account_id = T::DefaultMappings::to_default_account_id(&evm_address);
+        ensure!(
+            !NativeToEvm::<T>::contains_key(&account_id),
+            Error::<T>::AlreadyMapped
+        );
+
+        Self::charge_storage_fee(&account_id)?;
+        Self::release_default_account(&account_id)?;
+
+        // create double mappings for the pair with default account id
+        EvmToNative::<T>::insert(&evm_address, &account_id);
+        NativeToEvm::<T>::insert(&account_id, &evm_address);
+
+        Self::deposit_event(Event::AccountClaimed {
+            account_id: account_id.clone(),
+            evm_address,
+        });
+        Ok(account_id)
+    }
+
+    /// Remove the double mappings of the account, the storage fee held on the account (if any)
+    /// is released back to it.
+    fn do_unlink(account_id: T::AccountId) -> DispatchResult {
+        let evm_address = NativeToEvm::<T>::take(&account_id).ok_or(Error::<T>::NotMapped)?;
+        EvmToNative::<T>::remove(&evm_address);
+        PendingUnlinks::<T>::remove(&account_id);
+        // claim signatures made so far must not be replayable
+        ClaimNonces::<T>::mutate(&account_id, |nonce| nonce.saturating_inc());
+
+        // evm address falls back to its default account id, evm transactions signed so far
+        // must not be replayable with it, so it's kept alive until claimed again
+        let default_account_id = T::DefaultMappings::to_default_account_id(&evm_address);
+        if !UnlinkedDefaultAccounts::<T>::contains_key(&default_account_id) {
+            frame_system::Pallet::<T>::inc_providers(&default_account_id);
+            UnlinkedDefaultAccounts::<T>::insert(&default_account_id, ());
+        }
+        Self::carry_over_nonce(&account_id, &default_account_id);
+
+        // exactly the fee paid for the mappings is refunded, regardless of the current policy
+        let refunded_fee =
+            T::Currency::release_all(&HoldReason::AccountMapping.into(), &account_id, Exact)?;
+
+        Self::deposit_event(Event::AccountUnlinked {
+            account_id,
+            evm_address,
+            refunded_fee,
+        });
+        Ok(())
+    }
+
+    /// Create the default mappings for the account, unless either the account or its default
+    /// evm address is already mapped, or the default account id of the evm address exists.
+    ///
+    /// Returns `true` if the mappings have been created.
+    pub(crate) fn try_create_default_mapping(account_id: &T::AccountId) -> bool {
+        if NativeToEvm::<T>::contains_key(account_id) {
+            return false;
+        }
+        let evm_address = T::DefaultMappings::to_default_h160(account_id);
+        // evm address can only be taken by another account if it was claimed for it
+        if EvmToNative::<T>::contains_key(&evm_address) {
+            return false;
+        }
+        // funds of the default account id would be lost, it has to be claimed by the user
+        if frame_system::Pallet::<T>::account_exists(&T::DefaultMappings::to_default_account_id(
+            &evm_address,
+        )) {
+            return false;
+        }
+
+        EvmToNative::<T>::insert(&evm_address, account_id);
+        NativeToEvm::<T>::insert(account_id, &evm_address);
+        true
+    }
+
+    /// Run the default mappings migration (if in progress) with the given weight.
+    fn default_mappings_migration_step(remaining_weight: Weight) -> Weight {
+        let overhead = T::DbWeight::get().reads_writes(1, 1);
+        if remaining_weight.any_lt(overhead) {
+            return Weight::zero();
+        }
+        let Some(cursor) = DefaultMappingsMigrationCursor::<T>::get() else {
+            return T::DbWeight::get().reads(1);
+        };
+
+        // the migration isn't run by the migrations pallet, so its checks are run from here
+        #[cfg(feature = "try-runtime")]
+        let pre_state = migration::LazyDefaultMappingMigration::<T>::pre_upgrade()
+            .expect("default mappings migration pre-upgrade checks must pass");
+
+        let mut meter = WeightMeter::with_limit(remaining_weight.saturating_sub(overhead));
+        match migration::LazyDefaultMappingMigration::<T>::step(cursor, &mut meter) {
+            Ok(Some(cursor)) => DefaultMappingsMigrationCursor::<T>::put(Some(cursor)),
+            Ok(None) => {
+                DefaultMappingsMigrationCursor::<T>::kill();
+                Self::deposit_event(Event::DefaultMappingsMigrationCompleted);
+
+                #[cfg(feature = "try-runtime")]
+                migration::LazyDefaultMappingMigration::<T>::post_upgrade(pre_state)
+                    .expect("default mappings migration post-upgrade checks must pass");
+            }
+            // not enough weight left in this block, try again in the next one
+            Err(_) => {}
+        }
+
+        meter.consumed().saturating_add(overhead)
+    }
+
+    /// Whether the default account id of the evm address holds state which isn't moved with the
+    /// native balance, e.g. balances of the assets, which take a consumer or a sufficient reference.
+    ///
+    /// The sufficient reference taken by the code of an evm contract is expected.
+    fn holds_unmovable_state(default_account_id: &T::AccountId, evm_address: &EvmAddress) -> bool {
+        let account = frame_system::Account::<T>::get(default_account_id);
+        let code_sufficients = if T::ContractSignatureVerifier::is_contract(evm_address) {
+            1
+        } else {
+            0
+        };
+
+        !account.consumers.is_zero() || account.sufficients > code_sufficients
+    }
+
+    /// Release the provider reference keeping the default account id of an unlinked evm address
+    /// alive, if any. The account is reaped if nothing else keeps it alive.
+    fn release_default_account(default_account_id: &T::AccountId) -> DispatchResult {
+        if UnlinkedDefaultAccounts::<T>::take(default_account_id).is_some() {
+            frame_system::Pallet::<T>::dec_providers(default_account_id)?;
+        }
+        Ok(())
+    }
+
+    /// Make sure the nonce of `to` is not lower than the nonce of `from`.
+    fn carry_over_nonce(from: &T::AccountId, to: &T::AccountId) {
+        let nonce = frame_system::Pallet::<T>::account_nonce(from);
+        if frame_system::Pallet::<T>::account_nonce(to) < nonce {
+            frame_system::Account::<T>::mutate(to, |info| info.nonce = nonce);
+        }
+    }
+
+    /// Charge the (exact) storage fee (politely) from the user while preserving the account
+    /// from being reaped. The fee is either held or burned according to the `UnlinkFeePolicy`.
+    fn charge_storage_fee(who: &T::AccountId) -> DispatchResult {
         let balance = T::Currency::reducible_balance(who, Preserve, Polite);
         let fee = T::AccountMappingStorageFee::get();
         ensure!(balance >= fee, Error::<T>::FundsUnavailable);
-        T::Currency::burn_from(
-            who,
-            T::AccountMappingStorageFee::get(),
-            Preserve,
-            Exact,
-            Polite,
-        )
+        match T::UnlinkFeePolicy::get() {
+            UnlinkFeePolicy::Refund => {
+                T::Currency::hold(&HoldReason::AccountMapping.into(), who, fee)
+            }
+            UnlinkFeePolicy::Burn => {
+                T::Currency::burn_from(who, fee, Preserve, Exact, Polite).map(|_| ())
+            }
+        }
     }
 }
 
 /// EIP-712 compatible signature scheme for verifying ownership of EVM Address
 /// https://eips.ethereum.org/EIPS/eip-712
 ///
-/// Raw Data = Domain Separator + Type Hash + keccak256(AccountId)
+/// Raw Data = Domain Separator + Type Hash + keccak256(AccountId) [+ claim nonce]
+///
+/// The claim nonce is only part of the payload once the account has been unlinked, first
+/// claims keep using the `Claim(bytes substrateAddress)` type.
 impl<T: Config> Pallet<T> {
     pub fn build_signing_payload(who: &T::AccountId) -> [u8; 32] {
         let domain_separator = Self::build_domain_separator();
@@ -302,6 +818,65 @@ impl<T: Config> Pallet<T> {
         keccak_256(&payload)
     }
 
+    /// Counterpart of [`Self::build_signing_payload`], signed by the native account
+    /// in order to prove its ownership when claiming from the evm side.
+    ///
+    /// Raw Data = Domain Separator + Type Hash + evm address (padded to 32 bytes) [+ claim nonce]
+    pub fn build_native_signing_payload(
+        evm_address: &EvmAddress,
+        account_id: &T::AccountId,
+    ) -> [u8; 32] {
+        let domain_separator = Self::build_domain_separator();
+        let args_hash = Self::build_native_args_hash(evm_address, account_id);
+
+        let mut payload = b"\x19\x01".to_vec();
+        payload.extend_from_slice(&domain_separator);
+        payload.extend_from_slice(&args_hash);
+        keccak_256(&payload)
+    }
+
+    /// Signing payload of the native extrinsics signed by evm keys.
+    ///
+    /// Raw Data = Domain Separator + Type Hash + keccak256(payload)
+    pub fn build_extrinsic_signing_payload(payload: &[u8]) -> [u8; 32] {
+        let domain_separator = Self::build_domain_separator();
+        let mut args_hash = keccak256!("NativeExtrinsic(bytes payload)").to_vec();
+        args_hash.extend_from_slice(&keccak_256(payload));
+
+        let mut payload = b"\x19\x01".to_vec();
+        payload.extend_from_slice(&domain_separator);
+        payload.extend_from_slice(&keccak_256(args_hash.as_slice()));
+        keccak_256(&payload)
+    }
+
+    /// Verify the signature of a native extrinsic made by an evm key, given that the `signer`
+    /// is the account id the recovered evm address is mapped to, or its default one.
+    ///
+    /// The `payload` is the SCALE encoded signed payload of the extrinsic (hashed with blake2_256
+    /// if longer than 256 bytes), which includes the signer's nonce so the signature cannot be replayed.
+    ///
+    /// Signatures are rejected while an unlink of the `signer` is pending, since the evm key might
+    /// be compromised and must not be able to cancel the unlink.
+    pub fn verify_extrinsic_signature(
+        payload: &[u8],
+        sig: &EvmSignature,
+        signer: &T::AccountId,
+    ) -> bool {
+        if PendingUnlinks::<T>::contains_key(signer) {
+            return false;
+        }
+        let payload_hash = Self::build_extrinsic_signing_payload(payload);
+
+        sp_io::crypto::secp256k1_ecdsa_recover(sig, &payload_hash)
+            .map(|pubkey| H160::from(H256::from_slice(&keccak_256(&pubkey))))
+            .map(|evm_address| {
+                <Self as UnifiedAddressMapper<T::AccountId>>::to_account_id_or_default(&evm_address)
+                    .into_address()
+                    == *signer
+            })
+            .unwrap_or(false)
+    }
+
     pub fn verify_signature(who: &T::AccountId, sig: &EvmSignature) -> Option<EvmAddress> {
         let payload_hash = Self::build_signing_payload(who);
 
@@ -324,8 +899,30 @@ impl<T: Config> Pallet<T> {
     }
 
     fn build_args_hash(account: &T::AccountId) -> [u8; 32] {
-        let mut args_hash = keccak256!("Claim(bytes substrateAddress)").to_vec();
+        let nonce = ClaimNonces::<T>::get(account);
+        let mut args_hash = if nonce.is_zero() {
+            keccak256!("Claim(bytes substrateAddress)").to_vec()
+        } else {
+            keccak256!("Claim(bytes substrateAddress,uint256 nonce)").to_vec()
+        };
         args_hash.extend_from_slice(&keccak_256(&account.encode()));
+        if !nonce.is_zero() {
+            args_hash.extend_from_slice(&<[u8; 32]>::from(U256::from(nonce)));
+        }
+        keccak_256(args_hash.as_slice())
+    }
+
+    fn build_native_args_hash(evm_address: &EvmAddress, account: &T::AccountId) -> [u8; 32] {
+        let nonce = ClaimNonces::<T>::get(account);
+        let mut args_hash = if nonce.is_zero() {
+            keccak256!("NativeClaim(address evmAddress)").to_vec()
+        } else {
+            keccak256!("NativeClaim(address evmAddress,uint256 nonce)").to_vec()
+        };
+        args_hash.extend_from_slice(H256::from(*evm_address).as_bytes());
+        if !nonce.is_zero() {
+            args_hash.extend_from_slice(&<[u8; 32]>::from(U256::from(nonce)));
+        }
         keccak_256(args_hash.as_slice())
     }
 }
@@ -379,6 +976,122 @@ impl<T: Config> AddressMapping<T::AccountId> for Pallet<T> {
     }
 }
 
+/// Verifier of the signatures made by evm smart contract wallets
+pub trait ContractSignatureVerifier {
+    /// Check whether the contract accepts the `signature` for the given `hash`
+    fn is_valid_signature(contract: &EvmAddress, hash: &[u8; 32], signature: &[u8]) -> bool;
+
+    /// Check whether the address holds the code of a contract
+    fn is_contract(address: &EvmAddress) -> bool;
+
+    /// Max weight consumed by the signature verification
+    fn weight() -> Weight;
+
+    /// Make the given contract accept any signature
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_valid_contract(contract: &EvmAddress);
+}
+
+/// Rejects all signatures, effectively disabling smart contract wallet claims
+impl ContractSignatureVerifier for () {
+    fn is_valid_signature(_: &EvmAddress, _: &[u8; 32], _: &[u8]) -> bool {
+        false
+    }
+
+    fn is_contract(_: &EvmAddress) -> bool {
+        false
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_valid_contract(_: &EvmAddress) {}
+}
+
+/// EIP-1271 signature verification, calling `isValidSignature(bytes32,bytes)` of the contract
+/// through the EVM runner with at most `GasLimit` gas.
+///
+/// The call is made from the zero address and any state changes made by it are reverted.
+/// Precompiles and addresses without code are rejected, and the contract must return exactly
+/// the 32 bytes ABI encoded magic value, so that echoing the input is not mistaken for it.
+pub struct EvmContractSignatureVerifier<R, GasLimit>(PhantomData<(R, GasLimit)>);
+impl<R, GasLimit> ContractSignatureVerifier for EvmContractSignatureVerifier<R, GasLimit>
+where
+    R: pallet_evm::Config,
+    GasLimit: Get<u64>,
+{
+    fn is_valid_signature(contract: &EvmAddress, hash: &[u8; 32], signature: &[u8]) -> bool {
+        let is_precompile = !matches!(
+            R::PrecompilesValue::get().is_precompile(*contract, GasLimit::get()),
+            IsPrecompileResult::Answer {
+                is_precompile: false,
+                ..
+            }
+        );
+        if is_precompile || !Self::is_contract(contract) {
+            return false;
+        }
+
+        let input = Writer::new_with_selector(u32::from_be_bytes(EIP1271_MAGIC_VALUE))
+            .write(H256::from(*hash))
+            .write(UnboundedBytes::from(signature.to_vec()))
+            .build();
+
+        let result = with_transaction(|| {
+            let result = R::Runner::call(
+                H160::zero(),
+                *contract,
+                input,
+                U256::zero(),
+                GasLimit::get(),
+                None,
+                None,
+                None,
+                Vec::new(),
+                false,
+                false,
+                None,
+                None,
+                R::config(),
+            );
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+        });
+
+        // `bytes4` magic value, right padded to 32 bytes
+        let mut magic_value = [0u8; 32];
+        magic_value[..4].copy_from_slice(&EIP1271_MAGIC_VALUE);
+
+        match result {
+            Ok(Ok(info)) => info.exit_reason.is_succeed() && info.value == magic_value,
+            _ => false,
+        }
+    }
+
+    fn is_contract(address: &EvmAddress) -> bool {
+        pallet_evm::AccountCodes::<R>::decode_len(address).unwrap_or_default() > 0
+    }
+
+    fn weight() -> Weight {
+        R::GasWeightMapping::gas_to_weight(GasLimit::get(), true)
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn set_valid_contract(contract: &EvmAddress) {
+        // PUSH4 <magic value> PUSH1 0xe0 SHL PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+        let code = [
+            &[0x63][..],
+            &EIP1271_MAGIC_VALUE[..],
+            &[
+                0x60, 0xe0, 0x1b, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3,
+            ][..],
+        ]
+        .concat();
+        pallet_evm::AccountCodes::<R>::insert(contract, code);
+    }
+}
+
 /// OnKilledAccount hooks implementation for removing storage mapping
 /// for killed accounts
 pub struct KillAccountMapping<T>(PhantomData<T>);
@@ -389,6 +1102,8 @@ impl<T: Config> OnKilledAccount<T::AccountId> for KillAccountMapping<T> {
             EvmToNative::<T>::remove(evm_addr);
             NativeToEvm::<T>::remove(who);
         }
+        PendingUnlinks::<T>::remove(who);
+        // claim nonce is kept, so the claim signatures stay invalid if the account is recreated
     }
 }
 
@@ -0,0 +1,143 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    weights::WeightMeter,
+};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "mbm::unified-accounts";
+const PALLET_MIGRATIONS_ID: &[u8; 20] = b"unified-accounts-mbm";
+
+/// Multi-block migration which creates the default mappings for all the existing accounts.
+///
+/// Walks over `frame_system::Account` and inserts the double mappings between each account
+/// and its default evm address, unless either of them is already mapped. No storage fee is
+/// charged for the mappings created by the migration.
+///
+/// Accounts are also skipped if the default account id of their default evm address exists,
+/// since its funds would no longer be accessible once the evm address is mapped.
+///
+/// The pallet runs it in `on_idle` once started by the `ManagerOrigin` via
+/// `start_default_mappings_migration`, so the mappings aren't created without governance approval.
+/// With `try-runtime`, `pre_upgrade` is run before every step and `post_upgrade` once the last
+/// step completes the migration.
+pub struct LazyDefaultMappingMigration<T>(PhantomData<T>);
+
+impl<T: Config> SteppedMigration for LazyDefaultMappingMigration<T> {
+    type Cursor = <T as frame_system::Config>::AccountId;
+    // Without the explicit length here the construction of the ID would not be infallible.
+    type Identifier = MigrationId<20>;
+
+    /// The identifier of this migration. Which should be globally unique.
+    fn id() -> Self::Identifier {
+        MigrationId {
+            pallet_id: *PALLET_MIGRATIONS_ID,
+            version_from: 0,
+            version_to: 1,
+        }
+    }
+
+    fn step(
+        cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        let required = T::WeightInfo::default_mapping_migration_step();
+        // If there is not enough weight for a single step, return an error. This case can be
+        // problematic if it is the first migration that ran in this block. But there is nothing
+        // that we can do about it here.
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        let mut iter = if let Some(last_key) = cursor {
+            // If a cursor is provided, start iterating from the last processed account.
+            frame_system::Account::<T>::iter_keys_from(frame_system::Account::<T>::hashed_key_for(
+                last_key,
+            ))
+        } else {
+            // If no cursor is provided, start iterating from the beginning.
+            frame_system::Account::<T>::iter_keys()
+        };
+
+        let mut count = 0u32;
+        let mut next_cursor = None;
+
+        // We loop here to do as much progress as possible per step.
+        while meter.can_consume(required) {
+            let Some(account_id) = iter.next() else {
+                // Signal that the migration is complete (no more accounts to process).
+                next_cursor = None;
+                break;
+            };
+            meter.consume(required);
+
+            if Pallet::<T>::try_create_default_mapping(&account_id) {
+                count.saturating_inc();
+            }
+
+            // Return the processed account as the new cursor.
+            next_cursor = Some(account_id);
+        }
+
+        log::debug!(target: LOG_TARGET, "created {count:?} default mappings");
+        Ok(next_cursor)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let mappings = NativeToEvm::<T>::iter_keys().count() as u64;
+        Ok(mappings.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let prev_mappings = u64::decode(&mut &state[..])
+            .map_err(|_| TryRuntimeError::Other("failed to decode mappings count"))?;
+
+        let mut mappings = 0u64;
+        for (account_id, evm_address) in NativeToEvm::<T>::iter() {
+            ensure!(
+                EvmToNative::<T>::get(&evm_address) == Some(account_id),
+                "mappings are not consistent"
+            );
+            mappings.saturating_inc();
+        }
+        ensure!(
+            mappings >= prev_mappings,
+            "existing mappings must not be removed"
+        );
+
+        for account_id in frame_system::Account::<T>::iter_keys() {
+            let evm_address = T::DefaultMappings::to_default_h160(&account_id);
+            ensure!(
+                NativeToEvm::<T>::contains_key(&account_id)
+                    || EvmToNative::<T>::contains_key(&evm_address)
+                    || frame_system::Pallet::<T>::account_exists(
+                        &T::DefaultMappings::to_default_account_id(&evm_address)
+                    ),
+                "account is left unmapped"
+            );
+        }
+        Ok(())
+    }
+}
@@ -27,12 +27,13 @@ use frame_support::{
     weights::Weight,
 };
 use pallet_ethereum::PostLogContent;
-use pallet_evm::FeeCalculator;
+use pallet_evm::{FeeCalculator, Precompile, PrecompileHandle, PrecompileResult};
+use pallet_evm_precompile_simple::Identity;
 use sp_core::{keccak_256, H160, U256};
 use sp_io::TestExternalities;
 use sp_runtime::{
     traits::{AccountIdLookup, BlakeTwo256},
-    AccountId32, BuildStorage, ConsensusEngineId,
+    AccountId32, BuildStorage, ConsensusEngineId, DispatchError,
 };
 
 parameter_types! {
@@ -79,10 +80,33 @@ impl FindAuthor<H160> for MockFindAuthor {
     }
 }
 
+pub fn identity_precompile_address() -> H160 {
+    H160::from_low_u64_be(4)
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MockPrecompiles;
+impl PrecompileSet for MockPrecompiles {
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        match handle.code_address() {
+            a if a == identity_precompile_address() => Some(Identity::execute(handle)),
+            _ => None,
+        }
+    }
+
+    fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+        IsPrecompileResult::Answer {
+            is_precompile: address == identity_precompile_address(),
+            extra_cost: 0,
+        }
+    }
+}
+
 parameter_types! {
     pub WeightPerGas: Weight = Weight::from_parts(1, 0);
     pub const BlockGasLimit: U256 = U256::MAX;
     pub ChainId: u64 = 1024;
+    pub PrecompilesValue: MockPrecompiles = MockPrecompiles;
 }
 
 impl pallet_evm::Config for TestRuntime {
@@ -96,8 +120,8 @@ impl pallet_evm::Config for TestRuntime {
     type Currency = Balances;
     type RuntimeEvent = RuntimeEvent;
     type Runner = pallet_evm::runner::stack::Runner<Self>;
-    type PrecompilesType = ();
-    type PrecompilesValue = ();
+    type PrecompilesType = MockPrecompiles;
+    type PrecompilesValue = PrecompilesValue;
     type ChainId = ChainId;
     type OnChargeTransaction = ();
     type BlockGasLimit = BlockGasLimit;
@@ -122,17 +146,49 @@ impl pallet_ethereum::Config for TestRuntime {
 
 parameter_types! {
     pub const AccountMappingStorageFee: u128 = 100_000_000;
+    pub const ContractSignatureGasLimit: u64 = 100_000;
+    pub static FeePolicy: UnlinkFeePolicy = UnlinkFeePolicy::Burn;
 }
 
 impl pallet_unified_accounts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type OnAccountUnification = MockOnAccountUnification;
+    type ContractSignatureVerifier =
+        EvmContractSignatureVerifier<TestRuntime, ContractSignatureGasLimit>;
+    type MaxContractSignatureLength = ConstU32<1024>;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type UnlinkDelay = ConstU64<10>;
+    type UnlinkFeePolicy = FeePolicy;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub static RefuseUnification: bool = false;
+    pub static UnifiedAccountPairs: Vec<(AccountId, AccountId)> = vec![];
+}
+
+/// Records the unified account pairs, or refuses the unification if configured so.
+pub struct MockOnAccountUnification;
+impl OnAccountUnification<AccountId> for MockOnAccountUnification {
+    fn on_unification(default: &AccountId, unified: &AccountId) -> DispatchResult {
+        ensure!(
+            !RefuseUnification::get(),
+            DispatchError::Other("Unification refused.")
+        );
+        UnifiedAccountPairs::mutate(|pairs| pairs.push((default.clone(), unified.clone())));
+        Ok(())
+    }
+
+    fn weight() -> Weight {
+        Weight::zero()
+    }
+}
+
 pub(crate) type AccountId = AccountId32;
 pub(crate) type Balance = u128;
 
@@ -148,6 +204,41 @@ pub fn bob_secret() -> libsecp256k1::SecretKey {
     libsecp256k1::SecretKey::parse(&keccak_256(b"Bob")).unwrap()
 }
 
+/// Code of a smart contract wallet accepting only the given hash, regardless of the signature.
+pub fn eip1271_wallet_code(valid_hash: [u8; 32]) -> Vec<u8> {
+    // PUSH32 <valid hash> PUSH1 0x04 CALLDATALOAD EQ PUSH1 0x2d JUMPI
+    let mut code = vec![0x7f];
+    code.extend_from_slice(&valid_hash);
+    code.extend_from_slice(&[0x60, 0x04, 0x35, 0x14, 0x60, 0x2d, 0x57]);
+    // PUSH1 0x00 PUSH1 0x00 RETURN
+    code.extend_from_slice(&[0x60, 0x00, 0x60, 0x00, 0xf3]);
+    // JUMPDEST PUSH4 <magic value> PUSH1 0xe0 SHL PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+    code.extend_from_slice(&[0x5b, 0x63]);
+    code.extend_from_slice(&EIP1271_MAGIC_VALUE);
+    code.extend_from_slice(&[
+        0x60, 0xe0, 0x1b, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3,
+    ]);
+    code
+}
+
+/// Code of a smart contract which returns its call data, i.e. the call selector followed by the arguments.
+pub fn echo_contract_code() -> Vec<u8> {
+    // CALLDATASIZE PUSH1 0x00 PUSH1 0x00 CALLDATACOPY CALLDATASIZE PUSH1 0x00 RETURN
+    vec![0x36, 0x60, 0x00, 0x60, 0x00, 0x37, 0x36, 0x60, 0x00, 0xf3]
+}
+
+/// Code of a smart contract wallet which never stops executing, i.e. runs out of gas.
+pub fn out_of_gas_wallet_code() -> Vec<u8> {
+    // JUMPDEST PUSH1 0x00 JUMP
+    vec![0x5b, 0x60, 0x00, 0x56]
+}
+
+pub fn deploy_wallet(address: H160, code: Vec<u8>) {
+    // as done by `pallet-evm`, contract code takes a sufficient reference of its account
+    System::inc_sufficients(&UnifiedAccounts::to_default_account_id(&address));
+    pallet_evm::AccountCodes::<TestRuntime>::insert(address, code);
+}
+
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 
 construct_runtime!(
@@ -19,15 +19,15 @@
 #![cfg(test)]
 
 use super::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::fungible::InspectHold};
 use mock::*;
 
 use ethers::{
     contract::{Eip712, EthAbiType},
-    core::types::{transaction::eip712::Eip712, Bytes},
+    core::types::{transaction::eip712::Eip712, Address, Bytes, U256 as EthU256},
 };
 use parity_scale_codec::Encode;
-use sp_runtime::{traits::StaticLookup, AccountId32, MultiAddress};
+use sp_runtime::{traits::StaticLookup, AccountId32, DispatchError, MultiAddress};
 
 /// EIP712 Payload struct
 #[derive(Eip712, EthAbiType, Clone)]
@@ -42,17 +42,80 @@ struct Claim {
     substrate_address: Bytes,
 }
 
+/// EIP712 Payload struct for claims from the evm side
+#[derive(Eip712, EthAbiType, Clone)]
+#[eip712(
+        name = "Astar EVM Claim",
+        version = "1",
+        chain_id = 1024,
+        // mock genisis hash
+        raw_salt = "0x4545454545454545454545454545454545454545454545454545454545454545"
+    )]
+struct NativeClaim {
+    evm_address: Address,
+}
+
+/// EIP712 Payload structs bound to the claim nonce, used once the account has been unlinked
+mod relink {
+    use super::*;
+
+    #[derive(Eip712, EthAbiType, Clone)]
+    #[eip712(
+        name = "Astar EVM Claim",
+        version = "1",
+        chain_id = 1024,
+        // mock genisis hash
+        raw_salt = "0x4545454545454545454545454545454545454545454545454545454545454545"
+    )]
+    pub struct Claim {
+        pub substrate_address: Bytes,
+        pub nonce: EthU256,
+    }
+
+    #[derive(Eip712, EthAbiType, Clone)]
+    #[eip712(
+        name = "Astar EVM Claim",
+        version = "1",
+        chain_id = 1024,
+        // mock genisis hash
+        raw_salt = "0x4545454545454545454545454545454545454545454545454545454545454545"
+    )]
+    pub struct NativeClaim {
+        pub evm_address: Address,
+        pub nonce: EthU256,
+    }
+}
+
+/// EIP712 Payload struct for native extrinsics signed by evm keys
+#[derive(Eip712, EthAbiType, Clone)]
+#[eip712(
+        name = "Astar EVM Claim",
+        version = "1",
+        chain_id = 1024,
+        // mock genisis hash
+        raw_salt = "0x4545454545454545454545454545454545454545454545454545454545454545"
+    )]
+struct NativeExtrinsic {
+    payload: Bytes,
+}
+
 /// Build the signature payload for given native account and eth private key
 fn get_evm_signature(who: &AccountId32, secret: &libsecp256k1::SecretKey) -> [u8; 65] {
-    // sign the payload
-    UnifiedAccounts::eth_sign_prehash(
-        &Claim {
+    let nonce = ClaimNonces::<TestRuntime>::get(who);
+    let payload = if nonce == 0 {
+        Claim {
+            substrate_address: who.encode().into(),
+        }
+        .encode_eip712()
+    } else {
+        relink::Claim {
             substrate_address: who.encode().into(),
+            nonce: nonce.into(),
         }
         .encode_eip712()
-        .unwrap(),
-        secret,
-    )
+    };
+    // sign the payload
+    UnifiedAccounts::eth_sign_prehash(&payload.unwrap(), secret)
 }
 
 /// Create the mappings for the accounts
@@ -89,6 +152,109 @@ fn eip712_signature_verify_works() {
     });
 }
 
+#[test]
+fn native_eip712_signing_payload_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let claim = NativeClaim {
+            evm_address: Address::from_slice(alice_eth.as_bytes()),
+        };
+
+        // assert signing payload is correct
+        assert_eq!(
+            claim.encode_eip712().unwrap(),
+            UnifiedAccounts::build_native_signing_payload(&alice_eth, &ALICE),
+            "signing payload should match"
+        );
+    });
+}
+
+#[test]
+fn relink_eip712_signing_payloads_include_claim_nonce() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let first_claim_payload = UnifiedAccounts::build_signing_payload(&ALICE);
+        ClaimNonces::<TestRuntime>::insert(&ALICE, 3);
+
+        // assert signing payloads are bound to the claim nonce
+        assert_eq!(
+            relink::Claim {
+                substrate_address: ALICE.encode().into(),
+                nonce: 3.into(),
+            }
+            .encode_eip712()
+            .unwrap(),
+            UnifiedAccounts::build_signing_payload(&ALICE),
+            "signing payload should match"
+        );
+        assert_eq!(
+            relink::NativeClaim {
+                evm_address: Address::from_slice(alice_eth.as_bytes()),
+                nonce: 3.into(),
+            }
+            .encode_eip712()
+            .unwrap(),
+            UnifiedAccounts::build_native_signing_payload(&alice_eth, &ALICE),
+            "signing payload should match"
+        );
+        assert_ne!(
+            UnifiedAccounts::build_signing_payload(&ALICE),
+            first_claim_payload
+        );
+    });
+}
+
+#[test]
+fn extrinsic_eip712_signature_verify_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let payload = b"signed extrinsic payload".to_vec();
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // assert signing payload is correct
+        let payload_hash = UnifiedAccounts::build_extrinsic_signing_payload(&payload);
+        assert_eq!(
+            NativeExtrinsic {
+                payload: payload.clone().into(),
+            }
+            .encode_eip712()
+            .unwrap(),
+            payload_hash,
+            "signing payload should match"
+        );
+        let sig = UnifiedAccounts::eth_sign_prehash(&payload_hash, &alice_secret());
+
+        // unmapped evm address signs for its default account id
+        assert!(UnifiedAccounts::verify_extrinsic_signature(
+            &payload,
+            &sig,
+            &alice_eth_old_account
+        ));
+        assert!(!UnifiedAccounts::verify_extrinsic_signature(
+            &payload, &sig, &ALICE
+        ));
+
+        // mapped evm address signs for the account it's mapped to
+        connect_accounts(&ALICE, &alice_secret());
+        assert!(UnifiedAccounts::verify_extrinsic_signature(
+            &payload, &sig, &ALICE
+        ));
+        assert!(!UnifiedAccounts::verify_extrinsic_signature(
+            &payload,
+            &sig,
+            &alice_eth_old_account
+        ));
+
+        // signature is bound to the payload
+        assert!(!UnifiedAccounts::verify_extrinsic_signature(
+            b"another payload",
+            &sig,
+            &ALICE
+        ));
+    });
+}
+
 #[test]
 fn static_lookup_works() {
     ExtBuilder::default().build().execute_with(|| {
@@ -397,3 +563,898 @@ fn charging_storage_fee_should_not_reap_account() {
         );
     });
 }
+
+#[test]
+fn account_claim_moves_default_account_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // transfer some funds to alice_eth (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+
+        // claim the account, unification hooks must be called for the default account
+        connect_accounts(&ALICE, &alice_secret());
+        assert_eq!(
+            UnifiedAccountPairs::get(),
+            vec![(alice_eth_old_account, ALICE)]
+        );
+
+        // default account of bob_eth doesn't exist, so no state to move
+        connect_accounts(&BOB, &bob_secret());
+        assert_eq!(UnifiedAccountPairs::get().len(), 1);
+    });
+}
+
+#[test]
+fn account_claim_should_not_work_if_unification_refused() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // transfer some funds to alice_eth (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+
+        // default account state cannot be moved, claim must fail without any side effects
+        RefuseUnification::set(true);
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+            ),
+            DispatchError::Other("Unification refused.")
+        );
+        assert_eq!(Balances::free_balance(&alice_eth_old_account), 1001);
+        assert!(EvmToNative::<TestRuntime>::get(alice_eth).is_none());
+        assert!(NativeToEvm::<TestRuntime>::get(ALICE).is_none());
+    });
+}
+
+#[test]
+fn account_claim_should_not_work_if_default_account_holds_unmovable_state() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // transfer some funds to alice_eth (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+
+        // e.g. balance of a sufficient asset
+        System::inc_sufficients(&alice_eth_old_account);
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+            ),
+            Error::<TestRuntime>::DefaultAccountNotEmpty
+        );
+        System::dec_sufficients(&alice_eth_old_account);
+
+        // e.g. balance of a non-sufficient asset
+        assert_ok!(System::inc_consumers(&alice_eth_old_account));
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+            ),
+            Error::<TestRuntime>::DefaultAccountNotEmpty
+        );
+        System::dec_consumers(&alice_eth_old_account);
+
+        // once the state is moved by the user, the claim works
+        connect_accounts(&ALICE, &alice_secret());
+        assert!(!System::account_exists(&alice_eth_old_account));
+    });
+}
+
+#[test]
+fn default_account_id_claim_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+
+        // transfer some funds to alice_eth (H160) for the storage fee
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            AccountMappingStorageFee::get() * 2
+        ));
+
+        // claim the default account id
+        assert_eq!(
+            UnifiedAccounts::do_claim_default_account_id(alice_eth),
+            Ok(alice_eth_old_account.clone())
+        );
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountClaimed {
+                account_id: alice_eth_old_account.clone(),
+                evm_address: alice_eth.clone(),
+            },
+        ));
+
+        // make sure mappings are in place
+        assert_eq!(
+            EvmToNative::<TestRuntime>::get(alice_eth),
+            Some(alice_eth_old_account.clone())
+        );
+        assert_eq!(
+            NativeToEvm::<TestRuntime>::get(&alice_eth_old_account),
+            Some(alice_eth)
+        );
+
+        // cannot claim twice
+        assert_noop!(
+            UnifiedAccounts::do_claim_default_account_id(alice_eth),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn contract_account_claim_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0x12);
+        let wallet_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&wallet);
+        deploy_wallet(
+            wallet,
+            eip1271_wallet_code(UnifiedAccounts::build_signing_payload(&ALICE)),
+        );
+
+        // transfer some funds to the wallet (H160)
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            wallet_old_account.clone().into(),
+            1001
+        ));
+
+        // claim the wallet
+        assert_ok!(UnifiedAccounts::claim_evm_contract_address(
+            RuntimeOrigin::signed(ALICE),
+            wallet,
+            vec![1u8; 130].try_into().unwrap()
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountClaimed {
+                account_id: ALICE.clone(),
+                evm_address: wallet,
+            },
+        ));
+
+        // state of the default account is moved
+        assert_eq!(Balances::free_balance(&wallet_old_account), 0);
+        assert_eq!(
+            UnifiedAccountPairs::get(),
+            vec![(wallet_old_account, ALICE)]
+        );
+
+        // make sure mappings are in place
+        assert_eq!(EvmToNative::<TestRuntime>::get(wallet), Some(ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(wallet));
+    });
+}
+
+#[test]
+fn contract_account_claim_should_not_work_if_signature_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0x12);
+        deploy_wallet(
+            wallet,
+            eip1271_wallet_code(UnifiedAccounts::build_signing_payload(&ALICE)),
+        );
+
+        // wallet only accepts the claim payload of alice
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(BOB),
+                wallet,
+                vec![1u8; 65].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        // addresses without code cannot be claimed this way, even if signed by the owner
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                get_evm_signature(&ALICE, &alice_secret())
+                    .to_vec()
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+
+        // already mapped wallet cannot be claimed again
+        assert_ok!(UnifiedAccounts::claim_evm_contract_address(
+            RuntimeOrigin::signed(ALICE),
+            wallet,
+            Default::default()
+        ));
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                Default::default()
+            ),
+            Error::<TestRuntime>::AlreadyMapped
+        );
+    });
+}
+
+#[test]
+fn contract_account_claim_should_not_work_for_precompiles() {
+    ExtBuilder::default().build().execute_with(|| {
+        let identity = identity_precompile_address();
+        // precompiles may hold the revert code in live chains
+        deploy_wallet(identity, vec![0x60, 0x00, 0x60, 0x00, 0xfd]);
+
+        // identity precompile echoes the input, which starts with the magic value
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                identity,
+                vec![1u8; 65].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert!(!EvmContractSignatureVerifier::<
+            TestRuntime,
+            ContractSignatureGasLimit,
+        >::is_valid_signature(
+            &identity,
+            &UnifiedAccounts::build_signing_payload(&ALICE),
+            &[1u8; 65]
+        ));
+    });
+}
+
+#[test]
+fn contract_account_claim_should_not_work_for_echoed_magic_value() {
+    ExtBuilder::default().build().execute_with(|| {
+        let contract = H160::repeat_byte(0x12);
+        deploy_wallet(contract, echo_contract_code());
+
+        // returned data starts with the magic value, but is not exactly the encoded magic value
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                contract,
+                vec![1u8; 65].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                contract,
+                Default::default()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn contract_account_claim_is_gas_bounded() {
+    ExtBuilder::default().build().execute_with(|| {
+        let wallet = H160::repeat_byte(0x12);
+        deploy_wallet(wallet, out_of_gas_wallet_code());
+
+        // verification runs out of gas, signature is treated as invalid
+        assert_noop!(
+            UnifiedAccounts::claim_evm_contract_address(
+                RuntimeOrigin::signed(ALICE),
+                wallet,
+                vec![1u8; 65].try_into().unwrap()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+    });
+}
+
+#[test]
+fn force_unlink_account_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+        let alice_balance = Balances::free_balance(&ALICE);
+
+        // only manager origin can force unlink
+        assert_noop!(
+            UnifiedAccounts::force_unlink_account(RuntimeOrigin::signed(ALICE), ALICE),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+                refunded_fee: 0,
+            },
+        ));
+
+        // mappings are removed, storage fee stays burned
+        assert!(EvmToNative::<TestRuntime>::get(alice_eth).is_none());
+        assert!(NativeToEvm::<TestRuntime>::get(ALICE).is_none());
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance);
+
+        assert_noop!(
+            UnifiedAccounts::force_unlink_account(RuntimeOrigin::root(), ALICE),
+            Error::<TestRuntime>::NotMapped
+        );
+    });
+}
+
+#[test]
+fn unlink_refunds_storage_fee_by_policy() {
+    ExtBuilder::default().build().execute_with(|| {
+        FeePolicy::set(UnlinkFeePolicy::Refund);
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_balance = Balances::free_balance(&ALICE);
+        let fee = AccountMappingStorageFee::get();
+        connect_accounts(&ALICE, &alice_secret());
+
+        // storage fee is held on the account
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance - fee);
+        assert_eq!(
+            Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE),
+            fee
+        );
+
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+                refunded_fee: fee,
+            },
+        ));
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance);
+        assert!(Balances::balance_on_hold(&HoldReason::AccountMapping.into(), &ALICE).is_zero());
+
+        // burned fee is not refunded, even if the policy has changed since the claim
+        FeePolicy::set(UnlinkFeePolicy::Burn);
+        connect_accounts(&ALICE, &alice_secret());
+        FeePolicy::set(UnlinkFeePolicy::Refund);
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+                refunded_fee: 0,
+            },
+        ));
+        assert_eq!(Balances::free_balance(&ALICE), alice_balance - fee);
+    });
+}
+
+#[test]
+fn self_unlink_works_after_delay() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+
+        // account must be mapped
+        assert_noop!(
+            UnifiedAccounts::request_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::NotMapped
+        );
+        connect_accounts(&ALICE, &alice_secret());
+
+        assert_ok!(UnifiedAccounts::request_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::UnlinkRequested {
+                account_id: ALICE,
+                evm_address: alice_eth,
+                unlockable_at: 11,
+            },
+        ));
+        assert_eq!(PendingUnlinks::<TestRuntime>::get(ALICE), Some(11));
+        assert_noop!(
+            UnifiedAccounts::request_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkAlreadyRequested
+        );
+
+        // unlink delay must pass
+        System::set_block_number(10);
+        assert_noop!(
+            UnifiedAccounts::unlink_account(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkDelayNotPassed
+        );
+        // only the requester's mappings can be unlinked
+        assert_noop!(
+            UnifiedAccounts::unlink_account(RuntimeOrigin::signed(BOB)),
+            Error::<TestRuntime>::UnlinkNotRequested
+        );
+
+        System::set_block_number(11);
+        assert_ok!(UnifiedAccounts::unlink_account(RuntimeOrigin::signed(
+            ALICE
+        )));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::AccountUnlinked {
+                account_id: ALICE,
+                evm_address: alice_eth,
+                refunded_fee: 0,
+            },
+        ));
+        assert!(PendingUnlinks::<TestRuntime>::get(ALICE).is_none());
+        assert!(EvmToNative::<TestRuntime>::get(alice_eth).is_none());
+        assert!(NativeToEvm::<TestRuntime>::get(ALICE).is_none());
+    });
+}
+
+#[test]
+fn cancel_unlink_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+
+        assert_noop!(
+            UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkNotRequested
+        );
+        assert_ok!(UnifiedAccounts::request_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        assert_ok!(UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::UnlinkCancelled { account_id: ALICE },
+        ));
+
+        // cancelled request cannot be executed
+        System::set_block_number(11);
+        assert_noop!(
+            UnifiedAccounts::unlink_account(RuntimeOrigin::signed(ALICE)),
+            Error::<TestRuntime>::UnlinkNotRequested
+        );
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), Some(ALICE));
+    });
+}
+
+#[test]
+fn unlinked_accounts_can_be_claimed_again() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let bob_eth = UnifiedAccounts::eth_address(&bob_secret());
+        connect_accounts(&ALICE, &alice_secret());
+        connect_accounts(&BOB, &bob_secret());
+
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            BOB
+        ));
+
+        // swap the evm addresses
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            bob_eth,
+            get_evm_signature(&ALICE, &bob_secret())
+        ));
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(BOB),
+            alice_eth,
+            get_evm_signature(&BOB, &alice_secret())
+        ));
+
+        assert_eq!(EvmToNative::<TestRuntime>::get(bob_eth), Some(ALICE));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(ALICE), Some(bob_eth));
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), Some(BOB));
+        assert_eq!(NativeToEvm::<TestRuntime>::get(BOB), Some(alice_eth));
+    });
+}
+
+#[test]
+fn claim_signatures_cannot_be_replayed_after_unlink() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let signature = get_evm_signature(&ALICE, &alice_secret());
+        let native_payload = UnifiedAccounts::build_native_signing_payload(&alice_eth, &ALICE);
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            alice_eth,
+            signature,
+            Default::default()
+        ));
+
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        assert_eq!(ClaimNonces::<TestRuntime>::get(&ALICE), 1);
+
+        // previous signatures are no longer valid for the account
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alice_eth,
+                signature,
+                Default::default()
+            ),
+            Error::<TestRuntime>::InvalidSignature
+        );
+        assert_ne!(
+            UnifiedAccounts::build_native_signing_payload(&alice_eth, &ALICE),
+            native_payload
+        );
+
+        // a fresh signature works
+        connect_accounts(&ALICE, &alice_secret());
+        assert_eq!(EvmToNative::<TestRuntime>::get(alice_eth), Some(ALICE));
+    });
+}
+
+#[test]
+fn unlink_and_claim_carry_over_evm_nonce() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+        connect_accounts(&ALICE, &alice_secret());
+
+        // evm transactions signed by alice_eth increment alice's nonce
+        for _ in 0..5 {
+            System::inc_account_nonce(&ALICE);
+        }
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+
+        // alice_eth falls back to its default account, which must not reuse the nonces
+        assert_eq!(System::account_nonce(&alice_eth_old_account), 5);
+
+        // same for the newly claiming account
+        assert_eq!(System::account_nonce(&BOB), 0);
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(BOB),
+            alice_eth,
+            get_evm_signature(&BOB, &alice_secret())
+        ));
+        assert_eq!(System::account_nonce(&BOB), 5);
+    });
+}
+
+#[test]
+fn unlinked_default_account_is_kept_alive_until_claimed() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+        connect_accounts(&ALICE, &alice_secret());
+        System::inc_account_nonce(&ALICE);
+
+        // default account is created to hold the nonce, even without any balance
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+        assert!(System::account_exists(&alice_eth_old_account));
+        assert_eq!(System::account_nonce(&alice_eth_old_account), 1);
+        assert!(UnlinkedDefaultAccounts::<TestRuntime>::contains_key(
+            &alice_eth_old_account
+        ));
+
+        // funds can be moved out of it without reaping it
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1001
+        ));
+        assert_ok!(Balances::transfer_all(
+            RuntimeOrigin::signed(alice_eth_old_account.clone()),
+            BOB.into(),
+            false
+        ));
+        assert_eq!(System::account_nonce(&alice_eth_old_account), 1);
+
+        // once the evm address is claimed again, the default account is released
+        connect_accounts(&ALICE, &alice_secret());
+        assert!(!System::account_exists(&alice_eth_old_account));
+        assert!(!UnlinkedDefaultAccounts::<TestRuntime>::contains_key(
+            &alice_eth_old_account
+        ));
+        assert_eq!(System::account_nonce(&ALICE), 1);
+    });
+}
+
+#[test]
+fn evm_transactions_cannot_be_replayed_after_unlink() {
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let alice_eth_old_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&alice_eth);
+        connect_accounts(&ALICE, &alice_secret());
+
+        // evm transactions signed by alice_eth increment alice's nonce
+        for _ in 0..3 {
+            System::inc_account_nonce(&ALICE);
+        }
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            ALICE
+        ));
+
+        // anyone can fund the default account after the unlink
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            alice_eth_old_account.clone().into(),
+            1_000_000
+        ));
+        assert_eq!(
+            pallet_evm::Pallet::<TestRuntime>::account_basic(&alice_eth)
+                .0
+                .nonce,
+            3.into()
+        );
+
+        // replaying a transaction signed in the past is rejected by the nonce check
+        let result = <TestRuntime as pallet_evm::Config>::Runner::call(
+            alice_eth,
+            H160::repeat_byte(0x12),
+            vec![],
+            U256::zero(),
+            21_000,
+            Some(U256::one()),
+            None,
+            Some(U256::zero()),
+            vec![],
+            true,
+            true,
+            None,
+            None,
+            <TestRuntime as pallet_evm::Config>::config(),
+        );
+        assert!(matches!(
+            result,
+            Err(e) if matches!(e.error, pallet_evm::Error::<TestRuntime>::InvalidNonce)
+        ));
+    });
+}
+
+#[test]
+fn evm_key_cannot_cancel_pending_unlink() {
+    ExtBuilder::default().build().execute_with(|| {
+        let payload = b"cancel unlink payload".to_vec();
+        let sig = UnifiedAccounts::eth_sign_prehash(
+            &UnifiedAccounts::build_extrinsic_signing_payload(&payload),
+            &alice_secret(),
+        );
+        connect_accounts(&ALICE, &alice_secret());
+        assert!(UnifiedAccounts::verify_extrinsic_signature(
+            &payload, &sig, &ALICE
+        ));
+
+        // extrinsics signed by the evm key are rejected while the unlink is pending
+        assert_ok!(UnifiedAccounts::request_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        assert!(!UnifiedAccounts::verify_extrinsic_signature(
+            &payload, &sig, &ALICE
+        ));
+
+        // native key can still cancel it, after which evm key signatures are accepted again
+        assert_ok!(UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)));
+        assert!(UnifiedAccounts::verify_extrinsic_signature(
+            &payload, &sig, &ALICE
+        ));
+    });
+}
+
+#[test]
+fn killed_account_removes_pending_unlink() {
+    ExtBuilder::default().build().execute_with(|| {
+        connect_accounts(&ALICE, &alice_secret());
+        assert_ok!(UnifiedAccounts::request_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+
+        // kill alice, pending unlink must be removed along with the mappings
+        Balances::set_balance(&ALICE, 0);
+        assert!(PendingUnlinks::<TestRuntime>::get(ALICE).is_none());
+    });
+}
+
+#[test]
+fn default_mapping_migration_works() {
+    use frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+    use migration::LazyDefaultMappingMigration;
+
+    ExtBuilder::default().build().execute_with(|| {
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        connect_accounts(&ALICE, &alice_secret());
+        let balances: Vec<_> = [ALICE, BOB, CHARLIE]
+            .iter()
+            .map(|who| Balances::free_balance(who))
+            .collect();
+
+        // not enough weight for a single step
+        let step_weight = <TestRuntime as Config>::WeightInfo::default_mapping_migration_step();
+        assert!(LazyDefaultMappingMigration::<TestRuntime>::step(
+            None,
+            &mut WeightMeter::with_limit(Weight::zero())
+        )
+        .is_err());
+
+        // one account per step, resumed via cursor
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            cursor = LazyDefaultMappingMigration::<TestRuntime>::step(
+                cursor,
+                &mut WeightMeter::with_limit(step_weight),
+            )
+            .unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        // the last step finds no more accounts
+        assert_eq!(
+            steps,
+            frame_system::Account::<TestRuntime>::iter_keys().count() + 1
+        );
+
+        // existing mapping is kept
+        assert_eq!(NativeToEvm::<TestRuntime>::get(&ALICE), Some(alice_eth));
+        assert_eq!(EvmToNative::<TestRuntime>::get(&alice_eth), Some(ALICE));
+
+        // default mappings are created for the rest without any fee
+        for who in [BOB, CHARLIE] {
+            let default_eth = <TestRuntime as Config>::DefaultMappings::to_default_h160(&who);
+            assert_eq!(NativeToEvm::<TestRuntime>::get(&who), Some(default_eth));
+            assert_eq!(EvmToNative::<TestRuntime>::get(&default_eth), Some(who));
+        }
+        assert_eq!(
+            [ALICE, BOB, CHARLIE]
+                .iter()
+                .map(|who| Balances::free_balance(who))
+                .collect::<Vec<_>>(),
+            balances
+        );
+
+        // running it again does nothing
+        let mappings = NativeToEvm::<TestRuntime>::iter_keys().count();
+        assert_eq!(
+            LazyDefaultMappingMigration::<TestRuntime>::step(None, &mut WeightMeter::new())
+                .unwrap(),
+            None
+        );
+        assert_eq!(NativeToEvm::<TestRuntime>::iter_keys().count(), mappings);
+    });
+}
+
+#[test]
+fn default_mapping_migration_skips_funded_default_accounts() {
+    use frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+    use migration::LazyDefaultMappingMigration;
+
+    ExtBuilder::default().build().execute_with(|| {
+        // default account id of bob's default evm address holds funds
+        let bob_default_eth = <TestRuntime as Config>::DefaultMappings::to_default_h160(&BOB);
+        let reverse_account =
+            <TestRuntime as Config>::DefaultMappings::to_default_account_id(&bob_default_eth);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(CHARLIE),
+            reverse_account.clone().into(),
+            1001
+        ));
+
+        assert_eq!(
+            LazyDefaultMappingMigration::<TestRuntime>::step(None, &mut WeightMeter::new())
+                .unwrap(),
+            None
+        );
+
+        // bob is skipped, so the funds stay accessible by bob's default evm address
+        assert!(NativeToEvm::<TestRuntime>::get(&BOB).is_none());
+        assert!(EvmToNative::<TestRuntime>::get(&bob_default_eth).is_none());
+        assert!(NativeToEvm::<TestRuntime>::get(&ALICE).is_some());
+    });
+}
+
+#[test]
+fn default_mappings_migration_is_started_by_manager() {
+    ExtBuilder::default().build().execute_with(|| {
+        let step_weight = <TestRuntime as Config>::WeightInfo::default_mapping_migration_step();
+        // enough for the cursor overhead and a single account per block
+        let idle_weight = step_weight.saturating_add(
+            <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(1, 1),
+        );
+
+        // nothing happens until the migration is started
+        UnifiedAccounts::on_idle(1, idle_weight);
+        assert!(NativeToEvm::<TestRuntime>::get(&BOB).is_none());
+
+        assert_noop!(
+            UnifiedAccounts::start_default_mappings_migration(RuntimeOrigin::signed(ALICE)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(UnifiedAccounts::start_default_mappings_migration(
+            RuntimeOrigin::root()
+        ));
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::DefaultMappingsMigrationStarted,
+        ));
+        assert_noop!(
+            UnifiedAccounts::start_default_mappings_migration(RuntimeOrigin::root()),
+            Error::<TestRuntime>::MigrationInProgress
+        );
+
+        // not enough weight for the overhead, nothing is done
+        assert_eq!(UnifiedAccounts::on_idle(1, Weight::zero()), Weight::zero());
+        // not enough weight for a single step, retried later
+        assert_eq!(
+            UnifiedAccounts::on_idle(1, idle_weight.saturating_sub(step_weight)),
+            <TestRuntime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+        );
+        assert_eq!(
+            DefaultMappingsMigrationCursor::<TestRuntime>::get(),
+            Some(None)
+        );
+
+        // accounts are processed in idle time until completed
+        let accounts = frame_system::Account::<TestRuntime>::iter_keys().count();
+        for block in 1..=accounts as u64 {
+            UnifiedAccounts::on_idle(block, idle_weight);
+            assert!(DefaultMappingsMigrationCursor::<TestRuntime>::exists());
+        }
+        UnifiedAccounts::on_idle(accounts as u64 + 1, idle_weight);
+        assert!(!DefaultMappingsMigrationCursor::<TestRuntime>::exists());
+        System::assert_last_event(RuntimeEvent::UnifiedAccounts(
+            crate::Event::DefaultMappingsMigrationCompleted,
+        ));
+
+        for who in [ALICE, BOB, CHARLIE] {
+            assert_eq!(
+                NativeToEvm::<TestRuntime>::get(&who),
+                Some(<TestRuntime as Config>::DefaultMappings::to_default_h160(
+                    &who
+                ))
+            );
+        }
+    });
+}
@@ -50,10 +50,17 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn claim_evm_address() -> Weight;
 	fn claim_default_evm_address() -> Weight;
+	fn claim_evm_contract_address() -> Weight;
+	fn force_unlink_account() -> Weight;
+	fn request_unlink() -> Weight;
+	fn cancel_unlink() -> Weight;
+	fn unlink_account() -> Weight;
 	fn to_account_id() -> Weight;
 	fn to_account_id_or_default() -> Weight;
 	fn to_h160() -> Weight;
 	fn to_h160_or_default() -> Weight;
+	fn default_mapping_migration_step() -> Weight;
+	fn start_default_mappings_migration() -> Weight;
 }
 
 /// Weights for pallet_unified_accounts using the Substrate node and recommended hardware.
@@ -69,27 +76,126 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:0)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256`
 		//  Estimated: `3593`
 		// Minimum execution time: 91_231_000 picoseconds.
 		Weight::from_parts(91_688_000, 3593)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3533`
 		// Minimum execution time: 40_749_000 picoseconds.
 		Weight::from_parts(41_411_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:0)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn claim_evm_contract_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431`
+		//  Estimated: `6196`
+		// Minimum execution time: 83_107_000 picoseconds.
+		Weight::from_parts(84_362_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:0 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:1)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn force_unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `325`
+		//  Estimated: `6196`
+		// Minimum execution time: 42_175_000 picoseconds.
+		Weight::from_parts(43_018_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn request_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170`
+		//  Estimated: `3533`
+		// Minimum execution time: 13_214_000 picoseconds.
+		Weight::from_parts(13_637_000, 3533)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3517`
+		// Minimum execution time: 10_342_000 picoseconds.
+		Weight::from_parts(10_716_000, 3517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:1)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `373`
+		//  Estimated: `6196`
+		// Minimum execution time: 46_790_000 picoseconds.
+		Weight::from_parts(47_508_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
@@ -131,6 +237,32 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(5_719_000, 3533)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 	}
+	/// Storage: System Account (r:3 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn default_mapping_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `8799`
+		// Minimum execution time: 20_918_000 picoseconds.
+		Weight::from_parts(21_386_000, 8799)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: UnifiedAccounts DefaultMappingsMigrationCursor (r:1 w:1)
+	/// Proof: UnifiedAccounts DefaultMappingsMigrationCursor (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	fn start_default_mappings_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1518`
+		// Minimum execution time: 8_125_000 picoseconds.
+		Weight::from_parts(8_407_000, 1518)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -145,27 +277,126 @@ impl WeightInfo for () {
 	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:0)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
 	fn claim_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `256`
 		//  Estimated: `3593`
 		// Minimum execution time: 91_231_000 picoseconds.
 		Weight::from_parts(91_688_000, 3593)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
 	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
 	fn claim_default_evm_address() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `3533`
 		// Minimum execution time: 40_749_000 picoseconds.
 		Weight::from_parts(41_411_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: EVMChainId ChainId (r:1 w:0)
+	/// Proof: EVMChainId ChainId (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: EVM AccountCodes (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodes (max_values: None, max_size: None, mode: Measured)
+	/// Storage: EVM AccountCodesMetadata (r:1 w:0)
+	/// Proof Skipped: EVM AccountCodesMetadata (max_values: None, max_size: None, mode: Measured)
+	/// Storage: System Account (r:2 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:0)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn claim_evm_contract_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431`
+		//  Estimated: `6196`
+		// Minimum execution time: 83_107_000 picoseconds.
+		Weight::from_parts(84_362_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:0 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:1)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn force_unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `325`
+		//  Estimated: `6196`
+		// Minimum execution time: 42_175_000 picoseconds.
+		Weight::from_parts(43_018_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:0)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn request_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170`
+		//  Estimated: `3533`
+		// Minimum execution time: 13_214_000 picoseconds.
+		Weight::from_parts(13_637_000, 3533)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn cancel_unlink() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `95`
+		//  Estimated: `3517`
+		// Minimum execution time: 10_342_000 picoseconds.
+		Weight::from_parts(10_716_000, 3517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:0 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts PendingUnlinks (r:1 w:1)
+	/// Proof: UnifiedAccounts PendingUnlinks (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts ClaimNonces (r:1 w:1)
+	/// Proof: UnifiedAccounts ClaimNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Balances Holds (r:1 w:1)
+	/// Proof: Balances Holds (max_values: None, max_size: Some(211), added: 2686, mode: MaxEncodedLen)
+	fn unlink_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `373`
+		//  Estimated: `6196`
+		// Minimum execution time: 46_790_000 picoseconds.
+		Weight::from_parts(47_508_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: UnifiedAccounts EvmToNative (r:1 w:0)
 	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
@@ -207,4 +438,30 @@ impl WeightInfo for () {
 		Weight::from_parts(5_719_000, 3533)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 	}
+	/// Storage: System Account (r:3 w:0)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts NativeToEvm (r:1 w:1)
+	/// Proof: UnifiedAccounts NativeToEvm (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	/// Storage: UnifiedAccounts EvmToNative (r:1 w:1)
+	/// Proof: UnifiedAccounts EvmToNative (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	fn default_mapping_migration_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `8799`
+		// Minimum execution time: 20_918_000 picoseconds.
+		Weight::from_parts(21_386_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: UnifiedAccounts DefaultMappingsMigrationCursor (r:1 w:1)
+	/// Proof: UnifiedAccounts DefaultMappingsMigrationCursor (max_values: Some(1), max_size: Some(33), added: 528, mode: MaxEncodedLen)
+	fn start_default_mappings_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `1518`
+		// Minimum execution time: 8_125_000 picoseconds.
+		Weight::from_parts(8_407_000, 1518)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: BSD-3-Clause
+
+pragma solidity >=0.8.0;
+
+/// Predeployed at the address 0x0000000000000000000000000000000000005008
+/// For better understanding check the source code:
+/// repo: https://github.com/AstarNetwork/Astar
+/// code: pallets/price-aggregator
+interface PriceOracle {
+
+    /// @notice Get the moving average price of the native currency.
+    ///         In case the price feed has been stale for too long, the fallback price is returned instead.
+    /// @return price: Price as a fixed point number with 18 decimals.
+    function average_price() external view returns (uint128);
+
+    /// @notice Get the ongoing intermediate aggregation of the native currency price.
+    /// @return (average of the values aggregated so far, number of aggregated values,
+    ///          block number at which the average is pushed into the circular buffer).
+    ///         Average is a fixed point number with 18 decimals.
+    function intermediate_value() external view returns (uint128, uint32, uint32);
+
+    /// @notice Get the values stored in the native currency circular buffer, oldest value first.
+    /// @return values: Values as fixed point numbers with 18 decimals.
+    function circular_buffer() external view returns (uint128[] memory);
+
+    /// @notice Get the native currency price feed staleness info.
+    /// @return (true if price feed is stale, number of consecutive blocks without a valid price value).
+    function staleness() external view returns (bool, uint32);
+}
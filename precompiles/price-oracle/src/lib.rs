@@ -0,0 +1,139 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+//! Astar price oracle interface.
+//!
+//! Read-only access to the native currency price aggregated by `pallet-price-aggregator`.
+//! All prices are returned as fixed point numbers with 18 decimals.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use fp_evm::PrecompileHandle;
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::prelude::*;
+use sp_core::Get;
+use sp_runtime::FixedPointNumber;
+use sp_std::{marker::PhantomData, prelude::*};
+
+use astar_primitives::oracle::{CurrencyAmount, CurrencyId, PriceProvider};
+use pallet_price_aggregator::{
+    BlocksWithoutValidValue, CircularBuffer, IntermediateValueAggregator,
+    Pallet as PriceAggregatorPallet, ValueAggregator, ValuesCircularBuffer,
+};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Circular buffer type used by the price aggregator.
+type CircularBufferFor<R> =
+    CircularBuffer<<R as pallet_price_aggregator::Config>::CircularBufferLength>;
+
+/// A precompile that exposes the native currency price aggregated on-chain.
+pub struct PriceOraclePrecompile<R>(PhantomData<R>);
+
+#[precompile_utils::precompile]
+impl<R> PriceOraclePrecompile<R>
+where
+    R: pallet_evm::Config + pallet_price_aggregator::Config,
+{
+    /// Read the moving average price of the native currency.
+    ///
+    /// In case the price feed has been stale for too long, the configured fallback price is returned instead.
+    #[precompile::public("average_price()")]
+    #[precompile::view]
+    fn average_price(handle: &mut impl PrecompileHandle) -> EvmResult<u128> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: BlocksWithoutValidValue:
+        // Twox64Concat(8) + CurrencyId::max_encoded_len + u32(4)
+        handle.record_db_read::<R>(12 + CurrencyId::max_encoded_len())?;
+        // Storage item: ValuesCircularBuffer:
+        // Twox64Concat(8) + CurrencyId::max_encoded_len + CircularBuffer::max_encoded_len
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len() + CircularBufferFor::<R>::max_encoded_len(),
+        )?;
+
+        let price = <PriceAggregatorPallet<R> as PriceProvider>::average_price();
+
+        Ok(price.into_inner())
+    }
+
+    /// Read the ongoing intermediate aggregation of the native currency price.
+    ///
+    /// Returns the average of the values aggregated so far, the number of aggregated values,
+    /// and the block number at which the average will be pushed into the circular buffer.
+    #[precompile::public("intermediate_value()")]
+    #[precompile::view]
+    fn intermediate_value(handle: &mut impl PrecompileHandle) -> EvmResult<(u128, u32, u32)> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: IntermediateValueAggregator:
+        // Twox64Concat(8) + CurrencyId::max_encoded_len + ValueAggregator::max_encoded_len
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len() + ValueAggregator::max_encoded_len(),
+        )?;
+
+        let aggregator = IntermediateValueAggregator::<R>::get(R::NativeCurrencyId::get());
+
+        Ok((
+            aggregator.average().into_inner(),
+            aggregator.count(),
+            aggregator.limit_block(),
+        ))
+    }
+
+    /// Read the values stored in the native currency circular buffer, oldest value first.
+    #[precompile::public("circular_buffer()")]
+    #[precompile::view]
+    fn circular_buffer(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<u128>> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: ValuesCircularBuffer:
+        // Twox64Concat(8) + CurrencyId::max_encoded_len + CircularBuffer::max_encoded_len
+        handle.record_db_read::<R>(
+            8 + CurrencyId::max_encoded_len() + CircularBufferFor::<R>::max_encoded_len(),
+        )?;
+
+        let values = ValuesCircularBuffer::<R>::get(R::NativeCurrencyId::get())
+            .ordered_values()
+            .into_iter()
+            .map(CurrencyAmount::into_inner)
+            .collect();
+
+        Ok(values)
+    }
+
+    /// Read the native currency price feed staleness info.
+    ///
+    /// Returns whether the price feed is considered stale, and the number of consecutive blocks
+    /// without any valid price value.
+    #[precompile::public("staleness()")]
+    #[precompile::view]
+    fn staleness(handle: &mut impl PrecompileHandle) -> EvmResult<(bool, u32)> {
+        // TODO: benchmark this function so we can measure ref time & PoV correctly
+        // Storage item: BlocksWithoutValidValue:
+        // Twox64Concat(8) + CurrencyId::max_encoded_len + u32(4)
+        handle.record_db_read::<R>(12 + CurrencyId::max_encoded_len())?;
+
+        let native_currency_id = R::NativeCurrencyId::get();
+
+        Ok((
+            PriceAggregatorPallet::<R>::is_stale(native_currency_id),
+            BlocksWithoutValidValue::<R>::get(native_currency_id),
+        ))
+    }
+}
@@ -0,0 +1,174 @@
+// This file is part of Astar.
+
+// Copyright (C) Stake Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// Astar is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Astar is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Astar. If not, see <http://www.gnu.org/licenses/>.
+
+use crate::mock::*;
+use crate::*;
+
+use precompile_utils::testing::*;
+use sp_runtime::traits::Zero;
+
+fn precompiles() -> TestPrecompileSet<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn native_currency_id() -> CurrencyId {
+    <Runtime as pallet_price_aggregator::Config>::NativeCurrencyId::get()
+}
+
+#[test]
+fn average_price_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Empty buffer, expect zero
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::average_price {},
+            )
+            .expect_no_logs()
+            .execute_returns(0_u128);
+
+        // Fill the buffer, expect the moving average
+        let values = vec![
+            CurrencyAmount::from_rational(1, 10),
+            CurrencyAmount::from_rational(2, 10),
+            CurrencyAmount::from_rational(3, 10),
+        ];
+        ValuesCircularBuffer::<Runtime>::insert(
+            native_currency_id(),
+            CircularBuffer::from_values(&values),
+        );
+
+        let expected_price = CurrencyAmount::from_rational(2, 10);
+        assert_eq!(
+            <PriceAggregator as PriceProvider>::average_price(),
+            expected_price,
+            "Sanity check."
+        );
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::average_price {},
+            )
+            .expect_no_logs()
+            .execute_returns(expected_price.into_inner());
+    });
+}
+
+#[test]
+fn intermediate_value_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        let limit_block = 17;
+        let aggregator = ValueAggregator::new(limit_block)
+            .try_add(CurrencyAmount::from_rational(1, 10))
+            .and_then(|aggregator| aggregator.try_add(CurrencyAmount::from_rational(3, 10)))
+            .expect("Cannot overflow.");
+        IntermediateValueAggregator::<Runtime>::insert(native_currency_id(), aggregator);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::intermediate_value {},
+            )
+            .expect_no_logs()
+            .execute_returns((
+                CurrencyAmount::from_rational(2, 10).into_inner(),
+                2_u32,
+                limit_block,
+            ));
+    });
+}
+
+#[test]
+fn circular_buffer_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Empty buffer
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::circular_buffer {},
+            )
+            .expect_no_logs()
+            .execute_returns(Vec::<u128>::new());
+
+        // Overflow the buffer, expect values to be returned in chronological order
+        let capacity = <Runtime as pallet_price_aggregator::Config>::CircularBufferLength::get();
+        let values: Vec<_> = (1..=capacity + 2)
+            .map(|x| CurrencyAmount::from_rational(x.into(), 10))
+            .collect();
+        ValuesCircularBuffer::<Runtime>::insert(
+            native_currency_id(),
+            CircularBuffer::from_values(&values),
+        );
+
+        let expected_values: Vec<u128> = values
+            .iter()
+            .skip(2)
+            .map(|value| value.into_inner())
+            .collect();
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::circular_buffer {},
+            )
+            .expect_no_logs()
+            .execute_returns(expected_values);
+    });
+}
+
+#[test]
+fn staleness_is_ok() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(BlocksWithoutValidValue::<Runtime>::get(native_currency_id()).is_zero());
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::staleness {},
+            )
+            .expect_no_logs()
+            .execute_returns((false, 0_u32));
+
+        // Just below the threshold
+        let threshold = <Runtime as pallet_price_aggregator::Config>::StalenessThreshold::get();
+        BlocksWithoutValidValue::<Runtime>::insert(native_currency_id(), threshold - 1);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::staleness {},
+            )
+            .expect_no_logs()
+            .execute_returns((false, threshold - 1));
+
+        // Threshold reached
+        BlocksWithoutValidValue::<Runtime>::insert(native_currency_id(), threshold);
+        precompiles()
+            .prepare_test(
+                TestAccount::Alice,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::staleness {},
+            )
+            .expect_no_logs()
+            .execute_returns((true, threshold));
+    });
+}
@@ -25,4 +25,27 @@ interface UnifiedAccounts {
     function get_native_address_or_default(
         address evmAddress
     ) external view returns (bytes32, bool);
+
+    /// Gets the claim nonce of the given account id, which is bumped every time its mappings are removed.
+    /// @param accountId: The account id for which you want the claim nonce for.
+    /// @return the claim nonce, part of the claim signing payloads.
+    function get_claim_nonce(bytes32 accountId) external view returns (uint32);
+
+    /// Claims the given native account for the caller's evm address, creating the double mappings.
+    /// Native account must prove its ownership by signing the EIP-712 payload
+    /// `NativeClaim(address evmAddress)` (domain: "Astar EVM Claim", version "1", chain id,
+    /// genesis hash as salt) with its sr25519 or ed25519 key. Once the native account has been
+    /// unlinked, `NativeClaim(address evmAddress,uint256 nonce)` must be signed instead, where
+    /// `nonce` is its claim nonce. Payload wrapped in `<Bytes>..</Bytes>` is accepted as well.
+    /// @param nativeAddress: The account id to be claimed.
+    /// @param signature: 64 bytes sr25519 or ed25519 signature of the payload.
+    /// @return true if the claim was successful, otherwise reverts.
+    function claim_native_address(
+        bytes32 nativeAddress,
+        bytes calldata signature
+    ) external returns (bool);
+
+    /// Claims the default native account for the caller's evm address, creating the double mappings.
+    /// @return the claimed default account id, reverts if a mapping already exists.
+    function claim_default_native_address() external returns (bytes32);
 }
@@ -18,18 +18,23 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use astar_primitives::evm::{UnifiedAddress, UnifiedAddressMapper};
+use astar_primitives::evm::{OnAccountUnification, UnifiedAddress, UnifiedAddressMapper};
 use core::marker::PhantomData;
 use fp_evm::PrecompileHandle;
-use frame_support::traits::IsType;
+use frame_support::traits::{ConstU32, IsType};
+use pallet_evm::GasWeightMapping;
+use pallet_unified_accounts::WeightInfo;
 use precompile_utils::prelude::*;
-use sp_core::{crypto::AccountId32, H256};
+use sp_core::{crypto::AccountId32, ed25519, sr25519, H256};
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+// sr25519 & ed25519 signature bytes
+type NativeSignatureBytes = ConstU32<64>;
+
 /// A precompile that expose AU related functions.
 pub struct UnifiedAccountsPrecompile<T, UA>(PhantomData<(T, UA)>);
 
@@ -67,4 +72,89 @@ where
         };
         Ok(output)
     }
+
+    /// Gets the claim nonce of the given native account, part of the claim signing payload.
+    #[precompile::public("get_claim_nonce(bytes32)")]
+    #[precompile::view]
+    fn get_claim_nonce(handle: &mut impl PrecompileHandle, account_id: H256) -> EvmResult<u32> {
+        // ClaimNonces: Blake2_128(16) + AccountId(32) + u32(4)
+        handle.record_db_read::<R>(52)?;
+
+        let account_id = AccountId32::new(account_id.into()).into();
+        Ok(pallet_unified_accounts::ClaimNonces::<R>::get(account_id))
+    }
+
+    /// Claims the given native account for the caller's evm address.
+    ///
+    /// The native account must prove the ownership by signing the payload built by
+    /// `build_native_signing_payload` with its sr25519 or ed25519 key. Payload wrapped
+    /// in `<Bytes>..</Bytes>`, as done by most Substrate wallets, is accepted as well.
+    #[precompile::public("claim_native_address(bytes32,bytes)")]
+    fn claim_native_address(
+        handle: &mut impl PrecompileHandle,
+        native_address: H256,
+        signature: BoundedBytes<NativeSignatureBytes>,
+    ) -> EvmResult<bool> {
+        let weight = <R as pallet_unified_accounts::Config>::WeightInfo::claim_evm_address()
+            .saturating_add(<R as pallet_unified_accounts::Config>::OnAccountUnification::weight());
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(weight))?;
+
+        let evm_address = handle.context().caller;
+        let account_id = AccountId32::new(native_address.into());
+
+        let payload = pallet_unified_accounts::Pallet::<R>::build_native_signing_payload(
+            &evm_address,
+            &account_id.clone().into(),
+        );
+        if !Self::verify_native_signature(&account_id, &payload, signature.as_bytes()) {
+            return Err(revert("invalid signature"));
+        }
+
+        pallet_unified_accounts::Pallet::<R>::do_claim_evm_address(account_id.into(), evm_address)
+            .map_err(|e| revert(<&'static str>::from(e)))?;
+
+        Ok(true)
+    }
+
+    /// Claims the default native account for the caller's evm address.
+    ///
+    /// Returns the claimed native account.
+    #[precompile::public("claim_default_native_address()")]
+    fn claim_default_native_address(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+        handle.record_cost(R::GasWeightMapping::weight_to_gas(
+            <R as pallet_unified_accounts::Config>::WeightInfo::claim_default_evm_address(),
+        ))?;
+
+        let evm_address = handle.context().caller;
+        let account_id =
+            pallet_unified_accounts::Pallet::<R>::do_claim_default_account_id(evm_address)
+                .map_err(|e| revert(<&'static str>::from(e)))?;
+
+        Ok(H256::from(account_id.into().as_ref()))
+    }
+
+    /// `true` if the signature over the payload is a valid sr25519 or ed25519 signature of the account.
+    fn verify_native_signature(
+        account_id: &AccountId32,
+        payload: &[u8; 32],
+        signature: &[u8],
+    ) -> bool {
+        let Ok(signature) = <[u8; 64]>::try_from(signature) else {
+            return false;
+        };
+        let public: [u8; 32] = account_id.clone().into();
+        let wrapped_payload = [&b"<Bytes>"[..], &payload[..], &b"</Bytes>"[..]].concat();
+
+        [&payload[..], &wrapped_payload[..]].iter().any(|message| {
+            sp_io::crypto::sr25519_verify(
+                &sr25519::Signature::from_raw(signature),
+                message,
+                &sr25519::Public::from_raw(public),
+            ) || sp_io::crypto::ed25519_verify(
+                &ed25519::Signature::from_raw(signature),
+                message,
+                &ed25519::Public::from_raw(public),
+            )
+        })
+    }
 }
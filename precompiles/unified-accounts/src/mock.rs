@@ -202,15 +202,24 @@ impl pallet_evm::Config for TestRuntime {
 parameter_types! {
     // 2 storage items with value size 20 and 32
     pub const AccountMappingStorageFee: u128 = 0;
+    pub const UnlinkFeePolicy: pallet_unified_accounts::UnlinkFeePolicy =
+        pallet_unified_accounts::UnlinkFeePolicy::Burn;
     pub ChainId: u64 = 1024;
 }
 
 impl pallet_unified_accounts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type OnAccountUnification = ();
+    type ContractSignatureVerifier = ();
+    type MaxContractSignatureLength = ConstU32<1024>;
+    type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+    type UnlinkDelay = ConstU64<10>;
+    type UnlinkFeePolicy = UnlinkFeePolicy;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
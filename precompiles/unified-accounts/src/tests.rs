@@ -21,6 +21,7 @@ use crate::*;
 
 use frame_support::assert_ok;
 use precompile_utils::testing::*;
+use sp_core::{ed25519, sr25519, Pair, H160};
 
 fn precompiles() -> TestPrecompileSet<TestRuntime> {
     PrecompilesValue::get()
@@ -128,3 +129,211 @@ fn test_get_native_address() {
             .execute_returns(res);
     });
 }
+
+#[test]
+fn test_claim_native_address_with_sr25519_signature() {
+    ExtBuilder.build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+        let account_id: AccountId32 = pair.public().into();
+        let evm_address: H160 = TestAccount::Viktor.into();
+
+        let payload = UnifiedAccounts::build_native_signing_payload(&evm_address, &account_id);
+        let signature = pair.sign(&payload);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: signature.0.to_vec().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        // make sure mappings are in place
+        assert_eq!(
+            pallet_unified_accounts::EvmToNative::<TestRuntime>::get(evm_address),
+            Some(account_id.clone())
+        );
+        assert_eq!(
+            pallet_unified_accounts::NativeToEvm::<TestRuntime>::get(account_id),
+            Some(evm_address)
+        );
+    });
+}
+
+#[test]
+fn test_claim_native_address_with_wrapped_ed25519_signature() {
+    ExtBuilder.build().execute_with(|| {
+        let pair = ed25519::Pair::from_seed(&[7u8; 32]);
+        let account_id: AccountId32 = pair.public().into();
+        let evm_address: H160 = TestAccount::Viktor.into();
+
+        // payload is wrapped by the wallet before signing
+        let payload = UnifiedAccounts::build_native_signing_payload(&evm_address, &account_id);
+        let signature = pair.sign(&[&b"<Bytes>"[..], &payload[..], &b"</Bytes>"[..]].concat());
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: signature.0.to_vec().into(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        assert_eq!(
+            pallet_unified_accounts::EvmToNative::<TestRuntime>::get(evm_address),
+            Some(account_id)
+        );
+    });
+}
+
+#[test]
+fn test_claim_native_address_fails() {
+    ExtBuilder.build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+        let other_pair = sr25519::Pair::from_seed(&[8u8; 32]);
+        let account_id: AccountId32 = pair.public().into();
+        let evm_address: H160 = TestAccount::Viktor.into();
+        let payload = UnifiedAccounts::build_native_signing_payload(&evm_address, &account_id);
+
+        // Case 1: signed by a different account
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: other_pair.sign(&payload).0.to_vec().into(),
+                },
+            )
+            .execute_reverts(|output| output == b"invalid signature");
+
+        // Case 2: signed for a different evm address
+        let other_payload =
+            UnifiedAccounts::build_native_signing_payload(&H160::repeat_byte(0xBB), &account_id);
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: pair.sign(&other_payload).0.to_vec().into(),
+                },
+            )
+            .execute_reverts(|output| output == b"invalid signature");
+
+        // Case 3: evm address already mapped
+        assert_ok!(UnifiedAccounts::claim_evm_address(
+            RuntimeOrigin::signed(ALICE),
+            UnifiedAccounts::eth_address(&alice_secret()),
+            get_evm_signature(&ALICE, &alice_secret())
+        ));
+        let alice_eth = UnifiedAccounts::eth_address(&alice_secret());
+        let payload = UnifiedAccounts::build_native_signing_payload(&alice_eth, &account_id);
+        precompiles()
+            .prepare_test(
+                alice_eth,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: pair.sign(&payload).0.to_vec().into(),
+                },
+            )
+            .execute_reverts(|output| output == b"AlreadyMapped");
+    });
+}
+
+#[test]
+fn test_claim_native_address_signature_cannot_be_replayed_after_unlink() {
+    ExtBuilder.build().execute_with(|| {
+        let pair = sr25519::Pair::from_seed(&[7u8; 32]);
+        let account_id: AccountId32 = pair.public().into();
+        let evm_address: H160 = TestAccount::Viktor.into();
+        let payload = UnifiedAccounts::build_native_signing_payload(&evm_address, &account_id);
+        let signature = pair.sign(&payload);
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: signature.0.to_vec().into(),
+                    assets: Default::default(),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(true);
+
+        assert_ok!(UnifiedAccounts::force_unlink_account(
+            RuntimeOrigin::root(),
+            account_id.clone()
+        ));
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::get_claim_nonce {
+                    account_id: H256::from(pair.public().0),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(1u32);
+
+        // the same signature cannot be used to link the accounts again
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_native_address {
+                    native_address: H256::from(pair.public().0),
+                    signature: signature.0.to_vec().into(),
+                    assets: Default::default(),
+                },
+            )
+            .execute_reverts(|output| output == b"invalid signature");
+        assert!(pallet_unified_accounts::EvmToNative::<TestRuntime>::get(evm_address).is_none());
+    });
+}
+
+#[test]
+fn test_claim_default_native_address() {
+    ExtBuilder.build().execute_with(|| {
+        let evm_address: H160 = TestAccount::Viktor.into();
+        let default_account_id =
+            <TestRuntime as pallet_unified_accounts::Config>::DefaultMappings::to_default_account_id(
+                &evm_address,
+            );
+        let default_account_id_bytes: &[u8; 32] = default_account_id.as_ref();
+
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_default_native_address {},
+            )
+            .expect_no_logs()
+            .execute_returns(H256::from(default_account_id_bytes));
+
+        assert_eq!(
+            pallet_unified_accounts::EvmToNative::<TestRuntime>::get(evm_address),
+            Some(default_account_id)
+        );
+
+        // cannot claim twice
+        precompiles()
+            .prepare_test(
+                TestAccount::Viktor,
+                PRECOMPILE_ADDRESS,
+                PrecompileCall::claim_default_native_address {},
+            )
+            .execute_reverts(|output| output == b"AlreadyMapped");
+    });
+}
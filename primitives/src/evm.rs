@@ -23,13 +23,15 @@ use frame_support::{
     traits::{
         fungible::{Balanced, Credit},
         tokens::{fungible::Inspect, imbalance::OnUnbalanced},
+        Get, OnRuntimeUpgrade,
     },
+    weights::Weight,
 };
 use pallet_evm::{AddressMapping, HashedAddressMapping, OnChargeEVMTransaction};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::{Hasher, H160, H256, U256};
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::{traits::UniqueSaturatedInto, DispatchResult};
 use sp_std::marker::PhantomData;
 
 use pallet_assets::AssetsCallback;
@@ -68,6 +70,36 @@ where
     }
 }
 
+/// Registers the revert code at the address of a precompile added to an already running network.
+///
+/// Genesis only covers new networks, so without it the precompile has no code on live chains and
+/// fails the `extcodesize` checks done by Solidity. Existing code at the address is left untouched.
+pub struct AddPrecompileRevertCode<R, A>(PhantomData<(R, A)>);
+impl<R, A> OnRuntimeUpgrade for AddPrecompileRevertCode<R, A>
+where
+    R: pallet_evm::Config,
+    A: Get<H160>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let address = A::get();
+        if pallet_evm::AccountCodes::<R>::contains_key(address) {
+            return R::DbWeight::get().reads(1);
+        }
+
+        pallet_evm::AccountCodes::<R>::insert(address, EVM_REVERT_CODE.to_vec());
+        R::DbWeight::get().reads_writes(1, 1)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        ensure!(
+            !pallet_evm::AccountCodes::<R>::get(A::get()).is_empty(),
+            "Precompile address must have code."
+        );
+        Ok(())
+    }
+}
+
 /// Mapping between Native and EVM Addresses
 pub trait UnifiedAddressMapper<AccountId> {
     /// Gets the account id associated with given evm address, if mapped else None.
@@ -104,6 +136,35 @@ pub trait UnifiedAddressMapper<AccountId> {
     fn to_default_h160(account_id: &AccountId) -> EvmAddress;
 }
 
+/// Hook used to move the state held by the default account of an EVM address,
+/// once the EVM address gets unified with a native account.
+///
+/// After unification, the default account is no longer accessible by anyone,
+/// so any state left behind would be lost forever.
+pub trait OnAccountUnification<AccountId> {
+    /// Move all of the relevant state from the `default` account to the `unified` account.
+    ///
+    /// In case the state cannot be moved, an error must be returned, which refuses the unification.
+    fn on_unification(default: &AccountId, unified: &AccountId) -> DispatchResult;
+
+    /// Upper bound of the weight consumed by `on_unification`.
+    fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(8)]
+impl<AccountId> OnAccountUnification<AccountId> for Tuple {
+    fn on_unification(default: &AccountId, unified: &AccountId) -> DispatchResult {
+        for_tuples!( #( Tuple::on_unification(default, unified)?; )* );
+        Ok(())
+    }
+
+    fn weight() -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #( weight.saturating_accrue(Tuple::weight()); )* );
+        weight
+    }
+}
+
 /// Mappings derieved from hashing the original address
 pub struct HashedDefaultMappings<H>(PhantomData<H>);
 impl<H: Hasher<Out = H256>> UnifiedAddressMapper<AccountId> for HashedDefaultMappings<H> {
@@ -16,27 +16,46 @@
 // You should have received a copy of the GNU General Public License
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
-use frame_support::{pallet_prelude::*, traits::Time};
-use sp_arithmetic::fixed_point::FixedU128;
+use frame_support::{
+    pallet_prelude::*,
+    traits::{SortedMembers, Time},
+};
+use sp_arithmetic::{fixed_point::FixedU128, traits::Saturating, Percent};
 use sp_std::vec::Vec;
 
+use crate::AssetId;
+
 /// Interface for fetching price of the native token.
 pub trait PriceProvider {
     /// Get the price of the native token.
     fn average_price() -> Price;
 }
 
+/// Interface for fetching price of any tracked currency.
+pub trait MultiCurrencyPriceProvider {
+    /// Get the price of the specified currency.
+    ///
+    /// Returns `None` if the currency isn't tracked.
+    fn average_price_of(currency_id: CurrencyId) -> Option<Price>;
+}
+
 pub type Price = FixedU128;
 pub type CurrencyAmount = FixedU128;
 
-#[derive(Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, TypeInfo)]
+#[derive(
+    Encode, Decode, MaxEncodedLen, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, TypeInfo,
+)]
 pub enum CurrencyId {
     ASTR,
     SDN,
+    DOT,
+    USDT,
+    /// Any other asset registered in `pallet-assets`.
+    Asset(#[codec(compact)] AssetId),
 }
 
-type TimestampedValue<T, I = ()> =
-    orml_oracle::TimestampedValue<Price, <<T as orml_oracle::Config<I>>::Time as Time>::Moment>;
+type Moment<T, I = ()> = <<T as orml_oracle::Config<I>>::Time as Time>::Moment;
+type TimestampedValue<T, I = ()> = orml_oracle::TimestampedValue<Price, Moment<T, I>>;
 
 /// A dummy implementation of `CombineData` trait that does nothing.
 pub struct DummyCombineData<T, I = ()>(PhantomData<(T, I)>);
@@ -51,3 +70,38 @@ impl<T: orml_oracle::Config<I>, I> orml_traits::CombineData<CurrencyId, Timestam
         None
     }
 }
+
+/// Implementation of `CombineData` trait which selects the median of the fresh values.
+///
+/// * `Quorum` - share of the oracle members which need to provide a fresh value, at least one value is always required.
+/// * `ExpiresIn` - period after which the value is considered stale and is ignored.
+///
+/// In case of an even number of values, the upper median is selected so the result is always one of the provided values.
+pub struct MedianCombineData<T, Quorum, ExpiresIn, I = ()>(PhantomData<(T, Quorum, ExpiresIn, I)>);
+impl<T: orml_oracle::Config<I>, Quorum, ExpiresIn, I>
+    orml_traits::CombineData<CurrencyId, TimestampedValue<T, I>>
+    for MedianCombineData<T, Quorum, ExpiresIn, I>
+where
+    Quorum: Get<Percent>,
+    ExpiresIn: Get<Moment<T, I>>,
+{
+    fn combine_data(
+        _key: &CurrencyId,
+        mut values: Vec<TimestampedValue<T, I>>,
+        _prev_value: Option<TimestampedValue<T, I>>,
+    ) -> Option<TimestampedValue<T, I>> {
+        let now = T::Time::now();
+        let expires_in = ExpiresIn::get();
+        values.retain(|value| value.timestamp.saturating_add(expires_in) > now);
+
+        let members = <T as orml_oracle::Config<I>>::Members::count();
+        let required = Quorum::get().mul_ceil(members).max(1);
+        if values.len() < required {
+            return None;
+        }
+
+        values.sort_by(|a, b| a.value.cmp(&b.value));
+        let median = values.len() / 2;
+        Some(values.swap_remove(median))
+    }
+}
@@ -191,6 +191,9 @@ pub fn default_config(para_id: u32) -> serde_json::Value {
         democracy: Default::default(),
         treasury: Default::default(),
         community_treasury: Default::default(),
+        collective_proxy: CollectiveProxyConfig {
+            proxies: vec![CommunityProxy::get()],
+        },
     };
 
     serde_json::to_value(&config).expect("Could not build genesis config.")
@@ -29,6 +29,7 @@ use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_price_oracle::PriceOraclePrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 use pallet_evm_precompile_sr25519::Sr25519Precompile;
@@ -156,6 +157,11 @@ pub type AstarPrecompilesSetAt<R, C> = (
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    PrecompileAt<
+        AddressU64<20488>,
+        PriceOraclePrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type AstarPrecompiles<R, C> = PrecompileSetBuilder<
@@ -164,7 +170,7 @@ pub type AstarPrecompiles<R, C> = PrecompileSetBuilder<
         // Skip precompiles if out of range.
         PrecompilesInRangeInclusive<
             // We take range as last precompile index, UPDATE this once new prcompile is added
-            (AddressU64<1>, AddressU64<20487>),
+            (AddressU64<1>, AddressU64<20488>),
             AstarPrecompilesSetAt<R, C>,
         >,
         // Prefixed precompile sets (XC20)
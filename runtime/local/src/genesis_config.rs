@@ -173,6 +173,9 @@ pub fn default_config() -> serde_json::Value {
         democracy: Default::default(),
         treasury: Default::default(),
         community_treasury: Default::default(),
+        collective_proxy: CollectiveProxyConfig {
+            proxies: vec![CommunityProxy::get()],
+        },
         safe_mode: Default::default(),
         tx_pause: Default::default(),
     };
@@ -67,7 +67,10 @@ use astar_primitives::{
         CycleConfiguration, DAppId, EraNumber, PeriodNumber, RankedTier, SmartContract,
         StandardTierSlots,
     },
-    evm::{EvmRevertCodeHandler, HashedDefaultMappings},
+    evm::{
+        EvmAddress, EvmRevertCodeHandler, HashedDefaultMappings, UnifiedAddress,
+        UnifiedAddressMapper,
+    },
     governance::{
         CommunityCouncilCollectiveInst, CommunityCouncilMembershipInst, CommunityTreasuryInst,
         EnsureRootOrAllMainCouncil, EnsureRootOrAllTechnicalCommittee,
@@ -437,6 +440,9 @@ parameter_types! {
 
 impl pallet_static_price_provider::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type GovernanceOrigin = EnsureRootOrTwoThirdsMainCouncil;
+    type MaxScheduledChanges = ConstU32<16>;
+    type WeightInfo = pallet_static_price_provider::weights::SubstrateWeight<Runtime>;
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -539,14 +545,27 @@ impl pallet_utility::Config for Runtime {
 parameter_types! {
     // 2 storage items with value size 20 and 32
     pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // Enough for verifying multisig (e.g. Safe) signatures with tens of owners.
+    pub const ContractSignatureGasLimit: u64 = 500_000;
+    // Storage fee stays burned, to discourage mappings churn.
+    pub const UnlinkFeePolicy: pallet_unified_accounts::UnlinkFeePolicy =
+        pallet_unified_accounts::UnlinkFeePolicy::Burn;
 }
 
 impl pallet_unified_accounts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = ChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type OnAccountUnification = DappStaking;
+    type ContractSignatureVerifier =
+        pallet_unified_accounts::EvmContractSignatureVerifier<Runtime, ContractSignatureGasLimit>;
+    type MaxContractSignatureLength = ConstU32<2048>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type UnlinkDelay = ConstU32<{ 10 * MINUTES }>;
+    type UnlinkFeePolicy = UnlinkFeePolicy;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -1082,29 +1101,185 @@ impl pallet_treasury::Config<CommunityTreasuryInst> for Runtime {
 
 parameter_types! {
     pub CommunityTreasuryAccountId: AccountId = CommunityTreasuryPalletId::get().into_account_truncating();
+    pub const CollectiveProxyPalletId: PalletId = PalletId(*b"py/clprx");
+    /// Name of the community council proxy, also used by the legacy `execute_call`.
+    pub CommunityProxyName: pallet_collective_proxy::ProxyName<Runtime> =
+        sp_runtime::BoundedVec::truncate_from(b"community".to_vec());
+    /// Community council proxy, registered at genesis or migrated from the single proxy along
+    /// with its account.
+    pub CommunityProxy: (
+        pallet_collective_proxy::ProxyName<Runtime>,
+        pallet_collective_proxy::ProxyInfoOf<Runtime>,
+    ) = (
+        CommunityProxyName::get(),
+        pallet_collective_proxy::ProxyInfo {
+            account: CommunityTreasuryAccountId::get(),
+            origin: CollectiveProxyOrigin::CommunityCouncil,
+            filter: CollectiveProxyFilter::CommunityCouncil,
+        },
+    );
+}
+
+/// Origins that can act through a collective proxy.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CollectiveProxyOrigin {
+    CommunityCouncil,
+    MainCouncil,
+    TechnicalCommittee,
+}
+
+pub struct EnsureCollectiveProxyOrigin;
+impl frame_support::traits::EnsureOriginWithArg<RuntimeOrigin, CollectiveProxyOrigin>
+    for EnsureCollectiveProxyOrigin
+{
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, a: &CollectiveProxyOrigin) -> Result<(), RuntimeOrigin> {
+        use frame_support::traits::EnsureOrigin;
+        match a {
+            CollectiveProxyOrigin::CommunityCouncil => {
+                EnsureRootOrTwoThirdsCommunityCouncil::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::MainCouncil => {
+                EnsureRootOrTwoThirdsMainCouncil::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::TechnicalCommittee => {
+                EnsureRootOrTwoThirdsTechnicalCommittee::try_origin(o).map(|_| ())
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_a: &CollectiveProxyOrigin) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+/// Filter profiles of the collective proxies.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CollectiveProxyFilter {
+    /// dApp staking, remarks and batches of them.
+    CommunityCouncil,
+    /// dApp staking only.
+    DappStaking,
 }
 
-#[derive(Default)]
-pub struct CommunityCouncilCallFilter;
-impl InstanceFilter<RuntimeCall> for CommunityCouncilCallFilter {
+impl InstanceFilter<RuntimeCall> for CollectiveProxyFilter {
     fn filter(&self, c: &RuntimeCall) -> bool {
-        matches!(
-            c,
-            RuntimeCall::DappStaking(..)
-                | RuntimeCall::System(frame_system::Call::remark { .. })
-                | RuntimeCall::Utility(pallet_utility::Call::batch { .. })
-                | RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
-        )
+        match self {
+            CollectiveProxyFilter::CommunityCouncil => matches!(
+                c,
+                RuntimeCall::DappStaking(..)
+                    | RuntimeCall::System(frame_system::Call::remark { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
+            ),
+            CollectiveProxyFilter::DappStaking => matches!(c, RuntimeCall::DappStaking(..)),
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CollectiveProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collective_proxy::BenchmarkHelper<CollectiveProxyOrigin, CollectiveProxyFilter>
+    for CollectiveProxyBenchmarkHelper
+{
+    fn proxy_origin() -> CollectiveProxyOrigin {
+        CollectiveProxyOrigin::CommunityCouncil
+    }
+
+    fn call_filter() -> CollectiveProxyFilter {
+        CollectiveProxyFilter::CommunityCouncil
+    }
+}
+
+/// Funds spent from the community treasury by the calls it is allowed to make.
+pub struct CommunityCouncilSpendInspector;
+impl pallet_collective_proxy::SpendInspector<RuntimeCall, AssetId>
+    for CommunityCouncilSpendInspector
+{
+    fn spends(c: &RuntimeCall) -> Vec<(pallet_collective_proxy::SpendAsset<AssetId>, Balance)> {
+        use pallet_collective_proxy::SpendAsset;
+        match c {
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::lock { amount })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value: amount,
+                ..
+            })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                value: amount,
+                ..
+            }) => vec![(SpendAsset::Native, *amount)],
+            RuntimeCall::Assets(pallet_assets::Call::transfer { id, amount, .. })
+            | RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+                id, amount, ..
+            }) => vec![(SpendAsset::Asset(id.0), *amount)],
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().flat_map(Self::spends).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Calls batched by the collective proxies, counted towards their call rate limits.
+pub struct CollectiveProxyNestedCalls;
+impl pallet_collective_proxy::NestedCalls<RuntimeCall> for CollectiveProxyNestedCalls {
+    fn nested_calls(c: &RuntimeCall) -> Vec<&RuntimeCall> {
+        match c {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().collect()
+            }
+            _ => Vec::new(),
+        }
     }
 }
 
 impl pallet_collective_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureRootOrTwoThirdsCommunityCouncil;
-    type ProxyAccountId = CommunityTreasuryAccountId;
-    type CallFilter = CommunityCouncilCallFilter;
+    type ProxyOrigin = CollectiveProxyOrigin;
+    type CollectiveProxy = EnsureCollectiveProxyOrigin;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type CallFilter = CollectiveProxyFilter;
+    type PalletId = CollectiveProxyPalletId;
+    type AssetId = AssetId;
+    type SpendInspector = CommunityCouncilSpendInspector;
+    type NestedCalls = CollectiveProxyNestedCalls;
+    type BudgetPeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxNameLen = ConstU32<64>;
+    type MaxProxyNameLen = ConstU32<16>;
+    type LegacyProxy = CommunityProxyName;
     type WeightInfo = pallet_collective_proxy::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CollectiveProxyBenchmarkHelper;
 }
 
 /// Calls that can bypass the safe-mode pallet.
@@ -1320,6 +1495,7 @@ mod benches {
         [pallet_dynamic_evm_base_fee, DynamicEvmBaseFee]
         [pallet_tx_pause, TxPause]
         [pallet_safe_mode, SafeMode]
+        [pallet_static_price_provider, StaticPriceProvider]
     );
 }
 
@@ -1833,6 +2009,26 @@ impl_runtime_apis! {
         }
     }
 
+    impl unified_accounts_runtime_api::UnifiedAccountsApi<Block> for Runtime {
+        fn to_account_ids(evm_addresses: Vec<EvmAddress>) -> Vec<UnifiedAddress<AccountId>> {
+            evm_addresses
+                .iter()
+                .map(|evm_address| UnifiedAccounts::to_account_id_or_default(evm_address))
+                .collect()
+        }
+
+        fn to_h160s(account_ids: Vec<AccountId>) -> Vec<UnifiedAddress<EvmAddress>> {
+            account_ids
+                .iter()
+                .map(|account_id| UnifiedAccounts::to_h160_or_default(account_id))
+                .collect()
+        }
+
+        fn claim_signing_payload(account_id: AccountId) -> [u8; 32] {
+            UnifiedAccounts::build_signing_payload(&account_id)
+        }
+    }
+
 
     impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 
@@ -195,6 +195,9 @@ pub fn default_config(para_id: u32) -> serde_json::Value {
         democracy: Default::default(),
         treasury: Default::default(),
         community_treasury: Default::default(),
+        collective_proxy: CollectiveProxyConfig {
+            proxies: vec![CommunityProxy::get()],
+        },
         safe_mode: Default::default(),
         tx_pause: Default::default(),
     };
@@ -61,10 +61,12 @@ use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{
         AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, ConvertInto,
-        DispatchInfoOf, Dispatchable, OpaqueKeys, PostDispatchInfoOf, UniqueSaturatedInto,
+        DispatchInfoOf, Dispatchable, Lazy, OpaqueKeys, PostDispatchInfoOf, UniqueSaturatedInto,
+        Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
-    ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Permill, Perquintill, RuntimeDebug,
+    ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
+    RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use xcm::{
@@ -81,7 +83,10 @@ use astar_primitives::{
         AccountCheck as DappStakingAccountCheck, CycleConfiguration, DAppId, EraNumber,
         PeriodNumber, RankedTier, SmartContract, StandardTierSlots,
     },
-    evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler, HashedDefaultMappings},
+    evm::{
+        EVMFungibleAdapterWrapper, EvmAddress, EvmRevertCodeHandler, HashedDefaultMappings,
+        UnifiedAddress, UnifiedAddressMapper,
+    },
     governance::{
         CommunityCouncilCollectiveInst, CommunityCouncilMembershipInst, CommunityTreasuryInst,
         EnsureRootOrAllMainCouncil, EnsureRootOrAllTechnicalCommittee,
@@ -91,7 +96,7 @@ use astar_primitives::{
         MainCouncilMembershipInst, MainTreasuryInst, OracleMembershipInst,
         TechnicalCommitteeCollectiveInst, TechnicalCommitteeMembershipInst,
     },
-    oracle::{CurrencyAmount, CurrencyId, DummyCombineData, Price},
+    oracle::{CurrencyAmount, CurrencyId, MedianCombineData, MultiCurrencyPriceProvider, Price},
     xcm::AssetLocationIdConverter,
     Address, AssetId, BlockNumber, Hash, Header, Nonce, UnfreezeChainOnFailedMigration,
 };
@@ -611,7 +616,10 @@ parameter_types! {
     pub const MinCandidates: u32 = 5;
     pub const MaxInvulnerables: u32 = 48;
     pub const SlashRatio: Perbill = Perbill::from_percent(1);
-    pub const KickThreshold: BlockNumber = 2 * HOURS; // 2 SessionPeriod
+    pub const KickThreshold: u32 = 2; // consecutive sessions
+    pub const MissedSlotsTolerance: Perbill = Perbill::from_percent(20);
+    pub const MinNomination: Balance = 100 * SBY;
+    pub const MaxNominators: u32 = 32;
 }
 
 pub struct CollatorSelectionAccountCheck;
@@ -629,14 +637,20 @@ impl pallet_collator_selection::Config for Runtime {
     type MaxCandidates = MaxCandidates;
     type MinCandidates = MinCandidates;
     type MaxInvulnerables = MaxInvulnerables;
-    // should be a multiple of session or things will get inconsistent
     type KickThreshold = KickThreshold;
+    type MissedSlotsTolerance = MissedSlotsTolerance;
+    type CurrentSlot = pallet_collator_selection::AuraDigestSlot<Runtime>;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type ValidatorSet = Session;
     type SlashRatio = SlashRatio;
     type AccountCheck = CollatorSelectionAccountCheck;
+    type MinNomination = MinNomination;
+    type MaxNominators = MaxNominators;
+    type PerformancePenalty = pallet_collator_selection::QuadraticPenalty;
+    // 1 day of sessions
+    type PerformanceHistoryDepth = ConstU32<24>;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1184,14 +1198,27 @@ impl pallet_message_queue::Config for Runtime {
 parameter_types! {
     // 2 storage items with values 20 and 32
     pub const AccountMappingStorageFee: u128 = deposit(2, 32 + 20);
+    // Enough for verifying multisig (e.g. Safe) signatures with tens of owners.
+    pub const ContractSignatureGasLimit: u64 = 500_000;
+    // Storage fee stays burned, to discourage mappings churn.
+    pub const UnlinkFeePolicy: pallet_unified_accounts::UnlinkFeePolicy =
+        pallet_unified_accounts::UnlinkFeePolicy::Burn;
 }
 
 impl pallet_unified_accounts::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
+    type RuntimeHoldReason = RuntimeHoldReason;
     type Currency = Balances;
     type DefaultMappings = HashedDefaultMappings<BlakeTwo256>;
     type ChainId = EVMChainId;
     type AccountMappingStorageFee = AccountMappingStorageFee;
+    type OnAccountUnification = DappStaking;
+    type ContractSignatureVerifier =
+        pallet_unified_accounts::EvmContractSignatureVerifier<Runtime, ContractSignatureGasLimit>;
+    type MaxContractSignatureLength = ConstU32<2048>;
+    type ManagerOrigin = EnsureRootOrHalfTechnicalCommittee;
+    type UnlinkDelay = ConstU32<{ 7 * DAYS }>;
+    type UnlinkFeePolicy = UnlinkFeePolicy;
     type WeightInfo = pallet_unified_accounts::weights::SubstrateWeight<Self>;
 }
 
@@ -1200,16 +1227,36 @@ parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::ASTR;
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = DAYS;
+    // Single value can at most halve or double the reference price.
+    pub const MaxBlockDeviation: Perbill = Perbill::from_percent(100);
+    pub const MaxAggregationDeviation: Perbill = Perbill::from_percent(100);
+    pub const PriceDeviationAction: pallet_price_aggregator::DeviationAction =
+        pallet_price_aggregator::DeviationAction::Clamp;
+    // Price feed is considered stale if no valid value is submitted for one hour.
+    pub const PriceFeedStalenessThreshold: u32 = HOURS;
+    pub const PriceFallbackThreshold: u32 = 7 * DAYS;
 }
 
 impl pallet_price_aggregator::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
+    type MovingAverageStrategy = pallet_price_aggregator::SimpleMovingAverage;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<8>;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    type MaxBlockDeviation = MaxBlockDeviation;
+    type MaxAggregationDeviation = MaxAggregationDeviation;
+    type DeviationAction = PriceDeviationAction;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type StalenessThreshold = PriceFeedStalenessThreshold;
+    // No fallback price provider, moving average is always used.
+    type FallbackPrice = ();
+    type FallbackThreshold = PriceFallbackThreshold;
+    type MaxDataFeeders = <Runtime as pallet_membership::Config<OracleMembershipInst>>::MaxMembers;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1231,10 +1278,17 @@ parameter_types! {
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 }
 
+parameter_types! {
+    /// Share of the oracle members which need to provide a fresh value for it to be combined.
+    pub const OracleQuorum: Percent = Percent::from_percent(50);
+    /// Oracle values older than this (in milliseconds) are ignored when combining.
+    pub const OracleValueExpiry: u64 = 60 * 60 * 1000;
+}
+
 impl orml_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnNewData = PriceAggregator;
-    type CombineData = DummyCombineData<Runtime>;
+    type CombineData = MedianCombineData<Runtime, OracleQuorum, OracleValueExpiry>;
     type Time = Timestamp;
     type OracleKey = CurrencyId;
     type OracleValue = Price;
@@ -1261,7 +1315,7 @@ impl pallet_membership::Config<OracleMembershipInst> for Runtime {
     type ResetOrigin = EnsureRootOrHalfMainCouncil;
     type PrimeOrigin = EnsureRootOrHalfMainCouncil;
     type MembershipInitialized = ();
-    type MembershipChanged = ();
+    type MembershipChanged = PriceAggregator;
     type MaxMembers = ConstU32<16>;
     type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
@@ -1478,29 +1532,185 @@ impl pallet_treasury::Config<CommunityTreasuryInst> for Runtime {
 
 parameter_types! {
     pub CommunityTreasuryAccountId: AccountId = CommunityTreasuryPalletId::get().into_account_truncating();
+    pub const CollectiveProxyPalletId: PalletId = PalletId(*b"py/clprx");
+    /// Name of the community council proxy, also used by the legacy `execute_call`.
+    pub CommunityProxyName: pallet_collective_proxy::ProxyName<Runtime> =
+        sp_runtime::BoundedVec::truncate_from(b"community".to_vec());
+    /// Community council proxy, registered at genesis or migrated from the single proxy along
+    /// with its account.
+    pub CommunityProxy: (
+        pallet_collective_proxy::ProxyName<Runtime>,
+        pallet_collective_proxy::ProxyInfoOf<Runtime>,
+    ) = (
+        CommunityProxyName::get(),
+        pallet_collective_proxy::ProxyInfo {
+            account: CommunityTreasuryAccountId::get(),
+            origin: CollectiveProxyOrigin::CommunityCouncil,
+            filter: CollectiveProxyFilter::CommunityCouncil,
+        },
+    );
 }
 
-#[derive(Default)]
-pub struct CommunityCouncilCallFilter;
-impl InstanceFilter<RuntimeCall> for CommunityCouncilCallFilter {
+/// Origins that can act through a collective proxy.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CollectiveProxyOrigin {
+    CommunityCouncil,
+    MainCouncil,
+    TechnicalCommittee,
+}
+
+pub struct EnsureCollectiveProxyOrigin;
+impl frame_support::traits::EnsureOriginWithArg<RuntimeOrigin, CollectiveProxyOrigin>
+    for EnsureCollectiveProxyOrigin
+{
+    type Success = ();
+
+    fn try_origin(o: RuntimeOrigin, a: &CollectiveProxyOrigin) -> Result<(), RuntimeOrigin> {
+        use frame_support::traits::EnsureOrigin;
+        match a {
+            CollectiveProxyOrigin::CommunityCouncil => {
+                EnsureRootOrHalfCommunityCouncil::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::MainCouncil => {
+                EnsureRootOrHalfMainCouncil::try_origin(o).map(|_| ())
+            }
+            CollectiveProxyOrigin::TechnicalCommittee => {
+                EnsureRootOrHalfTechnicalCommittee::try_origin(o).map(|_| ())
+            }
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin(_a: &CollectiveProxyOrigin) -> Result<RuntimeOrigin, ()> {
+        Ok(RuntimeOrigin::root())
+    }
+}
+
+/// Filter profiles of the collective proxies.
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    MaxEncodedLen,
+    scale_info::TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub enum CollectiveProxyFilter {
+    /// dApp staking, remarks and batches of them.
+    CommunityCouncil,
+    /// dApp staking only.
+    DappStaking,
+}
+
+impl InstanceFilter<RuntimeCall> for CollectiveProxyFilter {
     fn filter(&self, c: &RuntimeCall) -> bool {
-        matches!(
-            c,
-            RuntimeCall::DappStaking(..)
-                | RuntimeCall::System(frame_system::Call::remark { .. })
-                | RuntimeCall::Utility(pallet_utility::Call::batch { .. })
-                | RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
-        )
+        match self {
+            CollectiveProxyFilter::CommunityCouncil => matches!(
+                c,
+                RuntimeCall::DappStaking(..)
+                    | RuntimeCall::System(frame_system::Call::remark { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch { .. })
+                    | RuntimeCall::Utility(pallet_utility::Call::batch_all { .. })
+            ),
+            CollectiveProxyFilter::DappStaking => matches!(c, RuntimeCall::DappStaking(..)),
+        }
+    }
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CollectiveProxyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_collective_proxy::BenchmarkHelper<CollectiveProxyOrigin, CollectiveProxyFilter>
+    for CollectiveProxyBenchmarkHelper
+{
+    fn proxy_origin() -> CollectiveProxyOrigin {
+        CollectiveProxyOrigin::CommunityCouncil
+    }
+
+    fn call_filter() -> CollectiveProxyFilter {
+        CollectiveProxyFilter::CommunityCouncil
+    }
+}
+
+/// Funds spent from the community treasury by the calls it is allowed to make.
+pub struct CommunityCouncilSpendInspector;
+impl pallet_collective_proxy::SpendInspector<RuntimeCall, AssetId>
+    for CommunityCouncilSpendInspector
+{
+    fn spends(c: &RuntimeCall) -> Vec<(pallet_collective_proxy::SpendAsset<AssetId>, Balance)> {
+        use pallet_collective_proxy::SpendAsset;
+        match c {
+            RuntimeCall::DappStaking(pallet_dapp_staking::Call::lock { amount })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+                value: amount,
+                ..
+            })
+            | RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                value: amount,
+                ..
+            }) => vec![(SpendAsset::Native, *amount)],
+            RuntimeCall::Assets(pallet_assets::Call::transfer { id, amount, .. })
+            | RuntimeCall::Assets(pallet_assets::Call::transfer_keep_alive {
+                id, amount, ..
+            }) => vec![(SpendAsset::Asset(id.0), *amount)],
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().flat_map(Self::spends).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Calls batched by the collective proxies, counted towards their call rate limits.
+pub struct CollectiveProxyNestedCalls;
+impl pallet_collective_proxy::NestedCalls<RuntimeCall> for CollectiveProxyNestedCalls {
+    fn nested_calls(c: &RuntimeCall) -> Vec<&RuntimeCall> {
+        match c {
+            RuntimeCall::Utility(pallet_utility::Call::batch { calls })
+            | RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) => {
+                calls.iter().collect()
+            }
+            _ => Vec::new(),
+        }
     }
 }
 
 impl pallet_collective_proxy::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type RuntimeCall = RuntimeCall;
-    type CollectiveProxy = EnsureRootOrHalfCommunityCouncil;
-    type ProxyAccountId = CommunityTreasuryAccountId;
-    type CallFilter = CommunityCouncilCallFilter;
+    type ProxyOrigin = CollectiveProxyOrigin;
+    type CollectiveProxy = EnsureCollectiveProxyOrigin;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type CallFilter = CollectiveProxyFilter;
+    type PalletId = CollectiveProxyPalletId;
+    type AssetId = AssetId;
+    type SpendInspector = CommunityCouncilSpendInspector;
+    type NestedCalls = CollectiveProxyNestedCalls;
+    type BudgetPeriod = ConstU32<{ 7 * DAYS }>;
+    type MaxNameLen = ConstU32<64>;
+    type MaxProxyNameLen = ConstU32<16>;
+    type LegacyProxy = CommunityProxyName;
     type WeightInfo = pallet_collective_proxy::weights::SubstrateWeight<Runtime>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = CollectiveProxyBenchmarkHelper;
 }
 
 parameter_types! {
@@ -1665,12 +1875,55 @@ pub type SignedExtra = (
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
-    fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
+    fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, UnifiedSignature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic =
     fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+
+/// Signature of the extrinsics, accepting the EIP-712 signatures made by Ethereum keys
+/// besides the native ones.
+///
+/// Native variants are encoded the same way as in `Signature`.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub enum UnifiedSignature {
+    /// An Ed25519 signature.
+    Ed25519(sp_core::ed25519::Signature),
+    /// An Sr25519 signature.
+    Sr25519(sp_core::sr25519::Signature),
+    /// An ECDSA/SECP256k1 signature.
+    Ecdsa(sp_core::ecdsa::Signature),
+    /// An EIP-712 signature made by an Ethereum key, signing for the account id its address
+    /// is mapped to (or the default one) in `pallet-unified-accounts`.
+    Eip712([u8; 65]),
+}
+
+impl From<Signature> for UnifiedSignature {
+    fn from(signature: Signature) -> Self {
+        match signature {
+            Signature::Ed25519(sig) => Self::Ed25519(sig),
+            Signature::Sr25519(sig) => Self::Sr25519(sig),
+            Signature::Ecdsa(sig) => Self::Ecdsa(sig),
+        }
+    }
+}
+
+impl Verify for UnifiedSignature {
+    type Signer = <Signature as Verify>::Signer;
+
+    fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &AccountId) -> bool {
+        match self {
+            Self::Ed25519(sig) => Signature::Ed25519(sig.clone()).verify(msg, signer),
+            Self::Sr25519(sig) => Signature::Sr25519(sig.clone()).verify(msg, signer),
+            Self::Ecdsa(sig) => Signature::Ecdsa(sig.clone()).verify(msg, signer),
+            Self::Eip712(sig) => {
+                UnifiedAccounts::verify_extrinsic_signature(msg.get(), sig, signer)
+            }
+        }
+    }
+}
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -1687,14 +1940,21 @@ pub type Executive = frame_executive::Executive<
 pub type Migrations = (Unreleased, Permanent);
 
 /// Unreleased migrations. Add new ones here:
-pub type Unreleased =
-    (pallet_dapp_staking::migration::versioned_migrations::V8ToV9<Runtime, TierSlotsArgs>,);
+pub type Unreleased = (
+    pallet_dapp_staking::migration::versioned_migrations::V8ToV9<Runtime, TierSlotsArgs>,
+    pallet_price_aggregator::migration::versioned::V1ToV2<Runtime>,
+    pallet_collator_selection::migrations::versioned::V0ToV1<Runtime>,
+    pallet_collective_proxy::migration::versioned::V0ToV1<Runtime, CommunityProxy>,
+    astar_primitives::evm::AddPrecompileRevertCode<Runtime, PriceOraclePrecompileAddress>,
+);
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
 pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
 
 parameter_types! {
     pub const TierSlotsArgs: (u64, u64) = (1000, 50);
+    /// Address of the price oracle precompile, added after genesis.
+    pub PriceOraclePrecompileAddress: H160 = H160::from_low_u64_be(20488);
 }
 
 type EventRecord = frame_system::EventRecord<
@@ -2281,6 +2541,50 @@ impl_runtime_apis! {
         }
     }
 
+    impl unified_accounts_runtime_api::UnifiedAccountsApi<Block> for Runtime {
+        fn to_account_ids(evm_addresses: Vec<EvmAddress>) -> Vec<UnifiedAddress<AccountId>> {
+            evm_addresses
+                .iter()
+                .map(|evm_address| UnifiedAccounts::to_account_id_or_default(evm_address))
+                .collect()
+        }
+
+        fn to_h160s(account_ids: Vec<AccountId>) -> Vec<UnifiedAddress<EvmAddress>> {
+            account_ids
+                .iter()
+                .map(|account_id| UnifiedAccounts::to_h160_or_default(account_id))
+                .collect()
+        }
+
+        fn claim_signing_payload(account_id: AccountId) -> [u8; 32] {
+            UnifiedAccounts::build_signing_payload(&account_id)
+        }
+    }
+
+    impl collator_selection_runtime_api::CollatorSelectionApi<Block> for Runtime {
+        fn candidates() -> Vec<collator_selection_runtime_api::CandidateStatus<AccountId, Balance, BlockNumber>> {
+            CollatorSelection::candidate_statuses()
+        }
+
+        fn non_candidates() -> Vec<collator_selection_runtime_api::NonCandidateStatus<AccountId, Balance>> {
+            CollatorSelection::non_candidate_statuses()
+        }
+
+        fn next_session_collators() -> Vec<AccountId> {
+            CollatorSelection::next_session_collators()
+        }
+    }
+
+    impl price_aggregator_runtime_api::PriceAggregatorApi<Block> for Runtime {
+        fn tracked_currencies() -> Vec<CurrencyId> {
+            PriceAggregator::tracked_currencies()
+        }
+
+        fn average_price(currency_id: CurrencyId) -> Option<Price> {
+            <PriceAggregator as MultiCurrencyPriceProvider>::average_price_of(currency_id)
+        }
+    }
+
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
         fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
             if !matches!(xcm_version, xcm::v3::VERSION | xcm::v4::VERSION) {
@@ -63,7 +63,8 @@ use sp_runtime::{
         DispatchInfoOf, Dispatchable, OpaqueKeys, PostDispatchInfoOf, UniqueSaturatedInto,
     },
     transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
-    ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Permill, Perquintill, RuntimeDebug,
+    ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perbill, Percent, Permill, Perquintill,
+    RuntimeDebug,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 use xcm::{
@@ -82,7 +83,7 @@ use astar_primitives::{
     },
     evm::{EVMFungibleAdapterWrapper, EvmRevertCodeHandler},
     governance::OracleMembershipInst,
-    oracle::{CurrencyAmount, CurrencyId, DummyCombineData, Price},
+    oracle::{CurrencyAmount, CurrencyId, MedianCombineData, MultiCurrencyPriceProvider, Price},
     xcm::AssetLocationIdConverter,
     Address, AssetId, BlockNumber, Hash, Header, Nonce, UnfreezeChainOnFailedMigration,
 };
@@ -559,7 +560,10 @@ parameter_types! {
     pub const MinCandidates: u32 = 5;
     pub const MaxInvulnerables: u32 = 48;
     pub const SlashRatio: Perbill = Perbill::from_percent(1);
-    pub const KickThreshold: BlockNumber = 2 * HOURS; // 2 SessionPeriod
+    pub const KickThreshold: u32 = 2; // consecutive sessions
+    pub const MissedSlotsTolerance: Perbill = Perbill::from_percent(20);
+    pub const MinNomination: Balance = 100 * SDN;
+    pub const MaxNominators: u32 = 32;
 }
 
 pub struct CollatorSelectionAccountCheck;
@@ -577,14 +581,20 @@ impl pallet_collator_selection::Config for Runtime {
     type MaxCandidates = MaxCandidates;
     type MinCandidates = MinCandidates;
     type MaxInvulnerables = MaxInvulnerables;
-    // should be a multiple of session or things will get inconsistent
     type KickThreshold = KickThreshold;
+    type MissedSlotsTolerance = MissedSlotsTolerance;
+    type CurrentSlot = pallet_collator_selection::AuraDigestSlot<Runtime>;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
     type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
     type ValidatorRegistration = Session;
     type ValidatorSet = Session;
     type SlashRatio = SlashRatio;
     type AccountCheck = CollatorSelectionAccountCheck;
+    type MinNomination = MinNomination;
+    type MaxNominators = MaxNominators;
+    type PerformancePenalty = pallet_collator_selection::QuadraticPenalty;
+    // 1 day of sessions
+    type PerformanceHistoryDepth = ConstU32<24>;
     type WeightInfo = pallet_collator_selection::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1086,16 +1096,36 @@ parameter_types! {
     pub const NativeCurrencyId: CurrencyId = CurrencyId::SDN;
     // Aggregate values for one day.
     pub const AggregationDuration: BlockNumber = DAYS;
+    // Single value can at most halve or double the reference price.
+    pub const MaxBlockDeviation: Perbill = Perbill::from_percent(100);
+    pub const MaxAggregationDeviation: Perbill = Perbill::from_percent(100);
+    pub const PriceDeviationAction: pallet_price_aggregator::DeviationAction =
+        pallet_price_aggregator::DeviationAction::Clamp;
+    // Price feed is considered stale if no valid value is submitted for one hour.
+    pub const PriceFeedStalenessThreshold: u32 = HOURS;
+    pub const PriceFallbackThreshold: u32 = 7 * DAYS;
 }
 
 impl pallet_price_aggregator::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type MaxValuesPerBlock = ConstU32<8>;
     type ProcessBlockValues = pallet_price_aggregator::MedianBlockValue;
+    type MovingAverageStrategy = pallet_price_aggregator::SimpleMovingAverage;
     type NativeCurrencyId = NativeCurrencyId;
+    type MaxTrackedCurrencies = ConstU32<8>;
     // 7 days
     type CircularBufferLength = ConstU32<7>;
     type AggregationDuration = AggregationDuration;
+    type MaxBlockDeviation = MaxBlockDeviation;
+    type MaxAggregationDeviation = MaxAggregationDeviation;
+    type DeviationAction = PriceDeviationAction;
+    type ResetOrigin = EnsureRoot<AccountId>;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type StalenessThreshold = PriceFeedStalenessThreshold;
+    // No fallback price provider, moving average is always used.
+    type FallbackPrice = ();
+    type FallbackThreshold = PriceFallbackThreshold;
+    type MaxDataFeeders = <Runtime as pallet_membership::Config<OracleMembershipInst>>::MaxMembers;
     type WeightInfo = pallet_price_aggregator::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1117,10 +1147,17 @@ parameter_types! {
     pub RootOperatorAccountId: AccountId = AccountId::from([0xffu8; 32]);
 }
 
+parameter_types! {
+    /// Share of the oracle members which need to provide a fresh value for it to be combined.
+    pub const OracleQuorum: Percent = Percent::from_percent(50);
+    /// Oracle values older than this (in milliseconds) are ignored when combining.
+    pub const OracleValueExpiry: u64 = 60 * 60 * 1000;
+}
+
 impl orml_oracle::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type OnNewData = PriceAggregator;
-    type CombineData = DummyCombineData<Runtime>;
+    type CombineData = MedianCombineData<Runtime, OracleQuorum, OracleValueExpiry>;
     type Time = Timestamp;
     type OracleKey = CurrencyId;
     type OracleValue = Price;
@@ -1148,7 +1185,7 @@ impl pallet_membership::Config<OracleMembershipInst> for Runtime {
     type PrimeOrigin = EnsureRoot<AccountId>;
 
     type MembershipInitialized = ();
-    type MembershipChanged = ();
+    type MembershipChanged = PriceAggregator;
     type MaxMembers = ConstU32<16>;
     type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
@@ -1285,14 +1322,20 @@ pub type Executive = frame_executive::Executive<
 pub type Migrations = (Unreleased, Permanent);
 
 /// Unreleased migrations. Add new ones here:
-pub type Unreleased =
-    (pallet_dapp_staking::migration::versioned_migrations::V8ToV9<Runtime, TierSlotsArgs>,);
+pub type Unreleased = (
+    pallet_dapp_staking::migration::versioned_migrations::V8ToV9<Runtime, TierSlotsArgs>,
+    pallet_price_aggregator::migration::versioned::V1ToV2<Runtime>,
+    pallet_collator_selection::migrations::versioned::V0ToV1<Runtime>,
+    astar_primitives::evm::AddPrecompileRevertCode<Runtime, PriceOraclePrecompileAddress>,
+);
 
 /// Migrations/checks that do not need to be versioned and can run on every upgrade.
 pub type Permanent = (pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,);
 
 parameter_types! {
     pub const TierSlotsArgs: (u64, u64) = (100, 50);
+    /// Address of the price oracle precompile, added after genesis.
+    pub PriceOraclePrecompileAddress: H160 = H160::from_low_u64_be(20488);
 }
 
 type EventRecord = frame_system::EventRecord<
@@ -1873,6 +1916,30 @@ impl_runtime_apis! {
         }
     }
 
+    impl collator_selection_runtime_api::CollatorSelectionApi<Block> for Runtime {
+        fn candidates() -> Vec<collator_selection_runtime_api::CandidateStatus<AccountId, Balance, BlockNumber>> {
+            CollatorSelection::candidate_statuses()
+        }
+
+        fn non_candidates() -> Vec<collator_selection_runtime_api::NonCandidateStatus<AccountId, Balance>> {
+            CollatorSelection::non_candidate_statuses()
+        }
+
+        fn next_session_collators() -> Vec<AccountId> {
+            CollatorSelection::next_session_collators()
+        }
+    }
+
+    impl price_aggregator_runtime_api::PriceAggregatorApi<Block> for Runtime {
+        fn tracked_currencies() -> Vec<CurrencyId> {
+            PriceAggregator::tracked_currencies()
+        }
+
+        fn average_price(currency_id: CurrencyId) -> Option<Price> {
+            <PriceAggregator as MultiCurrencyPriceProvider>::average_price_of(currency_id)
+        }
+    }
+
     impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
         fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
             if !matches!(xcm_version, xcm::v3::VERSION | xcm::v4::VERSION) {
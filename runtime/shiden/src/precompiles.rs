@@ -30,6 +30,7 @@ use pallet_evm_precompile_dispatch::Dispatch;
 use pallet_evm_precompile_dispatch_lockdrop::DispatchLockdrop;
 use pallet_evm_precompile_ed25519::Ed25519Verify;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_price_oracle::PriceOraclePrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
 use pallet_evm_precompile_sr25519::Sr25519Precompile;
@@ -149,6 +150,11 @@ pub type ShidenPrecompilesSetAt<R, C> = (
         // Not callable from smart contract nor precompiled, only EOA accounts
         (),
     >,
+    PrecompileAt<
+        AddressU64<20488>,
+        PriceOraclePrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
 );
 
 pub type ShidenPrecompiles<R, C> = PrecompileSetBuilder<
@@ -157,7 +163,7 @@ pub type ShidenPrecompiles<R, C> = PrecompileSetBuilder<
         // Skip precompiles if out of range.
         PrecompilesInRangeInclusive<
             // We take range as last precompile index, UPDATE this once new prcompile is added
-            (AddressU64<1>, AddressU64<20487>),
+            (AddressU64<1>, AddressU64<20488>),
             ShidenPrecompilesSetAt<R, C>,
         >,
         // Prefixed precompile sets (XC20)
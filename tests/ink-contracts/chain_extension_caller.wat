@@ -0,0 +1,44 @@
+;; Minimal contract forwarding its input to the chain extensions, used by the integration tests.
+;; `chain_extension_caller.wasm` is compiled from this file.
+;;
+;; Input: chain extension function id (u32, little endian) followed by the chain extension input.
+;; Output: return code of the chain extension (u32, little endian) wrapped in an ink! compatible
+;; `Ok`, followed by the chain extension output buffer.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "call_chain_extension"
+		(func $call_chain_extension (param i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) input length
+	;; [4, 8) output length
+	;; [256, 4352) input
+	;; [8192, 8197) `Ok` prefixed return code
+	;; [8197, 12293) output
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(i32.store (i32.const 0) (i32.const 4096))
+		(call $seal_input (i32.const 256) (i32.const 0))
+
+		(i32.store (i32.const 4) (i32.const 4096))
+		(i32.store8 (i32.const 8192) (i32.const 0))
+		(i32.store (i32.const 8193)
+			(call $call_chain_extension
+				(i32.load (i32.const 256))
+				(i32.const 260)
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))
+				(i32.const 8197)
+				(i32.const 4)
+			)
+		)
+
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 8192)
+			(i32.add (i32.load (i32.const 4)) (i32.const 5))
+		)
+	)
+)
@@ -120,8 +120,15 @@ fn external_proposals_work() {
 #[test]
 fn community_council_can_execute_dapp_staking_calls() {
     new_test_ext().execute_with(|| {
+        // The community proxy is registered at genesis
+        let (proxy, proxy_info) = CommunityProxy::get();
+        assert_eq!(
+            pallet_collective_proxy::Proxies::<Runtime>::get(&proxy),
+            Some(proxy_info.clone())
+        );
+
         // Fund the proxy account
-        let proxy_account = <Runtime as pallet_collective_proxy::Config>::ProxyAccountId::get();
+        let proxy_account = proxy_info.account;
         let lock_amount = 10_000_000_000_000_000_000_000;
         Balances::make_free_balance_be(&proxy_account, lock_amount);
 
@@ -18,7 +18,10 @@
 
 use crate::setup::*;
 
-use astar_primitives::oracle::{Price, PriceProvider};
+use astar_primitives::{
+    governance::OracleMembershipInst,
+    oracle::{Price, PriceProvider},
+};
 use pallet_price_aggregator::{IntermediateValueAggregator, ValueAggregator};
 
 #[test]
@@ -30,7 +33,10 @@ fn price_submission_works() {
 
         // 0. Need to set limit block to something sensible, otherwise we'll waste time on many redundant iterations
         let limit_block = 10;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         // 1. Submit a price for a valid asset - the native currency
         let price_1 = Price::from_rational(15, 100);
@@ -50,7 +56,7 @@ fn price_submission_works() {
         run_for_blocks(1);
         let expected_average = (price_1 + price_2) * Price::from_rational(1, 2);
         assert_eq!(
-            IntermediateValueAggregator::<Runtime>::get().average(),
+            IntermediateValueAggregator::<Runtime>::get(native_currency_id).average(),
             expected_average
         );
 
@@ -82,9 +88,113 @@ fn price_submission_works() {
 
         // 5. Run until next limit block without any transactions, don't expect any changes
         let limit_block = limit_block * 2;
-        IntermediateValueAggregator::<Runtime>::put(ValueAggregator::new(limit_block));
+        IntermediateValueAggregator::<Runtime>::insert(
+            native_currency_id,
+            ValueAggregator::new(limit_block),
+        );
 
         run_to_block(limit_block + 1);
         assert_eq!(PriceAggregator::average_price(), expected_moving_average);
     })
 }
+
+#[test]
+fn combined_oracle_value_is_median_of_fresh_values() {
+    new_test_ext().execute_with(|| {
+        let native_currency_id =
+            <Runtime as pallet_price_aggregator::Config>::NativeCurrencyId::get();
+        let (price_1, price_2, price_3) = (
+            Price::from_rational(15, 100),
+            Price::from_rational(16, 100),
+            Price::from_rational(17, 100),
+        );
+
+        // 0. With 3 members, at least 2 values are required
+        assert_ok!(OracleMembership::add_member(
+            RuntimeOrigin::root(),
+            CAT.into()
+        ));
+        assert_eq!(
+            pallet_membership::Members::<Runtime, OracleMembershipInst>::get().len(),
+            3,
+            "Sanity check"
+        );
+
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(ALICE.clone()),
+            vec![(native_currency_id, price_1)].try_into().unwrap()
+        ));
+        assert!(Oracle::get(&native_currency_id).is_none());
+
+        // 1. Upper median is used for even number of values
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(BOB.clone()),
+            vec![(native_currency_id, price_3)].try_into().unwrap()
+        ));
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|v| v.value),
+            Some(price_3)
+        );
+
+        // 2. Median of all the values
+        run_for_blocks(1);
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(CAT.clone()),
+            vec![(native_currency_id, price_2)].try_into().unwrap()
+        ));
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|v| v.value),
+            Some(price_2)
+        );
+
+        // 3. Values of removed members are ignored
+        assert_ok!(OracleMembership::remove_member(
+            RuntimeOrigin::root(),
+            CAT.into()
+        ));
+        run_for_blocks(1);
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|v| v.value),
+            Some(price_3)
+        );
+
+        // 4. Expired values are ignored
+        run_for_blocks(1);
+        pallet_timestamp::Now::<Runtime>::put(
+            pallet_timestamp::Now::<Runtime>::get() + OracleValueExpiry::get(),
+        );
+        assert!(Oracle::get(&native_currency_id).is_none());
+
+        assert_ok!(Oracle::feed_values(
+            RuntimeOrigin::signed(ALICE.clone()),
+            vec![(native_currency_id, price_1)].try_into().unwrap()
+        ));
+        assert_eq!(
+            Oracle::get(&native_currency_id).map(|v| v.value),
+            Some(price_1)
+        );
+    })
+}
+
+#[test]
+fn price_oracle_precompile_revert_code_is_added_on_upgrade() {
+    new_test_ext().execute_with(|| {
+        use astar_primitives::evm::{AddPrecompileRevertCode, EVM_REVERT_CODE};
+        use frame_support::traits::OnRuntimeUpgrade;
+
+        let address = PriceOraclePrecompileAddress::get();
+        pallet_evm::AccountCodes::<Runtime>::remove(address);
+
+        AddPrecompileRevertCode::<Runtime, PriceOraclePrecompileAddress>::on_runtime_upgrade();
+        assert_eq!(
+            pallet_evm::AccountCodes::<Runtime>::get(address),
+            EVM_REVERT_CODE.to_vec()
+        );
+
+        // Existing code is left untouched.
+        let code = vec![0x60, 0x01];
+        pallet_evm::AccountCodes::<Runtime>::insert(address, code.clone());
+        AddPrecompileRevertCode::<Runtime, PriceOraclePrecompileAddress>::on_runtime_upgrade();
+        assert_eq!(pallet_evm::AccountCodes::<Runtime>::get(address), code);
+    })
+}
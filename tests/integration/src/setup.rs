@@ -200,6 +200,13 @@ impl ExtBuilder {
                 },
                 &mut t)
             .unwrap();
+
+            <pallet_collective_proxy::GenesisConfig<Runtime> as BuildStorage>::assimilate_storage(
+                &pallet_collective_proxy::GenesisConfig::<Runtime> {
+                    proxies: vec![CommunityProxy::get()],
+                },
+                &mut t)
+            .unwrap();
         }
 
         let mut ext = sp_io::TestExternalities::new(t);
@@ -17,11 +17,49 @@
 // along with Astar. If not, see <http://www.gnu.org/licenses/>.
 
 use crate::setup::*;
-use astar_primitives::evm::UnifiedAddress;
+use astar_primitives::{
+    dapp_staking::SmartContractHandle,
+    evm::{UnifiedAddress, UnifiedAddressMapper},
+    Address, Nonce,
+};
+use frame_support::traits::fungible::InspectFreeze;
 use parity_scale_codec::Encode;
 use sp_io::hashing::keccak_256;
+use sp_runtime::{
+    generic::Era,
+    transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
 
 const AU_CE_GETTER: &'static str = "au_ce_getters";
+const CE_CALLER: &'static str = "chain_extension_caller";
+
+/// Build an extrinsic signed by the eth private key with EIP-712 signature
+fn eip712_signed_extrinsic(
+    call: RuntimeCall,
+    signer: Address,
+    nonce: Nonce,
+    secret: &libsecp256k1::SecretKey,
+) -> UncheckedExtrinsic {
+    let extra: SignedExtra = (
+        frame_system::CheckSpecVersion::<Runtime>::new(),
+        frame_system::CheckTxVersion::<Runtime>::new(),
+        frame_system::CheckGenesis::<Runtime>::new(),
+        frame_system::CheckEra::<Runtime>::from(Era::Immortal),
+        frame_system::CheckNonce::<Runtime>::from(nonce),
+        frame_system::CheckWeight::<Runtime>::new(),
+        pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+        frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+    );
+    let payload = SignedPayload::new(call.clone(), extra.clone()).unwrap();
+    let signature = payload.using_encoded(|payload| {
+        UnifiedAccounts::eth_sign_prehash(
+            &UnifiedAccounts::build_extrinsic_signing_payload(payload),
+            secret,
+        )
+    });
+
+    UncheckedExtrinsic::new_signed(call, signer, UnifiedSignature::Eip712(signature), extra)
+}
 
 #[test]
 fn transfer_to_h160_via_lookup() {
@@ -119,3 +157,387 @@ fn unified_accounts_chain_extension_works() {
         );
     });
 }
+
+/// Build the input for `chain_extension_caller` contract to call the given
+/// unified accounts chain extension command
+fn au_ce_caller_input(command: u32, input: Vec<u8>) -> Vec<u8> {
+    [((3u32 << 16) | command).to_le_bytes().to_vec(), input].concat()
+}
+
+#[test]
+fn unified_accounts_chain_extension_claim_default_evm_address_works() {
+    const CLAIM_DEFAULT_EVM_ADDRESS: u32 = 4;
+
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_CALLER);
+        let input = au_ce_caller_input(CLAIM_DEFAULT_EVM_ADDRESS, vec![]);
+
+        // contract cannot pay for the storage fee
+        assert_eq!(
+            call_wasm_contract_method::<u32>(ALICE, contract_id.clone(), input.clone()),
+            4
+        );
+        assert!(UnifiedAccounts::to_h160(&contract_id).is_none());
+
+        // fund the contract and claim
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            contract_id.clone().into(),
+            UNIT,
+        ));
+        let default_address = UnifiedAccounts::to_default_h160(&contract_id);
+        assert_eq!(
+            call_wasm_contract_method::<(u32, H160)>(ALICE, contract_id.clone(), input.clone()),
+            (0, default_address)
+        );
+        assert_eq!(
+            UnifiedAccounts::to_h160(&contract_id),
+            Some(default_address)
+        );
+        assert_eq!(
+            UnifiedAccounts::to_account_id(&default_address),
+            Some(contract_id.clone())
+        );
+
+        // cannot claim twice
+        assert_eq!(
+            call_wasm_contract_method::<u32>(ALICE, contract_id.clone(), input),
+            1
+        );
+    });
+}
+
+#[test]
+fn unified_accounts_chain_extension_claim_evm_address_works() {
+    const CLAIM_EVM_ADDRESS: u32 = 5;
+
+    new_test_ext().execute_with(|| {
+        let contract_id = deploy_wasm_contract(CE_CALLER);
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(ALICE),
+            contract_id.clone().into(),
+            UNIT,
+        ));
+
+        // signature for another account is rejected
+        let signature = UnifiedAccounts::eth_sign_prehash(
+            &UnifiedAccounts::build_signing_payload(&ALICE),
+            &alith_secret_key(),
+        );
+        assert_eq!(
+            call_wasm_contract_method::<u32>(
+                ALICE,
+                contract_id.clone(),
+                au_ce_caller_input(CLAIM_EVM_ADDRESS, (alith(), signature).encode())
+            ),
+            3
+        );
+        assert!(UnifiedAccounts::to_h160(&contract_id).is_none());
+
+        // signature for the contract account works
+        let signature = UnifiedAccounts::eth_sign_prehash(
+            &UnifiedAccounts::build_signing_payload(&contract_id),
+            &alith_secret_key(),
+        );
+        let input = au_ce_caller_input(CLAIM_EVM_ADDRESS, (alith(), signature).encode());
+        assert_eq!(
+            call_wasm_contract_method::<u32>(ALICE, contract_id.clone(), input.clone()),
+            0
+        );
+        assert_eq!(UnifiedAccounts::to_h160(&contract_id), Some(alith()));
+        assert_eq!(
+            UnifiedAccounts::to_account_id(&alith()),
+            Some(contract_id.clone())
+        );
+
+        // cannot claim twice
+        assert_eq!(
+            call_wasm_contract_method::<u32>(ALICE, contract_id, input),
+            1
+        );
+    });
+}
+
+#[test]
+fn claim_evm_address_fails_while_default_account_holds_assets() {
+    new_test_ext().execute_with(|| {
+        let default_account = UnifiedAccounts::to_default_account_id(&alith());
+
+        // fund the default account, and mint some assets to it
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account.clone().into(),
+            10 * UNIT,
+        ));
+
+        let (asset_id_1, asset_id_2) = (19, 23);
+        let (amount_1, amount_2) = (1_000, 2_000);
+        for (asset_id, amount) in [(asset_id_1, amount_1), (asset_id_2, amount_2)] {
+            assert_ok!(Assets::create(
+                RuntimeOrigin::signed(BOB),
+                asset_id.into(),
+                BOB.into(),
+                1,
+            ));
+            assert_ok!(Assets::mint(
+                RuntimeOrigin::signed(BOB),
+                asset_id.into(),
+                default_account.clone().into(),
+                amount,
+            ));
+        }
+
+        // asset balances cannot be moved, so the claim is refused while any is held
+        let claim = || {
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alith(),
+                UnifiedAccounts::eth_sign_prehash(
+                    &UnifiedAccounts::build_signing_payload(&ALICE),
+                    &alith_secret_key(),
+                ),
+            )
+        };
+        for (asset_id, amount) in [(asset_id_1, amount_1), (asset_id_2, amount_2)] {
+            assert_noop!(
+                claim(),
+                pallet_unified_accounts::Error::<Runtime>::DefaultAccountNotEmpty
+            );
+            assert_ok!(Assets::transfer(
+                RuntimeOrigin::signed(default_account.clone()),
+                asset_id.into(),
+                ALICE.into(),
+                amount,
+            ));
+        }
+
+        // once all the assets are moved by the user, the claim works
+        assert_ok!(claim());
+        assert!(!System::account_exists(&default_account));
+    });
+}
+
+#[test]
+fn claim_evm_address_moves_dapp_staking_ledger() {
+    new_test_ext().execute_with(|| {
+        let default_account = UnifiedAccounts::to_default_account_id(&alith());
+        let contract =
+            <Runtime as pallet_dapp_staking::Config>::SmartContract::evm(H160::repeat_byte(0x01));
+        assert_ok!(DappStaking::register(
+            RuntimeOrigin::root(),
+            BOB.clone(),
+            contract.clone()
+        ));
+
+        // fund the default account, and lock & stake with it
+        let amount = 1_000 * UNIT;
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account.clone().into(),
+            2 * amount,
+        ));
+        assert_ok!(DappStaking::lock(
+            RuntimeOrigin::signed(default_account.clone()),
+            amount
+        ));
+        assert_ok!(DappStaking::stake(
+            RuntimeOrigin::signed(default_account.clone()),
+            contract.clone(),
+            amount,
+        ));
+
+        let ledger = pallet_dapp_staking::Ledger::<Runtime>::get(&default_account);
+        let staker_info =
+            pallet_dapp_staking::StakerInfo::<Runtime>::get(&default_account, &contract)
+                .expect("Stake was made, entry must exist.");
+
+        // claim the evm address, ledger & stake should be moved along with the native balance
+        connect_accounts(&ALICE, &alith_secret_key());
+
+        assert_eq!(pallet_dapp_staking::Ledger::<Runtime>::get(&ALICE), ledger);
+        assert_eq!(
+            pallet_dapp_staking::StakerInfo::<Runtime>::get(&ALICE, &contract),
+            Some(staker_info)
+        );
+        assert_eq!(
+            Balances::balance_frozen(
+                &pallet_dapp_staking::FreezeReason::DAppStaking.into(),
+                &ALICE
+            ),
+            amount
+        );
+        assert!(!pallet_dapp_staking::Ledger::<Runtime>::contains_key(
+            &default_account
+        ));
+        assert!(!System::account_exists(&default_account));
+    });
+}
+
+#[test]
+fn claim_evm_address_fails_if_dapp_staking_ledger_cannot_be_moved() {
+    new_test_ext().execute_with(|| {
+        let default_account = UnifiedAccounts::to_default_account_id(&alith());
+
+        // both the default account & the claiming account have dApp staking ledgers
+        let amount = 1_000 * UNIT;
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account.clone().into(),
+            2 * amount,
+        ));
+        assert_ok!(DappStaking::lock(
+            RuntimeOrigin::signed(default_account.clone()),
+            amount
+        ));
+        assert_ok!(DappStaking::lock(RuntimeOrigin::signed(ALICE), amount));
+
+        // ledgers cannot be merged, so the claim must be refused
+        assert_noop!(
+            UnifiedAccounts::claim_evm_address(
+                RuntimeOrigin::signed(ALICE),
+                alith(),
+                UnifiedAccounts::eth_sign_prehash(
+                    &UnifiedAccounts::build_signing_payload(&ALICE),
+                    &alith_secret_key()
+                )
+            ),
+            pallet_dapp_staking::Error::<Runtime>::UnificationTargetHasLedger
+        );
+    });
+}
+
+#[test]
+fn extrinsic_signed_by_evm_key_works() {
+    new_test_ext().execute_with(|| {
+        connect_accounts(&ALICE, &alith_secret_key());
+        let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+            dest: BOB.into(),
+            value: UNIT,
+        });
+        let nonce = System::account_nonce(&ALICE);
+        let bob_balance = Balances::free_balance(&BOB);
+
+        // alith signs for the account it is mapped to
+        let xt = eip712_signed_extrinsic(
+            call.clone(),
+            MultiAddress::Address20(alith().into()),
+            nonce,
+            &alith_secret_key(),
+        );
+        assert_eq!(Executive::apply_extrinsic(xt.clone()), Ok(Ok(())));
+        assert_eq!(Balances::free_balance(&BOB), bob_balance + UNIT);
+        assert_eq!(System::account_nonce(&ALICE), nonce + 1);
+
+        // extrinsic cannot be replayed
+        assert_eq!(
+            Executive::apply_extrinsic(xt),
+            Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+        );
+
+        // signer can also be provided by its native address
+        let xt = eip712_signed_extrinsic(
+            call.clone(),
+            MultiAddress::Id(ALICE),
+            nonce + 1,
+            &alith_secret_key(),
+        );
+        assert_eq!(Executive::apply_extrinsic(xt), Ok(Ok(())));
+        assert_eq!(Balances::free_balance(&BOB), bob_balance + 2 * UNIT);
+    });
+}
+
+#[test]
+fn pending_unlink_cannot_be_cancelled_by_evm_key() {
+    new_test_ext().execute_with(|| {
+        connect_accounts(&ALICE, &alith_secret_key());
+        assert_ok!(UnifiedAccounts::request_unlink(RuntimeOrigin::signed(
+            ALICE
+        )));
+        let call = RuntimeCall::UnifiedAccounts(pallet_unified_accounts::Call::cancel_unlink {});
+
+        // evm key might be compromised, so it cannot cancel the unlink
+        let xt = eip712_signed_extrinsic(
+            call.clone(),
+            MultiAddress::Id(ALICE),
+            System::account_nonce(&ALICE),
+            &alith_secret_key(),
+        );
+        assert_eq!(
+            Executive::apply_extrinsic(xt),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+        assert!(pallet_unified_accounts::PendingUnlinks::<Runtime>::contains_key(&ALICE));
+
+        // native key can cancel it
+        assert_ok!(UnifiedAccounts::cancel_unlink(RuntimeOrigin::signed(ALICE)));
+        assert!(!pallet_unified_accounts::PendingUnlinks::<Runtime>::contains_key(&ALICE));
+    });
+}
+
+#[test]
+fn extrinsic_signed_by_unmapped_evm_key_uses_default_account() {
+    new_test_ext().execute_with(|| {
+        let default_account = UnifiedAccounts::to_default_account_id(&alith());
+        assert_ok!(Balances::transfer_allow_death(
+            RuntimeOrigin::signed(BOB),
+            default_account.clone().into(),
+            10 * UNIT,
+        ));
+        let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+            dest: CAT.into(),
+            value: UNIT,
+        });
+        let cat_balance = Balances::free_balance(&CAT);
+
+        let xt = eip712_signed_extrinsic(
+            call.clone(),
+            MultiAddress::Address20(alith().into()),
+            0,
+            &alith_secret_key(),
+        );
+        assert_eq!(Executive::apply_extrinsic(xt), Ok(Ok(())));
+        assert_eq!(Balances::free_balance(&CAT), cat_balance + UNIT);
+        assert_eq!(System::account_nonce(&default_account), 1);
+
+        // alith cannot sign for other accounts
+        let xt = eip712_signed_extrinsic(call, MultiAddress::Id(BOB), 0, &alith_secret_key());
+        assert_eq!(
+            Executive::apply_extrinsic(xt),
+            Err(TransactionValidityError::Invalid(
+                InvalidTransaction::BadProof
+            ))
+        );
+    });
+}
+
+#[test]
+fn unified_accounts_runtime_api_works() {
+    use unified_accounts_runtime_api::runtime_decl_for_unified_accounts_api::UnifiedAccountsApiV1;
+
+    new_test_ext().execute_with(|| {
+        connect_accounts(&ALICE, &alith_secret_key());
+        let bob_default_h160 = UnifiedAccounts::to_default_h160(&BOB);
+        let default_account = UnifiedAccounts::to_default_account_id(&bob_default_h160);
+
+        assert_eq!(
+            Runtime::to_account_ids(vec![alith(), bob_default_h160]),
+            vec![
+                UnifiedAddress::Mapped(ALICE),
+                UnifiedAddress::Default(default_account)
+            ]
+        );
+        assert_eq!(
+            Runtime::to_h160s(vec![ALICE, BOB]),
+            vec![
+                UnifiedAddress::Mapped(alith()),
+                UnifiedAddress::Default(bob_default_h160)
+            ]
+        );
+        assert_eq!(
+            Runtime::claim_signing_payload(BOB),
+            UnifiedAccounts::build_signing_payload(&BOB)
+        );
+    });
+}